@@ -0,0 +1,48 @@
+#ifndef MATRIX_H
+#define MATRIX_H
+
+#include <stddef.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define MATRIX_OK 0
+#define MATRIX_NULL_POINTER -1
+#define MATRIX_OUT_OF_BOUNDS -2
+
+/* Opaque handle to a row-major matrix of doubles. */
+typedef struct Matrix Matrix;
+
+/* Returns a zero-filled rows x cols matrix, or NULL if rows * cols overflows.
+   Release it with matrix_free. */
+Matrix *matrix_create(size_t rows, size_t cols);
+
+/* Copies rows * cols doubles from data. Returns NULL if data is NULL or
+   rows * cols overflows. */
+Matrix *matrix_from_data(size_t rows, size_t cols, const double *data);
+
+void matrix_free(Matrix *m);
+
+size_t matrix_rows(const Matrix *m);
+size_t matrix_cols(const Matrix *m);
+
+int matrix_get(const Matrix *m, size_t row, size_t col, double *out);
+int matrix_set(Matrix *m, size_t row, size_t col, double value);
+
+/* Borrowed pointer to the row-major backing buffer, valid until matrix_free. */
+double *matrix_data(Matrix *m);
+
+/* Return a new matrix, or NULL if an argument is NULL or the shapes do not fit. */
+Matrix *matrix_add(const Matrix *a, const Matrix *b);
+Matrix *matrix_multiply(const Matrix *a, const Matrix *b);
+
+/* Solves A X = B. Returns NULL if an argument is NULL, A is not square, the
+   shapes do not fit, or A is singular. */
+Matrix *matrix_solve(const Matrix *a, const Matrix *b);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
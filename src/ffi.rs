@@ -0,0 +1,195 @@
+use crate::matrix::Matrix;
+use std::ffi::c_int;
+use std::ptr;
+
+pub const MATRIX_OK: c_int = 0;
+pub const MATRIX_NULL_POINTER: c_int = -1;
+pub const MATRIX_OUT_OF_BOUNDS: c_int = -2;
+
+// Returns null if rows * cols overflows usize.
+#[no_mangle]
+pub extern "C" fn matrix_create(rows: usize, cols: usize) -> *mut Matrix<f64> {
+    if rows.checked_mul(cols).is_none() {
+        return ptr::null_mut();
+    }
+
+    return Box::into_raw(Box::new(Matrix::new_empty(rows, cols)));
+}
+
+/// # Safety
+///
+/// `data` must point to at least `rows * cols` readable doubles laid out in
+/// row-major order. Returns null if `data` is null or `rows * cols`
+/// overflows usize.
+#[no_mangle]
+pub unsafe extern "C" fn matrix_from_data(
+    rows: usize,
+    cols: usize,
+    data: *const f64,
+) -> *mut Matrix<f64> {
+    let len = match rows.checked_mul(cols) {
+        Some(len) if !data.is_null() => len,
+        _ => return ptr::null_mut(),
+    };
+
+    let mut result = Matrix::new_empty(rows, cols);
+    let src = unsafe { std::slice::from_raw_parts(data, len) };
+    result.as_mut_slice().copy_from_slice(src);
+
+    return Box::into_raw(Box::new(result));
+}
+
+/// # Safety
+///
+/// `m` must be null or a pointer returned by this library that has not
+/// already been freed.
+#[no_mangle]
+pub unsafe extern "C" fn matrix_free(m: *mut Matrix<f64>) {
+    if !m.is_null() {
+        drop(unsafe { Box::from_raw(m) });
+    }
+}
+
+/// # Safety
+///
+/// `m` must be null or a live pointer returned by this library.
+#[no_mangle]
+pub unsafe extern "C" fn matrix_rows(m: *const Matrix<f64>) -> usize {
+    return match unsafe { m.as_ref() } {
+        Some(m) => m.num_rows(),
+        None => 0,
+    };
+}
+
+/// # Safety
+///
+/// `m` must be null or a live pointer returned by this library.
+#[no_mangle]
+pub unsafe extern "C" fn matrix_cols(m: *const Matrix<f64>) -> usize {
+    return match unsafe { m.as_ref() } {
+        Some(m) => m.num_cols(),
+        None => 0,
+    };
+}
+
+/// # Safety
+///
+/// `m` must be null or a live pointer returned by this library, and `out`
+/// must be null or valid for a single write.
+#[no_mangle]
+pub unsafe extern "C" fn matrix_get(
+    m: *const Matrix<f64>,
+    row: usize,
+    col: usize,
+    out: *mut f64,
+) -> c_int {
+    let (m, out) = match unsafe { (m.as_ref(), out.as_mut()) } {
+        (Some(m), Some(out)) => (m, out),
+        _ => return MATRIX_NULL_POINTER,
+    };
+
+    if row >= m.num_rows() || col >= m.num_cols() {
+        return MATRIX_OUT_OF_BOUNDS;
+    }
+
+    *out = m.at_or_default(row, col);
+    return MATRIX_OK;
+}
+
+/// # Safety
+///
+/// `m` must be null or a live pointer returned by this library.
+#[no_mangle]
+pub unsafe extern "C" fn matrix_set(
+    m: *mut Matrix<f64>,
+    row: usize,
+    col: usize,
+    value: f64,
+) -> c_int {
+    let m = match unsafe { m.as_mut() } {
+        Some(m) => m,
+        None => return MATRIX_NULL_POINTER,
+    };
+
+    if row >= m.num_rows() || col >= m.num_cols() {
+        return MATRIX_OUT_OF_BOUNDS;
+    }
+
+    return match m.set(row, col, value) {
+        Ok(_) => MATRIX_OK,
+        Err(_) => MATRIX_OUT_OF_BOUNDS,
+    };
+}
+
+/// # Safety
+///
+/// `m` must be null or a live pointer returned by this library. The returned
+/// pointer is only valid until the matrix is freed.
+#[no_mangle]
+pub unsafe extern "C" fn matrix_data(m: *mut Matrix<f64>) -> *mut f64 {
+    return match unsafe { m.as_mut() } {
+        Some(m) => m.as_mut_slice().as_mut_ptr(),
+        None => ptr::null_mut(),
+    };
+}
+
+/// # Safety
+///
+/// `a` and `b` must be null or live pointers returned by this library.
+/// Returns null if either is null or their dimensions are incompatible.
+#[no_mangle]
+pub unsafe extern "C" fn matrix_multiply(
+    a: *const Matrix<f64>,
+    b: *const Matrix<f64>,
+) -> *mut Matrix<f64> {
+    let (a, b) = match unsafe { (a.as_ref(), b.as_ref()) } {
+        (Some(a), Some(b)) => (a, b),
+        _ => return ptr::null_mut(),
+    };
+
+    return match a.matrix_multiply(b) {
+        Some(result) => Box::into_raw(Box::new(result)),
+        None => ptr::null_mut(),
+    };
+}
+
+/// # Safety
+///
+/// `a` and `b` must be null or live pointers returned by this library.
+/// Returns null if either is null or their dimensions differ.
+#[no_mangle]
+pub unsafe extern "C" fn matrix_add(
+    a: *const Matrix<f64>,
+    b: *const Matrix<f64>,
+) -> *mut Matrix<f64> {
+    let (a, b) = match unsafe { (a.as_ref(), b.as_ref()) } {
+        (Some(a), Some(b)) => (a, b),
+        _ => return ptr::null_mut(),
+    };
+
+    return match a.matrix_add(b) {
+        Some(result) => Box::into_raw(Box::new(result)),
+        None => ptr::null_mut(),
+    };
+}
+
+/// # Safety
+///
+/// `a` and `b` must be null or live pointers returned by this library.
+/// Returns the solution X of A X = B, or null if either is null, A is not
+/// square, the shapes disagree, or A is singular.
+#[no_mangle]
+pub unsafe extern "C" fn matrix_solve(
+    a: *const Matrix<f64>,
+    b: *const Matrix<f64>,
+) -> *mut Matrix<f64> {
+    let (a, b) = match unsafe { (a.as_ref(), b.as_ref()) } {
+        (Some(a), Some(b)) => (a, b),
+        _ => return ptr::null_mut(),
+    };
+
+    return match a.solve(b) {
+        Some(result) => Box::into_raw(Box::new(result)),
+        None => ptr::null_mut(),
+    };
+}
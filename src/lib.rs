@@ -0,0 +1,19 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![allow(clippy::needless_return)]
+
+extern crate alloc;
+
+mod macros;
+pub mod matrix;
+
+#[cfg(feature = "ffi")]
+pub mod ffi;
+
+#[cfg(feature = "python")]
+pub mod python;
+
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+#[cfg(test)]
+mod tests;
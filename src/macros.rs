@@ -0,0 +1,31 @@
+// Rows are separated by `;`. The rows become nested fixed-size arrays, so
+// ragged input is a compile error rather than a runtime one.
+#[macro_export]
+macro_rules! matrix {
+    () => {
+        $crate::matrix::Matrix::from_iter(0, 0, []).unwrap()
+    };
+    ($($($x:expr),+ $(,)?);+ $(;)?) => {
+        $crate::matrix::Matrix::from([$([$($x),+]),+])
+    };
+}
+
+// A column vector, i.e. an n x 1 matrix.
+#[macro_export]
+macro_rules! vector {
+    () => {
+        $crate::matrix::Matrix::from_iter(0, 1, []).unwrap()
+    };
+    ($($x:expr),+ $(,)?) => {
+        $crate::matrix::Matrix::from([$([$x]),+])
+    };
+}
+
+// `einsum!("ij,jk->ik", &a, &b)` for `matrix::tensor::einsum` with the
+// operands listed inline.
+#[macro_export]
+macro_rules! einsum {
+    ($spec:expr, $($operand:expr),+ $(,)?) => {
+        $crate::matrix::tensor::einsum($spec, &[$($operand),+])
+    };
+}
@@ -1,6 +1,3 @@
-mod matrix;
-mod tests;
-
 fn main() {
     println!("Hello, world!");
 }
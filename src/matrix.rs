@@ -1,10 +1,81 @@
-use std::fmt;
-use std::fmt::Display;
-use std::iter::zip;
-use std::ops::{Add, Div, Mul, Sub};
-use std::slice::Chunks;
-
-#[derive(Debug)]
+use alloc::borrow::ToOwned;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::Display;
+use core::iter::zip;
+use core::ops::{Add, Div, Mul, Sub};
+use core::slice::Chunks;
+use format::FormatOptions;
+
+#[cfg(feature = "arrow")]
+mod arrow;
+pub mod blas;
+mod broadcast;
+pub mod builder;
+pub mod bytes;
+pub mod conv;
+#[cfg(feature = "std")]
+pub mod csv;
+mod dataset;
+pub mod decomposition;
+pub mod diagonal;
+pub mod distance;
+mod elementwise;
+pub mod factorization;
+pub mod fft;
+pub mod format;
+pub mod graph;
+#[cfg(feature = "hdf5")]
+pub mod hdf5;
+pub mod heatmap;
+#[cfg(feature = "image")]
+mod image;
+mod kernel;
+mod labels;
+mod linalg;
+pub mod markov;
+mod mask;
+#[cfg(feature = "std")]
+pub mod mat;
+#[cfg(feature = "mmap")]
+pub mod mmap;
+#[cfg(feature = "std")]
+pub mod mtx;
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+#[cfg(feature = "ndarray")]
+mod ndarray;
+pub mod normalize;
+#[cfg(feature = "std")]
+pub mod npy;
+#[cfg(feature = "parquet")]
+mod parquet;
+pub mod pca;
+pub mod permutation;
+#[cfg(feature = "plot")]
+pub mod plot;
+mod predicates;
+mod query;
+#[cfg(feature = "rand")]
+pub mod random;
+mod range;
+pub mod regression;
+mod rowops;
+#[cfg(feature = "serde")]
+mod serde;
+pub mod shared;
+mod sparsity;
+pub mod special;
+mod stats;
+#[cfg(feature = "std")]
+pub mod stream;
+pub mod symmetric;
+pub mod tensor;
+pub mod tiles;
+pub mod triangular;
+
+#[derive(Debug, PartialEq)]
 pub struct Matrix<T>
 where
     T: Default,
@@ -14,17 +85,24 @@ where
     matrix: Vec<T>,
 }
 
+// Selects the slices an axis-wise operation works on: `Rows` treats each
+// row as one unit (one result per row, or each row an observation), and
+// `Cols` does the same for columns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+    Rows,
+    Cols,
+}
+
 #[allow(dead_code)]
 impl<T> Matrix<T>
 where
     T: Default + Copy + Clone,
 {
+    // Same as `new_empty`: every entry starts at the default value. Use
+    // `Matrix::builder()` for other fill values or a diagonal.
     pub fn new(rows: usize, cols: usize) -> Self {
-        Self {
-            rows,
-            cols,
-            matrix: Vec::new(),
-        }
+        return Self::new_empty(rows, cols);
     }
 
     pub fn new_empty(rows: usize, cols: usize) -> Self {
@@ -38,11 +116,40 @@ where
     pub fn from_vec(v: Vec<Vec<T>>) -> Self {
         Self {
             rows: v.len(),
-            cols: v[0].len(),
+            cols: v.first().map_or(0, |row| row.len()),
             matrix: v.into_iter().flatten().collect(),
         }
     }
 
+    // Takes ownership of row-major data without copying.
+    pub fn from_flat(rows: usize, cols: usize, data: Vec<T>) -> Result<Self, &'static str> {
+        if data.len() != rows * cols {
+            return Err("Data length does not match dimensions");
+        }
+
+        return Ok(Self {
+            rows,
+            cols,
+            matrix: data,
+        });
+    }
+
+    // Fails if the iterator yields fewer or more than `rows * cols` items.
+    pub fn from_iter<I>(rows: usize, cols: usize, iter: I) -> Result<Self, &'static str>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut iter = iter.into_iter();
+        let mut matrix = Vec::with_capacity(rows * cols);
+        matrix.extend(iter.by_ref().take(rows * cols));
+
+        if matrix.len() != rows * cols || iter.next().is_some() {
+            return Err("Iterator length does not match dimensions");
+        }
+
+        return Ok(Self { rows, cols, matrix });
+    }
+
     pub fn num_rows(&self) -> usize {
         return self.rows;
     }
@@ -111,13 +218,129 @@ where
         };
     }
 
-    pub fn rows(&self) -> Chunks<T> {
-        return self.matrix.chunks(self.cols);
+    // Like `map`, but stops at the first error and returns it.
+    pub fn try_map<F, TResult, E>(&self, map: F) -> Result<Matrix<TResult>, E>
+    where
+        F: Fn(&T) -> Result<TResult, E>,
+        TResult: Default,
+    {
+        let result = self.matrix.iter().map(map).collect::<Result<_, _>>()?;
+
+        return Ok(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            matrix: result,
+        });
+    }
+
+    // Combines two matrices of the same shape entry by entry. Returns None
+    // on a shape mismatch.
+    pub fn zip_map<U, F, TResult>(&self, m: &Matrix<U>, map: F) -> Option<Matrix<TResult>>
+    where
+        U: Default,
+        F: Fn(&T, &U) -> TResult,
+        TResult: Default,
+    {
+        if self.rows != m.rows || self.cols != m.cols {
+            return None;
+        }
+
+        let result = zip(&self.matrix, &m.matrix)
+            .map(|(x, y)| map(x, y))
+            .collect();
+
+        return Some(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            matrix: result,
+        });
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        return self.matrix.as_slice();
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        return self.matrix.as_mut_slice();
+    }
+
+    // Reinterprets the same row-major data with a new shape. Returns None
+    // when the element count differs.
+    pub fn reshape(self, rows: usize, cols: usize) -> Option<Self> {
+        if rows * cols != self.matrix.len() {
+            return None;
+        }
+
+        return Some(Matrix {
+            rows,
+            cols,
+            matrix: self.matrix,
+        });
+    }
+
+    // Yields nothing for matrices without columns.
+    pub fn rows(&self) -> Chunks<'_, T> {
+        return self.matrix.chunks(self.cols.max(1));
     }
 
     pub fn get_row(&self, i: usize) -> Option<&[T]> {
         return self.rows().nth(i);
     }
+
+    // Number of slices along `axis` and the length of each one.
+    pub(crate) fn lane_shape(&self, axis: Axis) -> (usize, usize) {
+        return match axis {
+            Axis::Rows => (self.rows, self.cols),
+            Axis::Cols => (self.cols, self.rows),
+        };
+    }
+
+    // The `i`-th row or column, depending on `axis`.
+    pub(crate) fn lane(&self, axis: Axis, i: usize) -> impl Iterator<Item = &T> {
+        let (start, step, len) = match axis {
+            Axis::Rows => (i * self.cols, 1, self.cols),
+            Axis::Cols => (i, self.cols.max(1), self.rows),
+        };
+
+        let data = self.matrix.get(start..).unwrap_or(&[]);
+
+        return data.iter().step_by(step).take(len);
+    }
+}
+
+// Moves values in and out of place without cloning, so these also work for
+// element types that are not Copy.
+impl<T> Matrix<T>
+where
+    T: Default,
+{
+    pub fn swap(&mut self, i1: usize, j1: usize, i2: usize, j2: usize) -> Result<&mut Self, &str> {
+        return match (self.offset(i1, j1), self.offset(i2, j2)) {
+            (Some(a), Some(b)) => {
+                self.matrix.swap(a, b);
+                Ok(self)
+            }
+            _ => Err("Index out of bounds"),
+        };
+    }
+
+    // Leaves the default value behind.
+    pub fn take(&mut self, row: usize, col: usize) -> Option<T> {
+        return self.replace(row, col, T::default());
+    }
+
+    // Stores `value` and returns the previous entry.
+    pub fn replace(&mut self, row: usize, col: usize, value: T) -> Option<T> {
+        let k = self.offset(row, col)?;
+        return Some(core::mem::replace(&mut self.matrix[k], value));
+    }
+
+    fn offset(&self, row: usize, col: usize) -> Option<usize> {
+        return match row < self.rows && col < self.cols {
+            true => Some(row * self.cols + col),
+            false => None,
+        };
+    }
 }
 
 #[allow(dead_code)]
@@ -144,7 +367,7 @@ where
             return None;
         }
 
-        let mut result = Matrix::new(self.rows, self.cols);
+        let mut result = Matrix::new_empty(self.rows, self.cols);
         let result_iter = zip(self.matrix.iter(), m.matrix.iter());
 
         result_iter
@@ -160,13 +383,13 @@ where
     }
 
     pub fn matrix_multiply(&self, m: &Matrix<Q>) -> Option<Matrix<Q>> {
-        if self.rows != m.rows || self.cols != m.cols {
+        if self.cols != m.rows {
             return None;
         }
 
-        let mut result = Matrix::new(self.rows, m.num_cols());
+        let mut result = Matrix::new_empty(self.rows, m.num_cols());
         for i in 0..self.num_rows() {
-            for j in 0..self.num_cols() {
+            for j in 0..m.num_cols() {
                 for k in 0..m.num_rows() {
                     let prod = self.at_or_default(i, k) * m.at_or_default(k, j);
                     let _ = result.apply(i, j, |x| x + &prod);
@@ -178,34 +401,36 @@ where
     }
 }
 
+// Moves the elements of a nested array straight into the flat buffer.
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for Matrix<T>
+where
+    T: Default,
+{
+    fn from(rows: [[T; C]; R]) -> Self {
+        let mut matrix = Vec::with_capacity(R * C);
+        rows.into_iter().for_each(|row| matrix.extend(row));
+
+        return Matrix {
+            rows: R,
+            cols: C,
+            matrix,
+        };
+    }
+}
+
 impl<D> fmt::Display for Matrix<D>
 where
     D: Display + Default,
 {
+    // Empty and degenerate matrices print their shape, since `[]` alone
+    // would not tell 0x3 apart from 3x0.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let mut result = String::from("\n");
-        let max_len = self
-            .matrix
-            .iter()
-            .map(|x| (*x).to_string().len())
-            .max()
-            .unwrap();
-
-        let rows = self.matrix.as_slice().chunks(self.cols);
-
-        for row in rows {
-            result.push_str("[ ");
-            row.iter()
-                .map(|x| {
-                    let str = (*x).to_string();
-                    let padded_str = format!("{:>max_len$}", str);
-
-                    return padded_str;
-                })
-                .for_each(|x| result.push_str(&format!("{} ", x)));
-            result.push_str("]\n");
+        if self.matrix.is_empty() {
+            return write!(f, "\n[] ({}x{})\n", self.rows, self.cols);
         }
 
-        return write!(f, "{}", result);
+        let options = FormatOptions::from_formatter(f);
+
+        return write!(f, "\n{}", self.format_with(&options));
     }
 }
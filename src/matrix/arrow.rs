@@ -0,0 +1,92 @@
+use super::Matrix;
+use arrow_array::{Array, ArrayRef, Float64Array, RecordBatch};
+use arrow_schema::{ArrowError, DataType, Field, Schema};
+use std::sync::Arc;
+
+fn default_column_names(cols: usize) -> Vec<String> {
+    return (0..cols).map(|j| format!("column_{}", j)).collect();
+}
+
+impl Matrix<f64> {
+    // Each matrix column becomes a non-nullable Float64 column. Without
+    // explicit names the columns are called `column_0`, `column_1`, ...
+    pub fn to_record_batch(&self, names: Option<&[&str]>) -> Result<RecordBatch, ArrowError> {
+        let names: Vec<String> = match names {
+            Some(names) if names.len() != self.cols => {
+                return Err(ArrowError::InvalidArgumentError(format!(
+                    "Expected {} column names, found {}",
+                    self.cols,
+                    names.len()
+                )))
+            }
+            Some(names) => names.iter().map(|n| n.to_string()).collect(),
+            None => default_column_names(self.cols),
+        };
+
+        let fields: Vec<Field> = names
+            .iter()
+            .map(|n| Field::new(n, DataType::Float64, false))
+            .collect();
+        let columns: Vec<ArrayRef> = (0..self.cols)
+            .map(|j| {
+                let column: Vec<f64> = self
+                    .matrix
+                    .iter()
+                    .skip(j)
+                    .step_by(self.cols)
+                    .copied()
+                    .collect();
+                Arc::new(Float64Array::from(column)) as ArrayRef
+            })
+            .collect();
+
+        return RecordBatch::try_new(Arc::new(Schema::new(fields)), columns);
+    }
+
+    // Builds a matrix from the named columns, in the order given, or from
+    // every column of the batch. All selected columns must be Float64 and
+    // contain no nulls.
+    pub fn from_record_batch(
+        batch: &RecordBatch,
+        names: Option<&[&str]>,
+    ) -> Result<Matrix<f64>, ArrowError> {
+        let columns: Vec<&ArrayRef> = match names {
+            Some(names) => names
+                .iter()
+                .map(|n| {
+                    batch
+                        .column_by_name(n)
+                        .ok_or_else(|| ArrowError::SchemaError(format!("No column named {}", n)))
+                })
+                .collect::<Result<_, _>>()?,
+            None => batch.columns().iter().collect(),
+        };
+
+        let columns: Vec<&Float64Array> = columns
+            .into_iter()
+            .enumerate()
+            .map(|(j, c)| match c.as_any().downcast_ref::<Float64Array>() {
+                Some(c) if c.null_count() == 0 => Ok(c),
+                Some(_) => Err(ArrowError::InvalidArgumentError(format!(
+                    "Column {} contains nulls",
+                    j
+                ))),
+                None => Err(ArrowError::SchemaError(format!(
+                    "Column {} has type {}, expected Float64",
+                    j,
+                    c.data_type()
+                ))),
+            })
+            .collect::<Result<_, _>>()?;
+
+        let rows = batch.num_rows();
+        let cols = columns.len();
+        let mut matrix = Vec::with_capacity(rows * cols);
+
+        for i in 0..rows {
+            columns.iter().for_each(|c| matrix.push(c.value(i)));
+        }
+
+        return Ok(Matrix { rows, cols, matrix });
+    }
+}
@@ -0,0 +1,317 @@
+use super::linalg::product;
+use super::Matrix;
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::Num;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+// Whether an operand is used as stored or as its transpose. The transpose
+// is never formed; the kernels just read the storage the other way round.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transpose {
+    No,
+    Yes,
+}
+
+// Shape of op(m) and the row-major strides for stepping along its rows and
+// its columns.
+fn op_layout<T: Default>(m: &Matrix<T>, t: Transpose) -> ((usize, usize), (usize, usize)) {
+    return match t {
+        Transpose::No => ((m.rows, m.cols), (m.cols, 1)),
+        Transpose::Yes => ((m.cols, m.rows), (1, m.cols)),
+    };
+}
+
+// C ← α op(A) op(B) + β C in place, without materialising either
+// transpose. As in BLAS, C is not read when β is zero, so it may hold
+// anything (even NaN) beforehand. Returns None if the shapes disagree.
+pub fn gemm<T>(
+    c: &mut Matrix<T>,
+    alpha: T,
+    a: &Matrix<T>,
+    transpose_a: Transpose,
+    b: &Matrix<T>,
+    transpose_b: Transpose,
+    beta: T,
+) -> Option<()>
+where
+    T: Default + Copy + Num,
+{
+    let ((m, k), (a_row, a_col)) = op_layout(a, transpose_a);
+    let ((kb, n), (b_row, b_col)) = op_layout(b, transpose_b);
+    if k != kb || c.rows != m || c.cols != n {
+        return None;
+    }
+
+    for x in c.matrix.iter_mut() {
+        *x = match beta.is_zero() {
+            true => T::zero(),
+            false => beta * *x,
+        };
+    }
+
+    if alpha.is_zero() {
+        return Some(());
+    }
+
+    for i in 0..m {
+        let row = &mut c.matrix[i * n..(i + 1) * n];
+        for p in 0..k {
+            let x = alpha * a.matrix[i * a_row + p * a_col];
+            for (j, y) in row.iter_mut().enumerate() {
+                *y = *y + x * b.matrix[p * b_row + j * b_col];
+            }
+        }
+    }
+
+    return Some(());
+}
+
+// y ← α x + y. Returns None if the lengths differ.
+pub fn axpy<T>(alpha: T, x: &[T], y: &mut [T]) -> Option<()>
+where
+    T: Copy + Num,
+{
+    if x.len() != y.len() {
+        return None;
+    }
+
+    for (yi, xi) in y.iter_mut().zip(x) {
+        *yi = *yi + alpha * *xi;
+    }
+
+    return Some(());
+}
+
+// y ← α op(A) x + β y, with the same treatment of β = 0 as `gemm`.
+pub fn gemv<T>(
+    alpha: T,
+    a: &Matrix<T>,
+    transpose_a: Transpose,
+    x: &[T],
+    beta: T,
+    y: &mut [T],
+) -> Option<()>
+where
+    T: Default + Copy + Num,
+{
+    let ((m, n), (a_row, a_col)) = op_layout(a, transpose_a);
+    if x.len() != n || y.len() != m {
+        return None;
+    }
+
+    for (i, yi) in y.iter_mut().enumerate() {
+        let dot = x.iter().enumerate().fold(T::zero(), |acc, (j, xj)| {
+            acc + a.matrix[i * a_row + j * a_col] * *xj
+        });
+        *yi = match beta.is_zero() {
+            true => alpha * dot,
+            false => alpha * dot + beta * *yi,
+        };
+    }
+
+    return Some(());
+}
+
+// Rank-one update A ← α x yᵀ + A.
+pub fn ger<T>(alpha: T, x: &[T], y: &[T], a: &mut Matrix<T>) -> Option<()>
+where
+    T: Default + Copy + Num,
+{
+    if x.len() != a.rows || y.len() != a.cols {
+        return None;
+    }
+
+    for (i, xi) in x.iter().enumerate() {
+        let scale = alpha * *xi;
+        axpy(scale, y, &mut a.matrix[i * a.cols..(i + 1) * a.cols])?;
+    }
+
+    return Some(());
+}
+
+// Multiplies a[i] · b[i] for every pair, e.g. one small transform per
+// sample. All shapes are checked before any work is done, and None is
+// returned if the batches differ in length or any pair does not conform.
+// For many pairs of one shape, `MatrixBatch::matmul` avoids the
+// per-matrix allocations.
+pub fn batched_matmul<T>(a: &[Matrix<T>], b: &[Matrix<T>]) -> Option<Vec<Matrix<T>>>
+where
+    T: Default + Copy + Num,
+{
+    if a.len() != b.len() || a.iter().zip(b).any(|(x, y)| x.cols != y.rows) {
+        return None;
+    }
+
+    return Some(a.iter().zip(b).map(|(x, y)| product(x, y)).collect());
+}
+
+// A stack of same-shaped matrices stored back to back in one buffer, so a
+// batch of small products walks memory linearly instead of chasing one
+// allocation per matrix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MatrixBatch<T> {
+    len: usize,
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> MatrixBatch<T>
+where
+    T: Default + Copy,
+{
+    pub fn new(len: usize, rows: usize, cols: usize) -> Self {
+        return MatrixBatch {
+            len,
+            rows,
+            cols,
+            data: vec![T::default(); len * rows * cols],
+        };
+    }
+
+    // Copies the matrices into one buffer. Returns None if their shapes
+    // differ; an empty slice gives an empty 0 x 0 batch.
+    pub fn from_matrices(matrices: &[Matrix<T>]) -> Option<Self> {
+        let (rows, cols) = matrices.first().map_or((0, 0), |m| (m.rows, m.cols));
+        if matrices.iter().any(|m| m.rows != rows || m.cols != cols) {
+            return None;
+        }
+
+        return Some(MatrixBatch {
+            len: matrices.len(),
+            rows,
+            cols,
+            data: matrices
+                .iter()
+                .flat_map(|m| m.matrix.iter().copied())
+                .collect(),
+        });
+    }
+
+    pub fn len(&self) -> usize {
+        return self.len;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len == 0;
+    }
+
+    // Shape of each matrix in the batch.
+    pub fn shape(&self) -> (usize, usize) {
+        return (self.rows, self.cols);
+    }
+
+    // Row-major entries of the i-th matrix.
+    pub fn get(&self, i: usize) -> Option<&[T]> {
+        let size = self.rows * self.cols;
+
+        return match i < self.len {
+            true => Some(&self.data[i * size..(i + 1) * size]),
+            false => None,
+        };
+    }
+
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut [T]> {
+        let size = self.rows * self.cols;
+
+        return match i < self.len {
+            true => Some(&mut self.data[i * size..(i + 1) * size]),
+            false => None,
+        };
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        return self.data.as_slice();
+    }
+
+    pub fn to_matrices(&self) -> Vec<Matrix<T>> {
+        return (0..self.len)
+            .map(|i| Matrix {
+                rows: self.rows,
+                cols: self.cols,
+                matrix: self.get(i).unwrap().to_vec(),
+            })
+            .collect();
+    }
+}
+
+impl<T> MatrixBatch<T>
+where
+    T: Default + Copy + Num,
+{
+    // Shape of the batched product, or None if the batches differ in
+    // length or the matrices do not conform.
+    fn product_shape(&self, other: &MatrixBatch<T>) -> Option<(usize, usize, usize)> {
+        if self.len != other.len || self.cols != other.rows {
+            return None;
+        }
+
+        return Some((self.rows, self.cols, other.cols));
+    }
+
+    // self[i] · other[i] for every i, written into one contiguous batch.
+    pub fn matmul(&self, other: &MatrixBatch<T>) -> Option<MatrixBatch<T>> {
+        let (m, k, n) = self.product_shape(other)?;
+        let mut result = MatrixBatch {
+            len: self.len,
+            rows: m,
+            cols: n,
+            data: vec![T::zero(); self.len * m * n],
+        };
+
+        if m * n > 0 && k > 0 {
+            let out = result.data.chunks_exact_mut(m * n);
+            for ((c, a), b) in out
+                .zip(self.data.chunks_exact(m * k))
+                .zip(other.data.chunks_exact(k * n))
+            {
+                product_into(a, b, c, k, n);
+            }
+        }
+
+        return Some(result);
+    }
+
+    // Same as `matmul`, with the pairs spread over the rayon thread pool.
+    #[cfg(feature = "rayon")]
+    pub fn par_matmul(&self, other: &MatrixBatch<T>) -> Option<MatrixBatch<T>>
+    where
+        T: Send + Sync,
+    {
+        let (m, k, n) = self.product_shape(other)?;
+        let mut result = MatrixBatch {
+            len: self.len,
+            rows: m,
+            cols: n,
+            data: vec![T::zero(); self.len * m * n],
+        };
+
+        if m * n > 0 && k > 0 {
+            result
+                .data
+                .par_chunks_exact_mut(m * n)
+                .zip(self.data.par_chunks_exact(m * k))
+                .zip(other.data.par_chunks_exact(k * n))
+                .for_each(|((c, a), b)| product_into(a, b, c, k, n));
+        }
+
+        return Some(result);
+    }
+}
+
+// c += a · b for row-major a (m x k), b (k x n) and c (m x n).
+fn product_into<T>(a: &[T], b: &[T], c: &mut [T], k: usize, n: usize)
+where
+    T: Copy + Num,
+{
+    for (row, a_row) in c.chunks_exact_mut(n).zip(a.chunks_exact(k)) {
+        for (x, b_row) in a_row.iter().zip(b.chunks_exact(n)) {
+            for (y, z) in row.iter_mut().zip(b_row) {
+                *y = *y + *x * *z;
+            }
+        }
+    }
+}
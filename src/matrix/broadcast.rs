@@ -0,0 +1,77 @@
+use super::{Axis, Matrix};
+use num_traits::Num;
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy,
+{
+    // Combines `v` with every row (`Axis::Rows`, one entry per column) or
+    // every column (`Axis::Cols`, one entry per row) as `f(entry, v[k])`,
+    // without tiling `v` into a full matrix. Returns None if the length of
+    // `v` does not match.
+    pub fn broadcast_op<F>(&self, axis: Axis, v: &[T], f: F) -> Option<Matrix<T>>
+    where
+        F: Fn(T, T) -> T,
+    {
+        let (_, len) = self.lane_shape(axis);
+        if v.len() != len {
+            return None;
+        }
+
+        let matrix = self
+            .matrix
+            .iter()
+            .enumerate()
+            .map(|(k, x)| match axis {
+                Axis::Rows => f(*x, v[k % self.cols]),
+                Axis::Cols => f(*x, v[k / self.cols]),
+            })
+            .collect();
+
+        return Some(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            matrix,
+        });
+    }
+}
+
+// `_row_vector` variants take one entry per column and apply it down each
+// column, e.g. subtracting the column means. `_col_vector` variants take
+// one entry per row.
+impl<T> Matrix<T>
+where
+    T: Default + Copy + Num,
+{
+    pub fn add_row_vector(&self, v: &[T]) -> Option<Matrix<T>> {
+        return self.broadcast_op(Axis::Rows, v, |x, y| x + y);
+    }
+
+    pub fn sub_row_vector(&self, v: &[T]) -> Option<Matrix<T>> {
+        return self.broadcast_op(Axis::Rows, v, |x, y| x - y);
+    }
+
+    pub fn mul_row_vector(&self, v: &[T]) -> Option<Matrix<T>> {
+        return self.broadcast_op(Axis::Rows, v, |x, y| x * y);
+    }
+
+    pub fn div_row_vector(&self, v: &[T]) -> Option<Matrix<T>> {
+        return self.broadcast_op(Axis::Rows, v, |x, y| x / y);
+    }
+
+    pub fn add_col_vector(&self, v: &[T]) -> Option<Matrix<T>> {
+        return self.broadcast_op(Axis::Cols, v, |x, y| x + y);
+    }
+
+    pub fn sub_col_vector(&self, v: &[T]) -> Option<Matrix<T>> {
+        return self.broadcast_op(Axis::Cols, v, |x, y| x - y);
+    }
+
+    pub fn mul_col_vector(&self, v: &[T]) -> Option<Matrix<T>> {
+        return self.broadcast_op(Axis::Cols, v, |x, y| x * y);
+    }
+
+    pub fn div_col_vector(&self, v: &[T]) -> Option<Matrix<T>> {
+        return self.broadcast_op(Axis::Cols, v, |x, y| x / y);
+    }
+}
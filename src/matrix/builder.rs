@@ -0,0 +1,186 @@
+use super::Matrix;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::Display;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildError {
+    MissingShape,
+    RowLength {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    RowCount {
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            BuildError::MissingShape => {
+                write!(
+                    f,
+                    "The number of columns must be set when no rows are pushed"
+                )
+            }
+            BuildError::RowLength {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Row {} has {} entries, expected {}",
+                row, found, expected
+            ),
+            BuildError::RowCount { expected, found } => {
+                write!(f, "Expected {} rows, found {}", expected, found)
+            }
+        };
+    }
+}
+
+impl core::error::Error for BuildError {}
+
+// Builds a matrix either from a declared shape and fill value, or from rows
+// pushed one at a time into a single flat buffer. A declared shape is
+// checked against the rows pushed. `diagonal` is applied last in both
+// cases.
+#[derive(Debug, Clone)]
+pub struct MatrixBuilder<T> {
+    rows: Option<usize>,
+    cols: Option<usize>,
+    fill: Option<T>,
+    diagonal: Option<T>,
+    pushed: usize,
+    data: Vec<T>,
+}
+
+impl<T> Default for MatrixBuilder<T> {
+    fn default() -> Self {
+        return MatrixBuilder {
+            rows: None,
+            cols: None,
+            fill: None,
+            diagonal: None,
+            pushed: 0,
+            data: Vec::new(),
+        };
+    }
+}
+
+impl<T> MatrixBuilder<T>
+where
+    T: Default + Clone,
+{
+    pub fn new() -> Self {
+        return Default::default();
+    }
+
+    pub fn with_cols(cols: usize) -> Self {
+        return MatrixBuilder::new().cols(cols);
+    }
+
+    pub fn rows(mut self, rows: usize) -> Self {
+        self.rows = Some(rows);
+        return self;
+    }
+
+    pub fn cols(mut self, cols: usize) -> Self {
+        self.cols = Some(cols);
+        return self;
+    }
+
+    // Value for every entry when no rows are pushed. Defaults to
+    // `T::default()`.
+    pub fn fill(mut self, value: T) -> Self {
+        self.fill = Some(value);
+        return self;
+    }
+
+    pub fn diagonal(mut self, value: T) -> Self {
+        self.diagonal = Some(value);
+        return self;
+    }
+
+    // Reserves space for `rows` more rows once the width is known.
+    pub fn reserve_rows(&mut self, rows: usize) -> &mut Self {
+        if let Some(cols) = self.cols {
+            self.data.reserve(rows * cols);
+        }
+        return self;
+    }
+
+    pub fn push_row(&mut self, row: &[T]) -> Result<&mut Self, BuildError> {
+        let cols = *self.cols.get_or_insert(row.len());
+
+        if row.len() != cols {
+            return Err(BuildError::RowLength {
+                row: self.pushed,
+                expected: cols,
+                found: row.len(),
+            });
+        }
+
+        self.data.extend_from_slice(row);
+        self.pushed += 1;
+        return Ok(self);
+    }
+
+    pub fn num_rows(&self) -> usize {
+        return self.pushed;
+    }
+
+    pub fn num_cols(&self) -> Option<usize> {
+        return self.cols;
+    }
+
+    pub fn build(self) -> Result<Matrix<T>, BuildError> {
+        let mut result = match (self.pushed, self.rows, self.cols) {
+            (0, Some(rows), Some(cols)) => Matrix {
+                rows,
+                cols,
+                matrix: vec![self.fill.unwrap_or_default(); rows * cols],
+            },
+            // An empty stream of rows with a known width is a 0 x cols
+            // matrix rather than an error.
+            (0, None, Some(cols)) => Matrix {
+                rows: 0,
+                cols,
+                matrix: Vec::new(),
+            },
+            (0, _, None) => return Err(BuildError::MissingShape),
+            (pushed, Some(rows), _) if rows != pushed => {
+                return Err(BuildError::RowCount {
+                    expected: rows,
+                    found: pushed,
+                })
+            }
+            (pushed, _, cols) => Matrix {
+                rows: pushed,
+                cols: cols.unwrap_or(0),
+                matrix: self.data,
+            },
+        };
+
+        if let Some(value) = self.diagonal {
+            for i in 0..result.rows.min(result.cols) {
+                result.matrix[i * result.cols + i] = value.clone();
+            }
+        }
+
+        return Ok(result);
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Clone,
+{
+    pub fn builder() -> MatrixBuilder<T> {
+        return MatrixBuilder::new();
+    }
+}
@@ -0,0 +1,156 @@
+use super::Matrix;
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::Display;
+
+pub const MAGIC: [u8; 4] = *b"MTRX";
+pub const VERSION: u8 = 1;
+
+// Magic, version, dtype and two reserved bytes, then rows and cols as u64.
+// The header is a multiple of 8 bytes so the data of a memory-mapped file
+// is suitably aligned for every element type.
+pub const HEADER_LEN: usize = 24;
+
+pub trait BinaryElement: Copy {
+    const DTYPE: u8;
+    const SIZE: usize;
+
+    fn from_le_slice(bytes: &[u8]) -> Self;
+    fn extend_le_bytes(self, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_binary_element {
+    ($($t:ty => $dtype:literal),*) => {
+        $(impl BinaryElement for $t {
+            const DTYPE: u8 = $dtype;
+            const SIZE: usize = core::mem::size_of::<$t>();
+
+            fn from_le_slice(bytes: &[u8]) -> Self {
+                return <$t>::from_le_bytes(bytes.try_into().unwrap());
+            }
+
+            fn extend_le_bytes(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+        })*
+    };
+}
+
+impl_binary_element!(
+    f32 => 1, f64 => 2,
+    i8 => 3, i16 => 4, i32 => 5, i64 => 6,
+    u8 => 7, u16 => 8, u32 => 9, u64 => 10
+);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BytesError {
+    TooShort,
+    BadMagic,
+    UnsupportedVersion(u8),
+    DtypeMismatch { expected: u8, found: u8 },
+    LengthMismatch { expected: usize, found: usize },
+    DimensionsOverflow { rows: usize, cols: usize },
+    DimensionsTooLarge { rows: u64, cols: u64 },
+}
+
+impl Display for BytesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            BytesError::TooShort => write!(f, "Input is shorter than the header"),
+            BytesError::BadMagic => write!(f, "Input does not start with the matrix magic"),
+            BytesError::UnsupportedVersion(v) => write!(f, "Unsupported format version {}", v),
+            BytesError::DtypeMismatch { expected, found } => {
+                write!(f, "Expected dtype {}, found {}", expected, found)
+            }
+            BytesError::LengthMismatch { expected, found } => {
+                write!(f, "Expected {} data bytes, found {}", expected, found)
+            }
+            BytesError::DimensionsOverflow { rows, cols } => {
+                write!(f, "Dimensions {}x{} overflow usize", rows, cols)
+            }
+            BytesError::DimensionsTooLarge { rows, cols } => {
+                write!(f, "Dimensions {}x{} do not fit in usize", rows, cols)
+            }
+        };
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BytesError {}
+
+// Parses and validates a header, returning the dtype and dimensions.
+pub fn read_header(bytes: &[u8]) -> Result<(u8, usize, usize), BytesError> {
+    if bytes.len() < HEADER_LEN {
+        return Err(BytesError::TooShort);
+    }
+    if bytes[..4] != MAGIC {
+        return Err(BytesError::BadMagic);
+    }
+    if bytes[4] != VERSION {
+        return Err(BytesError::UnsupportedVersion(bytes[4]));
+    }
+
+    let rows = u64::from_le_bytes(bytes[8..16].try_into().unwrap());
+    let cols = u64::from_le_bytes(bytes[16..24].try_into().unwrap());
+    let (rows, cols) = match (usize::try_from(rows), usize::try_from(cols)) {
+        (Ok(rows), Ok(cols)) => (rows, cols),
+        _ => return Err(BytesError::DimensionsTooLarge { rows, cols }),
+    };
+
+    return Ok((bytes[5], rows, cols));
+}
+
+// Byte length of a rows x cols payload of size-byte elements, rejecting
+// headers whose dimensions overflow usize.
+pub(crate) fn data_len(rows: usize, cols: usize, size: usize) -> Result<usize, BytesError> {
+    return rows
+        .checked_mul(cols)
+        .and_then(|n| n.checked_mul(size))
+        .ok_or(BytesError::DimensionsOverflow { rows, cols });
+}
+
+pub fn write_header(out: &mut Vec<u8>, dtype: u8, rows: usize, cols: usize) {
+    out.extend_from_slice(&MAGIC);
+    out.extend_from_slice(&[VERSION, dtype, 0, 0]);
+    out.extend_from_slice(&(rows as u64).to_le_bytes());
+    out.extend_from_slice(&(cols as u64).to_le_bytes());
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + BinaryElement,
+{
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(HEADER_LEN + self.matrix.len() * T::SIZE);
+        write_header(&mut out, T::DTYPE, self.rows, self.cols);
+        self.matrix.iter().for_each(|x| x.extend_le_bytes(&mut out));
+
+        return out;
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Matrix<T>, BytesError> {
+        let (dtype, rows, cols) = read_header(bytes)?;
+
+        if dtype != T::DTYPE {
+            return Err(BytesError::DtypeMismatch {
+                expected: T::DTYPE,
+                found: dtype,
+            });
+        }
+
+        let data = &bytes[HEADER_LEN..];
+        let expected = data_len(rows, cols, T::SIZE)?;
+        if data.len() != expected {
+            return Err(BytesError::LengthMismatch {
+                expected,
+                found: data.len(),
+            });
+        }
+
+        return Ok(Matrix {
+            rows,
+            cols,
+            matrix: data.chunks_exact(T::SIZE).map(T::from_le_slice).collect(),
+        });
+    }
+}
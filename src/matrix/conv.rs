@@ -0,0 +1,296 @@
+use super::Matrix;
+use alloc::vec;
+use num_traits::{Float, Num};
+
+// Output size of a window sliding over `len` padded entries, or None if
+// the window does not fit at all.
+fn output_len(len: usize, kernel: usize, stride: usize, padding: usize) -> Option<usize> {
+    let padded = len + 2 * padding;
+    if stride == 0 || kernel == 0 || kernel > padded {
+        return None;
+    }
+
+    return Some((padded - kernel) / stride + 1);
+}
+
+// Calls `f(patch_row, output_index, image_index)` for every kernel tap
+// that lands inside the image, skipping taps that fall on padding.
+fn for_each_tap<F>(shape: (usize, usize, usize, usize), stride: usize, padding: usize, mut f: F)
+where
+    F: FnMut(usize, usize, usize),
+{
+    let (rows, cols, kernel_h, kernel_w) = shape;
+    let out_w = (cols + 2 * padding - kernel_w) / stride + 1;
+    let out_h = (rows + 2 * padding - kernel_h) / stride + 1;
+
+    for ki in 0..kernel_h {
+        for kj in 0..kernel_w {
+            for oi in 0..out_h {
+                for oj in 0..out_w {
+                    // Taps on the leading padding wrap around to huge indices.
+                    let i = (oi * stride + ki).wrapping_sub(padding);
+                    let j = (oj * stride + kj).wrapping_sub(padding);
+                    if i < rows && j < cols {
+                        f(ki * kernel_w + kj, oi * out_w + oj, i * cols + j);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy + Num,
+{
+    // Unrolls every kernel_h x kernel_w patch into a column, so that a
+    // convolution becomes one matrix product: a 1 x (kernel_h * kernel_w)
+    // flattened kernel times the result gives the output row-major. The
+    // input is zero-padded by `padding` on every side. Returns None if the
+    // kernel is larger than the padded input or `stride` is zero.
+    pub fn im2col(
+        &self,
+        kernel_h: usize,
+        kernel_w: usize,
+        stride: usize,
+        padding: usize,
+    ) -> Option<Matrix<T>> {
+        let out_h = output_len(self.rows, kernel_h, stride, padding)?;
+        let out_w = output_len(self.cols, kernel_w, stride, padding)?;
+        let cols = out_h * out_w;
+        let mut matrix = vec![T::zero(); kernel_h * kernel_w * cols];
+
+        let shape = (self.rows, self.cols, kernel_h, kernel_w);
+        for_each_tap(shape, stride, padding, |row, out, index| {
+            matrix[row * cols + out] = self.matrix[index];
+        });
+
+        return Some(Matrix {
+            rows: kernel_h * kernel_w,
+            cols,
+            matrix,
+        });
+    }
+
+    // Inverse layout of `im2col` for an image of `rows` x `cols`: every
+    // column is scattered back onto its patch and overlapping entries are
+    // summed, which is the adjoint needed for convolution gradients.
+    // Returns None if `self` does not have the shape im2col would produce.
+    pub fn col2im(
+        &self,
+        rows: usize,
+        cols: usize,
+        kernel_h: usize,
+        kernel_w: usize,
+        stride: usize,
+        padding: usize,
+    ) -> Option<Matrix<T>> {
+        let out_h = output_len(rows, kernel_h, stride, padding)?;
+        let out_w = output_len(cols, kernel_w, stride, padding)?;
+        if self.rows != kernel_h * kernel_w || self.cols != out_h * out_w {
+            return None;
+        }
+
+        let mut matrix = vec![T::zero(); rows * cols];
+
+        let shape = (rows, cols, kernel_h, kernel_w);
+        for_each_tap(shape, stride, padding, |row, out, index| {
+            matrix[index] = matrix[index] + self.matrix[row * self.cols + out];
+        });
+
+        return Some(Matrix { rows, cols, matrix });
+    }
+}
+
+// Which part of the full convolution to keep, as in SciPy's convolve2d.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConvMode {
+    // Every output with any overlap: (h + kh - 1) x (w + kw - 1).
+    Full,
+    // Same size as the input, centred on the full result.
+    Same,
+    // Only outputs where the kernel lies entirely inside the input.
+    Valid,
+}
+
+// How values outside the input are filled in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaddingMode {
+    Zero,
+    // Repeats the edge value: a a | a b c | c c.
+    Replicate,
+    // Mirrors including the edge: b a | a b c | c b.
+    Symmetric,
+    // Treats the input as periodic: b c | a b c | a b.
+    Wrap,
+}
+
+fn padded_index(index: isize, len: usize, padding: PaddingMode) -> Option<usize> {
+    let n = len as isize;
+    if (0..n).contains(&index) {
+        return Some(index as usize);
+    }
+
+    return match padding {
+        PaddingMode::Zero => None,
+        PaddingMode::Replicate => Some(index.clamp(0, n - 1) as usize),
+        PaddingMode::Symmetric => {
+            let period = 2 * n;
+            let k = index.rem_euclid(period);
+            Some(match k < n {
+                true => k,
+                false => period - 1 - k,
+            } as usize)
+        }
+        PaddingMode::Wrap => Some(index.rem_euclid(n) as usize),
+    };
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy + Num,
+{
+    // True 2-D convolution (the kernel is flipped). Returns None if either
+    // matrix is empty, or in `Valid` mode if the kernel is larger than the
+    // input in either direction.
+    pub fn convolve2d(
+        &self,
+        kernel: &Matrix<T>,
+        mode: ConvMode,
+        padding: PaddingMode,
+    ) -> Option<Matrix<T>> {
+        let (h, w, kh, kw) = (self.rows, self.cols, kernel.rows, kernel.cols);
+        if self.matrix.is_empty() || kernel.matrix.is_empty() {
+            return None;
+        }
+
+        // Offsets into the full result and the output size.
+        let (top, left, rows, cols) = match mode {
+            ConvMode::Full => (0, 0, h + kh - 1, w + kw - 1),
+            ConvMode::Same => ((kh - 1) / 2, (kw - 1) / 2, h, w),
+            ConvMode::Valid if kh <= h && kw <= w => (kh - 1, kw - 1, h - kh + 1, w - kw + 1),
+            ConvMode::Valid => return None,
+        };
+
+        let mut matrix = vec![T::zero(); rows * cols];
+
+        for (out, value) in matrix.iter_mut().enumerate() {
+            let i = (out / cols + top) as isize;
+            let j = (out % cols + left) as isize;
+            for a in 0..kh {
+                let Some(x) = padded_index(i - a as isize, h, padding) else {
+                    continue;
+                };
+                for b in 0..kw {
+                    if let Some(y) = padded_index(j - b as isize, w, padding) {
+                        *value = *value + kernel.matrix[a * kw + b] * self.matrix[x * w + y];
+                    }
+                }
+            }
+        }
+
+        return Some(Matrix { rows, cols, matrix });
+    }
+
+    // Cross-correlation, i.e. sliding the kernel without flipping it, as
+    // most image filtering and CNN libraries do.
+    pub fn correlate2d(
+        &self,
+        kernel: &Matrix<T>,
+        mode: ConvMode,
+        padding: PaddingMode,
+    ) -> Option<Matrix<T>> {
+        let flipped = Matrix {
+            rows: kernel.rows,
+            cols: kernel.cols,
+            matrix: kernel.matrix.iter().rev().cloned().collect(),
+        };
+
+        return self.convolve2d(&flipped, mode, padding);
+    }
+}
+
+// Input (row, col) that each pooled value was taken from.
+pub type PoolIndices = Matrix<(usize, usize)>;
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy + PartialOrd,
+{
+    // Maximum of every `window` (height, width) placed `stride` apart.
+    // Windows that would run past the edge are dropped. Returns None if the
+    // window is empty or larger than the input, or a stride is zero.
+    pub fn max_pool(&self, window: (usize, usize), stride: (usize, usize)) -> Option<Matrix<T>> {
+        let (pooled, _) = self.max_pool_with_indices(window, stride)?;
+
+        return Some(pooled);
+    }
+
+    // Also returns the (row, col) in the input that each maximum came from,
+    // for unpooling or routing gradients. Ties keep the first position in
+    // row-major order.
+    pub fn max_pool_with_indices(
+        &self,
+        window: (usize, usize),
+        stride: (usize, usize),
+    ) -> Option<(Matrix<T>, PoolIndices)> {
+        let positions = self.pool_windows(window, stride, |taps| {
+            taps.reduce(|best, (i, j)| {
+                match self.matrix[i * self.cols + j] > self.matrix[best.0 * self.cols + best.1] {
+                    true => (i, j),
+                    false => best,
+                }
+            })
+            .unwrap()
+        })?;
+        let pooled = positions.map(|(i, j)| self.matrix[i * self.cols + j]);
+
+        return Some((pooled, positions));
+    }
+
+    fn pool_windows<F, R>(
+        &self,
+        window: (usize, usize),
+        stride: (usize, usize),
+        f: F,
+    ) -> Option<Matrix<R>>
+    where
+        F: Fn(&mut dyn Iterator<Item = (usize, usize)>) -> R,
+        R: Default,
+    {
+        let out_h = output_len(self.rows, window.0, stride.0, 0)?;
+        let out_w = output_len(self.cols, window.1, stride.1, 0)?;
+
+        let matrix = (0..out_h * out_w)
+            .map(|out| {
+                let top = out / out_w * stride.0;
+                let left = out % out_w * stride.1;
+                let mut taps = (top..top + window.0)
+                    .flat_map(|i| (left..left + window.1).map(move |j| (i, j)));
+                f(&mut taps)
+            })
+            .collect();
+
+        return Some(Matrix {
+            rows: out_h,
+            cols: out_w,
+            matrix,
+        });
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Float,
+{
+    // Mean of every window, with the same shape rules as `max_pool`.
+    pub fn avg_pool(&self, window: (usize, usize), stride: (usize, usize)) -> Option<Matrix<T>> {
+        let count = T::from(window.0 * window.1).unwrap();
+
+        return self.pool_windows(window, stride, |taps| {
+            taps.fold(T::zero(), |acc, (i, j)| {
+                acc + self.matrix[i * self.cols + j]
+            }) / count
+        });
+    }
+}
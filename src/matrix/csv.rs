@@ -0,0 +1,248 @@
+use super::Matrix;
+use std::fmt;
+use std::fmt::Display;
+use std::io;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsvHeader {
+    None,
+    Skip,
+    Capture,
+}
+
+#[derive(Debug, Clone)]
+pub struct CsvOptions {
+    pub delimiter: char,
+    pub header: CsvHeader,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        return CsvOptions {
+            delimiter: ',',
+            header: CsvHeader::None,
+        };
+    }
+}
+
+impl CsvOptions {
+    pub fn new() -> Self {
+        return Default::default();
+    }
+
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        return self;
+    }
+
+    pub fn header(mut self, header: CsvHeader) -> Self {
+        self.header = header;
+        return self;
+    }
+}
+
+// Lines and columns are 1-based so they match what an editor shows.
+#[derive(Debug)]
+pub enum CsvError {
+    Io(io::Error),
+    Parse {
+        line: usize,
+        column: usize,
+        value: String,
+    },
+    RowLength {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            CsvError::Io(e) => write!(f, "I/O error: {}", e),
+            CsvError::Parse {
+                line,
+                column,
+                value,
+            } => write!(
+                f,
+                "Could not parse {:?} at line {}, column {}",
+                value, line, column
+            ),
+            CsvError::RowLength {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Expected {} fields at line {}, found {}",
+                expected, line, found
+            ),
+        };
+    }
+}
+
+impl std::error::Error for CsvError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        return match self {
+            CsvError::Io(e) => Some(e),
+            _ => None,
+        };
+    }
+}
+
+impl From<io::Error> for CsvError {
+    fn from(e: io::Error) -> Self {
+        return CsvError::Io(e);
+    }
+}
+
+// Splits one record, honouring double-quoted fields with "" as an escaped
+// quote. Records spanning multiple lines are not supported.
+fn split_record(line: &str, delimiter: char) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            c if c == delimiter && !in_quotes => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    fields.push(field);
+
+    return fields;
+}
+
+fn quote_field(field: &str, delimiter: char) -> String {
+    if field.contains(delimiter) || field.contains('"') || field.contains('\n') {
+        return format!("\"{}\"", field.replace('"', "\"\""));
+    }
+
+    return field.to_string();
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + FromStr,
+{
+    // Returns the parsed matrix along with the header row when the options
+    // ask for it to be captured. Blank lines are ignored.
+    pub fn from_csv_reader<R>(
+        reader: R,
+        options: CsvOptions,
+    ) -> Result<(Matrix<T>, Option<Vec<String>>), CsvError>
+    where
+        R: Read,
+    {
+        let mut headers = None;
+        let mut cols = None;
+        let mut rows = 0;
+        let mut matrix = Vec::new();
+
+        for (i, line) in BufReader::new(reader).lines().enumerate() {
+            let line = line?;
+            let line_no = i + 1;
+            let line = line.trim_end_matches('\r');
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let fields = split_record(line, options.delimiter);
+
+            if headers.is_none() && rows == 0 && options.header != CsvHeader::None {
+                cols = Some(fields.len());
+                headers = Some(fields);
+                continue;
+            }
+
+            let expected = *cols.get_or_insert(fields.len());
+            if fields.len() != expected {
+                return Err(CsvError::RowLength {
+                    line: line_no,
+                    expected,
+                    found: fields.len(),
+                });
+            }
+
+            for (j, field) in fields.iter().enumerate() {
+                let value = field.trim().parse::<T>().map_err(|_| CsvError::Parse {
+                    line: line_no,
+                    column: j + 1,
+                    value: field.clone(),
+                })?;
+                matrix.push(value);
+            }
+            rows += 1;
+        }
+
+        let headers = match options.header {
+            CsvHeader::Capture => headers,
+            _ => None,
+        };
+
+        return Ok((
+            Matrix {
+                rows,
+                cols: cols.unwrap_or(0),
+                matrix,
+            },
+            headers,
+        ));
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Display,
+{
+    pub fn write_csv<W>(&self, writer: W) -> io::Result<()>
+    where
+        W: Write,
+    {
+        return self.write_csv_with(writer, &CsvOptions::default(), None);
+    }
+
+    pub fn write_csv_with<W>(
+        &self,
+        mut writer: W,
+        options: &CsvOptions,
+        headers: Option<&[&str]>,
+    ) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let delimiter = options.delimiter.to_string();
+
+        if let Some(headers) = headers {
+            let line: Vec<String> = headers
+                .iter()
+                .map(|h| quote_field(h, options.delimiter))
+                .collect();
+            writeln!(writer, "{}", line.join(&delimiter))?;
+        }
+
+        if self.cols > 0 {
+            for row in self.matrix.chunks(self.cols) {
+                let line: Vec<String> = row
+                    .iter()
+                    .map(|x| quote_field(&x.to_string(), options.delimiter))
+                    .collect();
+                writeln!(writer, "{}", line.join(&delimiter))?;
+            }
+        }
+
+        return writer.flush();
+    }
+}
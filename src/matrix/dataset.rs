@@ -0,0 +1,164 @@
+use super::permutation::PermutationMatrix;
+use super::Matrix;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+// Row-oriented helpers for matrices holding one observation per row and
+// one feature per column.
+impl<T> Matrix<T>
+where
+    T: Default + Copy,
+{
+    // Keeps only the rows for which `f` returns true, in their original
+    // order, reusing the allocation.
+    pub fn retain_rows<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&[T]) -> bool,
+    {
+        let cols = self.cols;
+        let mut kept = 0;
+
+        for i in 0..self.rows {
+            if f(&self.matrix[i * cols..(i + 1) * cols]) {
+                self.matrix
+                    .copy_within(i * cols..(i + 1) * cols, kept * cols);
+                kept += 1;
+            }
+        }
+
+        self.matrix.truncate(kept * cols);
+        self.rows = kept;
+    }
+
+    // The listed rows in the given order; indices may repeat. Returns None
+    // if any index is out of range.
+    pub fn select_rows(&self, indices: &[usize]) -> Option<Matrix<T>> {
+        if indices.iter().any(|i| *i >= self.rows) {
+            return None;
+        }
+
+        let cols = self.cols;
+        let mut matrix = Vec::with_capacity(indices.len() * cols);
+        for i in indices {
+            matrix.extend_from_slice(&self.matrix[i * cols..(i + 1) * cols]);
+        }
+
+        return Some(Matrix {
+            rows: indices.len(),
+            cols,
+            matrix,
+        });
+    }
+
+    pub fn select_cols(&self, indices: &[usize]) -> Option<Matrix<T>> {
+        if indices.iter().any(|j| *j >= self.cols) {
+            return None;
+        }
+
+        let matrix = (0..self.rows)
+            .flat_map(|i| indices.iter().map(move |j| self.matrix[i * self.cols + j]))
+            .collect();
+
+        return Some(Matrix {
+            rows: self.rows,
+            cols: indices.len(),
+            matrix,
+        });
+    }
+
+    // Splits into the listed rows, in the given order, and the remaining
+    // rows in their original order, e.g. one cross-validation fold and the
+    // rest. Returns None if any index is out of range.
+    pub fn split_rows_at(&self, indices: &[usize]) -> Option<(Matrix<T>, Matrix<T>)> {
+        let selected = self.select_rows(indices)?;
+
+        let mut chosen = vec![false; self.rows];
+        indices.iter().for_each(|i| chosen[*i] = true);
+        let rest: Vec<usize> = (0..self.rows).filter(|i| !chosen[*i]).collect();
+
+        return Some((selected, self.select_rows(&rest)?));
+    }
+
+    // Stable sort of the rows by `compare`. Returns the permutation P that
+    // was applied, so P * A is the sorted matrix and `P.apply_vector` puts
+    // labels kept alongside into the same order.
+    pub fn sort_rows_by<F>(&mut self, mut compare: F) -> PermutationMatrix
+    where
+        F: FnMut(&[T], &[T]) -> Ordering,
+    {
+        let cols = self.cols;
+        let mut order: Vec<usize> = (0..self.rows).collect();
+        order.sort_by(|a, b| {
+            compare(
+                &self.matrix[a * cols..(a + 1) * cols],
+                &self.matrix[b * cols..(b + 1) * cols],
+            )
+        });
+
+        let permutation = PermutationMatrix::from_indices(order).unwrap();
+        self.matrix = permutation.apply_rows(self).unwrap().matrix;
+
+        return permutation;
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy + PartialOrd,
+{
+    // Stable ascending sort of the rows by column `col`, with values that
+    // are not comparable to themselves (NaN) placed last. Returns None if
+    // the column is out of range.
+    pub fn sort_rows_by_col(&mut self, col: usize) -> Option<PermutationMatrix> {
+        if col >= self.cols {
+            return None;
+        }
+
+        return Some(self.sort_rows_by(|a, b| nan_last(&a[col], &b[col])));
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy + PartialEq,
+{
+    // The distinct rows in order of first appearance. Rows are compared
+    // with `==`, so a row containing NaN never matches another.
+    pub fn unique_rows(&self) -> Matrix<T> {
+        return self.unique_rows_with_inverse().0;
+    }
+
+    // Also returns, for every original row, the index of its row in the
+    // deduplicated matrix. Compares each row against the rows kept so far,
+    // so the cost grows with rows times distinct rows.
+    pub fn unique_rows_with_inverse(&self) -> (Matrix<T>, Vec<usize>) {
+        let cols = self.cols;
+        let row = |i: usize| &self.matrix[i * cols..(i + 1) * cols];
+        let mut first: Vec<usize> = Vec::new();
+        let mut inverse = Vec::with_capacity(self.rows);
+
+        for i in 0..self.rows {
+            match first.iter().position(|k| row(*k) == row(i)) {
+                Some(k) => inverse.push(k),
+                None => {
+                    inverse.push(first.len());
+                    first.push(i);
+                }
+            }
+        }
+
+        return (self.select_rows(&first).unwrap(), inverse);
+    }
+}
+
+// A total order for sorting: the usual order, with every value that is not
+// comparable to itself after all the others.
+fn nan_last<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    return match (a.partial_cmp(b), a.partial_cmp(a), b.partial_cmp(b)) {
+        (Some(ordering), _, _) => ordering,
+        (None, None, None) => Ordering::Equal,
+        (None, None, _) => Ordering::Greater,
+        (None, _, _) => Ordering::Less,
+    };
+}
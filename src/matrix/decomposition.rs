@@ -0,0 +1,641 @@
+use super::diagonal::DiagonalMatrix;
+use super::linalg::product;
+use super::Matrix;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::iter::zip;
+use num_complex::Complex;
+use num_traits::Float;
+
+// Francis steps allowed per row before `schur` gives up.
+const SCHUR_MAX_ITERATIONS: usize = 30;
+const POLAR_MAX_ITERATIONS: usize = 100;
+// Golub–Kahan SVD steps allowed per singular value.
+const SVD_MAX_ITERATIONS: usize = 75;
+
+// Which side of the matrix a reflection or rotation multiplies from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    // Acts on rows: M ← G M.
+    Left,
+    // Acts on columns: M ← M G.
+    Right,
+}
+
+// Unit vector v such that (I - 2 v vᵀ) x is a multiple of the first unit
+// vector. The sign is chosen to avoid cancellation. A zero x gives a zero
+// v, which leaves everything unchanged.
+pub fn householder_vector<T: Float>(x: &[T]) -> Vec<T> {
+    let mut v = x.to_vec();
+    let norm = v.iter().fold(T::zero(), |acc, x| acc + *x * *x).sqrt();
+    if norm == T::zero() {
+        return v;
+    }
+
+    let alpha = match v[0] >= T::zero() {
+        true => -norm,
+        false => norm,
+    };
+    v[0] = v[0] - alpha;
+
+    let length = v.iter().fold(T::zero(), |acc, x| acc + *x * *x).sqrt();
+    v.iter_mut().for_each(|x| *x = *x / length);
+
+    return v;
+}
+
+// Applies the reflection I - 2 v vᵀ to rows (or columns) offset..offset +
+// v.len() of `m`, touching every column (or row). Returns None if the
+// reflector does not fit.
+pub fn apply_householder<T>(m: &mut Matrix<T>, v: &[T], offset: usize, side: Side) -> Option<()>
+where
+    T: Default + Float,
+{
+    let (rows, cols) = (m.rows, m.cols);
+
+    match side {
+        Side::Left if offset + v.len() <= rows => {
+            for j in 0..cols {
+                reflect(
+                    v,
+                    (offset..offset + v.len()).map(|i| i * cols + j),
+                    &mut m.matrix,
+                );
+            }
+        }
+        Side::Right if offset + v.len() <= cols => {
+            for i in 0..rows {
+                reflect(
+                    v,
+                    (offset..offset + v.len()).map(|j| i * cols + j),
+                    &mut m.matrix,
+                );
+            }
+        }
+        _ => return None,
+    }
+
+    return Some(());
+}
+
+// Applies I - 2 v vᵀ to the entries of `data` at `indices`, which must
+// yield exactly v.len() positions.
+pub(crate) fn reflect<T, I>(v: &[T], indices: I, data: &mut [T])
+where
+    T: Float,
+    I: Iterator<Item = usize> + Clone,
+{
+    let dot = zip(v, indices.clone()).fold(T::zero(), |acc, (v, i)| acc + *v * data[i]);
+    let two_dot = dot + dot;
+
+    for (v, i) in zip(v, indices) {
+        data[i] = data[i] - two_dot * *v;
+    }
+}
+
+// (c, s, r) with r = hypot(a, b) such that G = [c s; -s c] maps (a, b) to
+// (r, 0). Both zero gives the identity rotation.
+pub fn givens<T: Float>(a: T, b: T) -> (T, T, T) {
+    if b == T::zero() {
+        return (T::one(), T::zero(), a);
+    }
+
+    let r = a.hypot(b);
+
+    return (a / r, b / r, r);
+}
+
+// Applies the rotation G = [c s; -s c] in the (i, k) plane: to rows i and k
+// from the left (M ← G M), or from the right as M ← M Gᵀ so that columns i
+// and k are combined the same way. Returns None for out-of-range indices.
+pub fn apply_givens<T>(m: &mut Matrix<T>, i: usize, k: usize, c: T, s: T, side: Side) -> Option<()>
+where
+    T: Default + Float,
+{
+    let (rows, cols) = (m.rows, m.cols);
+    let (count, stride, lanes, lane_step) = match side {
+        Side::Left => (rows, cols, cols, 1),
+        Side::Right => (cols, 1, rows, cols),
+    };
+    if i >= count || k >= count {
+        return None;
+    }
+
+    for lane in 0..lanes {
+        let (p, q) = (i * stride + lane * lane_step, k * stride + lane * lane_step);
+        let (x, y) = (m.matrix[p], m.matrix[q]);
+        m.matrix[p] = c * x + s * y;
+        m.matrix[q] = c * y - s * x;
+    }
+
+    return Some(());
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Float,
+{
+    // Upper Hessenberg form H and orthogonal Q with A = Q H Qᵀ, by
+    // Householder similarity transforms. H has zeros below the first
+    // subdiagonal, which is what the QR eigenvalue iteration starts from.
+    // Returns None for non-square input.
+    pub fn hessenberg(&self) -> Option<(Matrix<T>, Matrix<T>)> {
+        let n = self.rows;
+        if self.cols != n {
+            return None;
+        }
+
+        let mut h = self.map(|x| *x);
+        let mut q = Matrix::identity(n);
+
+        for k in 0..n.saturating_sub(2) {
+            let x: Vec<T> = (k + 1..n).map(|i| h.matrix[i * n + k]).collect();
+            let v = householder_vector(&x);
+
+            apply_householder(&mut h, &v, k + 1, Side::Left)?;
+            apply_householder(&mut h, &v, k + 1, Side::Right)?;
+            apply_householder(&mut q, &v, k + 1, Side::Right)?;
+
+            for i in k + 2..n {
+                h.matrix[i * n + k] = T::zero();
+            }
+        }
+
+        return Some((h, q));
+    }
+
+    // Golub–Kahan bidiagonalization A = U B Vᵀ with U (m x m) and V (n x n)
+    // orthogonal and B (m x n) upper bidiagonal: only the diagonal and the
+    // first superdiagonal are nonzero. Reflections alternate between
+    // clearing a column below the diagonal and a row right of the
+    // superdiagonal. This is the first stage of `svd`.
+    pub fn bidiagonalize(&self) -> Option<(Matrix<T>, Matrix<T>, Matrix<T>)> {
+        let (m, n) = (self.rows, self.cols);
+        let mut b = self.map(|x| *x);
+        let mut u = Matrix::identity(m);
+        let mut v = Matrix::identity(n);
+
+        for k in 0..m.min(n) {
+            let x: Vec<T> = (k..m).map(|i| b.matrix[i * n + k]).collect();
+            let h = householder_vector(&x);
+            apply_householder(&mut b, &h, k, Side::Left)?;
+            apply_householder(&mut u, &h, k, Side::Right)?;
+            for i in k + 1..m {
+                b.matrix[i * n + k] = T::zero();
+            }
+
+            if k + 1 < n {
+                let h = householder_vector(&b.matrix[k * n + k + 1..(k + 1) * n]);
+                apply_householder(&mut b, &h, k + 1, Side::Right)?;
+                apply_householder(&mut v, &h, k + 1, Side::Right)?;
+                for j in k + 2..n {
+                    b.matrix[k * n + j] = T::zero();
+                }
+            }
+        }
+
+        return Some((u, b, v));
+    }
+
+    // Singular value decomposition A = U Σ Vᵀ with U (m x m) and V (n x n)
+    // orthogonal and Σ (m x n) diagonal. Returns U, the min(m, n) singular
+    // values in descending order, and V. Bidiagonalizes A, then runs
+    // implicit-shift Golub–Kahan QR steps with Wilkinson shifts on the
+    // bidiagonal until every superdiagonal entry is negligible. Returns
+    // None if the iteration fails to converge.
+    pub fn svd(&self) -> Option<(Matrix<T>, Vec<T>, Matrix<T>)> {
+        if self.rows < self.cols {
+            let (u, s, v) = self.transpose().svd()?;
+            return Some((v, s, u));
+        }
+
+        let (mut u, mut b, mut v) = self.bidiagonalize()?;
+        let n = b.cols;
+        let eps = T::epsilon();
+        let at = |b: &Matrix<T>, i: usize, j: usize| b.matrix[i * n + j];
+        let norm = (0..n).fold(T::zero(), |acc, i| {
+            let e = match i + 1 < n {
+                true => at(&b, i, i + 1).abs(),
+                false => T::zero(),
+            };
+            return acc.max(at(&b, i, i).abs() + e);
+        });
+
+        let mut hi = n.saturating_sub(1);
+        let mut total = 0;
+
+        while hi > 0 {
+            for i in 0..hi {
+                let scale = at(&b, i, i).abs() + at(&b, i + 1, i + 1).abs();
+                if at(&b, i, i + 1).abs() <= eps * scale {
+                    b.matrix[i * n + i + 1] = T::zero();
+                }
+            }
+            if at(&b, hi - 1, hi) == T::zero() {
+                hi -= 1;
+                continue;
+            }
+
+            let mut lo = hi - 1;
+            while lo > 0 && at(&b, lo - 1, lo) != T::zero() {
+                lo -= 1;
+            }
+
+            total += 1;
+            if total > SVD_MAX_ITERATIONS * n {
+                return None;
+            }
+
+            // A zero on the diagonal lets the block split once its row (or,
+            // at the bottom, its column) is cleared with rotations.
+            if let Some(k) = (lo..=hi).find(|k| at(&b, *k, *k).abs() <= eps * norm) {
+                b.matrix[k * n + k] = T::zero();
+                match k < hi {
+                    true => {
+                        for j in k + 1..=hi {
+                            let (c, s, _) = givens(at(&b, j, j), at(&b, k, j));
+                            apply_givens(&mut b, j, k, c, s, Side::Left)?;
+                            apply_givens(&mut u, j, k, c, s, Side::Right)?;
+                            b.matrix[k * n + j] = T::zero();
+                        }
+                    }
+                    false => {
+                        for j in (lo..hi).rev() {
+                            let (c, s, _) = givens(at(&b, j, j), at(&b, j, hi));
+                            apply_givens(&mut b, j, hi, c, s, Side::Right)?;
+                            apply_givens(&mut v, j, hi, c, s, Side::Right)?;
+                            b.matrix[j * n + hi] = T::zero();
+                        }
+                    }
+                }
+                continue;
+            }
+
+            // Wilkinson shift from the trailing 2 x 2 block of BᵀB.
+            let (d1, d2, e1) = (at(&b, hi - 1, hi - 1), at(&b, hi, hi), at(&b, hi - 1, hi));
+            let e0 = match hi - 1 > lo {
+                true => at(&b, hi - 2, hi - 1),
+                false => T::zero(),
+            };
+            let (t11, t12, t22) = (d1 * d1 + e0 * e0, d1 * e1, d2 * d2 + e1 * e1);
+            let delta = (t11 - t22) / T::from(2).unwrap();
+            let root = delta.hypot(t12);
+            let shift = match delta >= T::zero() {
+                true => t22 - t12 * t12 / (delta + root),
+                false => t22 - t12 * t12 / (delta - root),
+            };
+            let shift = match shift.is_finite() {
+                true => shift,
+                false => t22,
+            };
+
+            // Chase the bulge down the bidiagonal.
+            let d0 = at(&b, lo, lo);
+            let (mut y, mut z) = (d0 * d0 - shift, d0 * at(&b, lo, lo + 1));
+            for k in lo..hi {
+                let (c, s, _) = givens(y, z);
+                apply_givens(&mut b, k, k + 1, c, s, Side::Right)?;
+                apply_givens(&mut v, k, k + 1, c, s, Side::Right)?;
+                if k > lo {
+                    b.matrix[(k - 1) * n + k + 1] = T::zero();
+                }
+
+                let (c, s, _) = givens(at(&b, k, k), at(&b, k + 1, k));
+                apply_givens(&mut b, k, k + 1, c, s, Side::Left)?;
+                apply_givens(&mut u, k, k + 1, c, s, Side::Right)?;
+                b.matrix[(k + 1) * n + k] = T::zero();
+
+                if k + 1 < hi {
+                    y = at(&b, k, k + 1);
+                    z = at(&b, k, k + 2);
+                }
+            }
+        }
+
+        // Make the singular values non-negative, then sort them in
+        // descending order along with the columns of U and V.
+        for i in 0..n {
+            if at(&b, i, i) < T::zero() {
+                b.matrix[i * n + i] = -b.matrix[i * n + i];
+                for r in 0..n {
+                    v.matrix[r * n + i] = -v.matrix[r * n + i];
+                }
+            }
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|i, j| {
+            at(&b, *j, *j)
+                .partial_cmp(&at(&b, *i, *i))
+                .unwrap_or(Ordering::Equal)
+        });
+
+        let values = order.iter().map(|i| at(&b, *i, *i)).collect();
+        let m = u.rows;
+        let mut sorted_u = u.map(|x| *x);
+        let mut sorted_v = v.map(|x| *x);
+        for (to, from) in order.iter().enumerate() {
+            for r in 0..m {
+                sorted_u.matrix[r * m + to] = u.matrix[r * m + from];
+            }
+            for r in 0..n {
+                sorted_v.matrix[r * n + to] = v.matrix[r * n + from];
+            }
+        }
+
+        return Some((sorted_u, values, sorted_v));
+    }
+
+    // Real Schur decomposition A = Q T Qᵀ with Q orthogonal and T quasi
+    // upper triangular: 1 x 1 diagonal blocks for real eigenvalues, 2 x 2
+    // blocks for complex conjugate pairs. Reduces to Hessenberg form, then
+    // runs Francis double-shift QR steps with deflation. Returns None for
+    // non-square input or if the iteration fails to converge.
+    pub fn schur(&self) -> Option<(Matrix<T>, Matrix<T>)> {
+        let (mut t, mut q) = self.hessenberg()?;
+        let n = t.rows;
+        let eps = T::epsilon();
+        let at = |t: &Matrix<T>, i: usize, j: usize| t.matrix[i * n + j];
+
+        let mut hi = n.saturating_sub(1);
+        let mut iterations = 0;
+        let mut total = 0;
+
+        while hi > 0 {
+            // Find the start of the unreduced block ending at `hi`.
+            let mut lo = hi;
+            while lo > 0 {
+                let scale = at(&t, lo - 1, lo - 1).abs() + at(&t, lo, lo).abs();
+                if at(&t, lo, lo - 1).abs() <= eps * scale {
+                    t.matrix[lo * n + lo - 1] = T::zero();
+                    break;
+                }
+                lo -= 1;
+            }
+
+            if lo == hi {
+                hi -= 1;
+                iterations = 0;
+                continue;
+            }
+            if lo + 1 == hi {
+                split_real_pair(&mut t, &mut q, lo)?;
+                hi = hi.saturating_sub(2);
+                iterations = 0;
+                continue;
+            }
+
+            iterations += 1;
+            total += 1;
+            if total > SCHUR_MAX_ITERATIONS * n {
+                return None;
+            }
+
+            // Shifts are the eigenvalues of the trailing 2 x 2 block, with an
+            // occasional ad hoc shift to break cycles.
+            let (sum, product) = match iterations % 11 == 10 {
+                true => {
+                    let w = at(&t, hi, hi - 1).abs() + at(&t, hi - 1, hi - 2).abs();
+                    let sum = w * T::from(1.5).unwrap();
+                    (sum, w * w)
+                }
+                false => {
+                    let (a, b) = (at(&t, hi - 1, hi - 1), at(&t, hi - 1, hi));
+                    let (c, d) = (at(&t, hi, hi - 1), at(&t, hi, hi));
+                    (a + d, a * d - b * c)
+                }
+            };
+
+            let (h00, h01) = (at(&t, lo, lo), at(&t, lo, lo + 1));
+            let (h10, h11, h21) = (
+                at(&t, lo + 1, lo),
+                at(&t, lo + 1, lo + 1),
+                at(&t, lo + 2, lo + 1),
+            );
+            let mut x = h00 * h00 + h01 * h10 - sum * h00 + product;
+            let mut y = h10 * (h00 + h11 - sum);
+            let mut z = h10 * h21;
+
+            // Chase the bulge down the subdiagonal.
+            for k in lo..hi - 1 {
+                let v = householder_vector(&[x, y, z]);
+                apply_householder(&mut t, &v, k, Side::Left)?;
+                apply_householder(&mut t, &v, k, Side::Right)?;
+                apply_householder(&mut q, &v, k, Side::Right)?;
+                if k > lo {
+                    t.matrix[(k + 1) * n + k - 1] = T::zero();
+                    t.matrix[(k + 2) * n + k - 1] = T::zero();
+                }
+
+                x = at(&t, k + 1, k);
+                y = at(&t, k + 2, k);
+                if k + 3 <= hi {
+                    z = at(&t, k + 3, k);
+                }
+            }
+
+            let (c, s, _) = givens(x, y);
+            apply_givens(&mut t, hi - 1, hi, c, s, Side::Left)?;
+            apply_givens(&mut t, hi - 1, hi, c, s, Side::Right)?;
+            apply_givens(&mut q, hi - 1, hi, c, s, Side::Right)?;
+            if hi >= 2 {
+                t.matrix[hi * n + hi - 2] = T::zero();
+            }
+        }
+
+        return Some((q, t));
+    }
+
+    // Same as `eigenvalues`, but balances the matrix first. Recommended for
+    // matrices whose entries span many orders of magnitude.
+    pub fn eigenvalues_balanced(&self) -> Option<Vec<Complex<T>>> {
+        let (balanced, _) = self.balance()?;
+
+        return balanced.eigenvalues();
+    }
+
+    // Eigenvalues read off the real Schur form, in diagonal order. Complex
+    // pairs come out next to each other, positive imaginary part first.
+    pub fn eigenvalues(&self) -> Option<Vec<Complex<T>>> {
+        let (_, t) = self.schur()?;
+        let n = t.rows;
+        let mut values = Vec::with_capacity(n);
+        let mut i = 0;
+
+        while i < n {
+            if i + 1 < n && t.matrix[(i + 1) * n + i] != T::zero() {
+                let (a, b) = (t.matrix[i * n + i], t.matrix[i * n + i + 1]);
+                let (c, d) = (t.matrix[(i + 1) * n + i], t.matrix[(i + 1) * n + i + 1]);
+                let half = T::from(0.5).unwrap();
+                let mean = (a + d) * half;
+                let disc = ((a - d) * half).powi(2) + b * c;
+                let im = (-disc).max(T::zero()).sqrt();
+                values.push(Complex::new(mean, im));
+                values.push(Complex::new(mean, -im));
+                i += 2;
+            } else {
+                values.push(Complex::new(t.matrix[i * n + i], T::zero()));
+                i += 1;
+            }
+        }
+
+        return Some(values);
+    }
+
+    // Polar decomposition A = U P of a square nonsingular matrix, with U
+    // orthogonal and P symmetric positive definite. U is the closest
+    // orthogonal matrix to A, which is the rotation estimate wanted in
+    // Procrustes problems. Uses the scaled Newton iteration
+    // X ← (ζ X + X⁻ᵀ / ζ) / 2. Returns None for non-square or singular A.
+    pub fn polar(&self) -> Option<(Matrix<T>, Matrix<T>)> {
+        let n = self.rows;
+        if self.cols != n {
+            return None;
+        }
+
+        let frobenius = |m: &Matrix<T>| {
+            m.matrix
+                .iter()
+                .fold(T::zero(), |acc, x| acc + *x * *x)
+                .sqrt()
+        };
+        let identity = Matrix::identity(n);
+        let half = T::from(0.5).unwrap();
+        let tolerance = T::from(4 * n.max(1)).unwrap() * T::epsilon();
+        let mut x = self.map(|v| *v);
+
+        for _ in 0..POLAR_MAX_ITERATIONS {
+            let inverse_t = x.solve(&identity)?.transpose();
+            let (norm, inverse_norm) = (frobenius(&x), frobenius(&inverse_t));
+            let zeta = (inverse_norm / norm).sqrt();
+
+            let next = Matrix {
+                rows: n,
+                cols: n,
+                matrix: zip(&x.matrix, &inverse_t.matrix)
+                    .map(|(a, b)| half * (zeta * *a + *b / zeta))
+                    .collect(),
+            };
+            let change = zip(&next.matrix, &x.matrix)
+                .fold(T::zero(), |acc, (a, b)| acc + (*a - *b) * (*a - *b));
+            x = next;
+
+            if change.sqrt() <= tolerance * frobenius(&x) {
+                let p = product(&x.transpose(), self);
+                let p = Matrix {
+                    rows: n,
+                    cols: n,
+                    matrix: (0..n * n)
+                        .map(|k| half * (p.matrix[k] + p.matrix[(k % n) * n + k / n]))
+                        .collect(),
+                };
+                return Some((x, p));
+            }
+        }
+
+        return None;
+    }
+
+    // Diagonal similarity B = D⁻¹ A D that makes each row and column of B
+    // have comparable norms, as in LAPACK's gebal without permutation. The
+    // scale factors are powers of two, so B is exact. Balancing leaves the
+    // eigenvalues unchanged but often makes them much more accurate for
+    // badly scaled matrices; eigenvectors x of B map back as D x. Returns
+    // None for non-square input.
+    pub fn balance(&self) -> Option<(Matrix<T>, DiagonalMatrix<T>)> {
+        let n = self.rows;
+        if self.cols != n {
+            return None;
+        }
+
+        let mut b = self.map(|x| *x);
+        let mut d = vec![T::one(); n];
+        let radix = T::from(2.0).unwrap();
+        let radix2 = radix * radix;
+        let threshold = T::from(0.95).unwrap();
+        let mut converged = false;
+
+        while !converged {
+            converged = true;
+
+            for (i, scale) in d.iter_mut().enumerate() {
+                let (mut c, r) = (0..n)
+                    .filter(|j| *j != i)
+                    .fold((T::zero(), T::zero()), |(c, r), j| {
+                        (c + b.matrix[j * n + i].abs(), r + b.matrix[i * n + j].abs())
+                    });
+                // As in LAPACK's gebal, rows and columns with an infinite
+                // or NaN norm are left unscaled; the loops below would
+                // otherwise never terminate.
+                if c == T::zero() || r == T::zero() || !(c + r).is_finite() {
+                    continue;
+                }
+
+                let total = c + r;
+                let mut f = T::one();
+                while c < r / radix {
+                    f = f * radix;
+                    c = c * radix2;
+                }
+                while c >= r * radix {
+                    f = f / radix;
+                    c = c / radix2;
+                }
+
+                if (c + r) / f < threshold * total {
+                    converged = false;
+                    *scale = *scale * f;
+                    for j in 0..n {
+                        b.matrix[i * n + j] = b.matrix[i * n + j] / f;
+                        b.matrix[j * n + i] = b.matrix[j * n + i] * f;
+                    }
+                }
+            }
+        }
+
+        return Some((b, DiagonalMatrix::new(d)));
+    }
+}
+
+// Rotates the 2 x 2 diagonal block at (p, p) to upper triangular form when
+// its eigenvalues are real, applying the same rotation to the rest of T
+// and to Q. Blocks with complex eigenvalues are left as they are.
+fn split_real_pair<T>(t: &mut Matrix<T>, q: &mut Matrix<T>, p: usize) -> Option<()>
+where
+    T: Default + Float,
+{
+    let n = t.rows;
+    let (a, b) = (t.matrix[p * n + p], t.matrix[p * n + p + 1]);
+    let (c, d) = (t.matrix[(p + 1) * n + p], t.matrix[(p + 1) * n + p + 1]);
+    let half = T::from(0.5).unwrap();
+    let gap = (a - d) * half;
+    let disc = gap * gap + b * c;
+    if disc < T::zero() {
+        return Some(());
+    }
+
+    // The eigenvalue further from d, which avoids cancellation, and an
+    // eigenvector for it taken from whichever row is better conditioned.
+    let root = disc.sqrt();
+    let lambda = (a + d) * half
+        + match gap >= T::zero() {
+            true => root,
+            false => -root,
+        };
+    let (x, y) = match b.abs() + (lambda - a).abs() >= (lambda - d).abs() + c.abs() {
+        true => (b, lambda - a),
+        false => (lambda - d, c),
+    };
+    if x == T::zero() && y == T::zero() {
+        return Some(());
+    }
+
+    let (cos, sin, _) = givens(x, y);
+    apply_givens(t, p, p + 1, cos, sin, Side::Left)?;
+    apply_givens(t, p, p + 1, cos, sin, Side::Right)?;
+    apply_givens(q, p, p + 1, cos, sin, Side::Right)?;
+    t.matrix[(p + 1) * n + p] = T::zero();
+
+    return Some(());
+}
@@ -0,0 +1,160 @@
+use super::Matrix;
+use alloc::vec::Vec;
+use num_traits::Num;
+
+// Only the diagonal is stored, so every operation here is linear in the
+// number of entries it touches.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiagonalMatrix<T> {
+    diagonal: Vec<T>,
+}
+
+impl<T> DiagonalMatrix<T>
+where
+    T: Default + Copy,
+{
+    pub fn new(diagonal: Vec<T>) -> Self {
+        return DiagonalMatrix { diagonal };
+    }
+
+    // Takes the main diagonal of a square matrix; the rest is ignored.
+    pub fn from_dense(m: &Matrix<T>) -> Option<Self> {
+        if m.rows != m.cols {
+            return None;
+        }
+
+        let diagonal = (0..m.rows).map(|i| m.matrix[i * m.cols + i]).collect();
+        return Some(DiagonalMatrix { diagonal });
+    }
+
+    pub fn size(&self) -> usize {
+        return self.diagonal.len();
+    }
+
+    pub fn values(&self) -> &[T] {
+        return &self.diagonal;
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        let n = self.size();
+        let mut result = Matrix::new_empty(n, n);
+
+        for (i, x) in self.diagonal.iter().enumerate() {
+            result.matrix[i * n + i] = *x;
+        }
+
+        return result;
+    }
+}
+
+impl<T> DiagonalMatrix<T>
+where
+    T: Default + Copy + Num,
+{
+    pub fn identity(size: usize) -> Self {
+        return DiagonalMatrix::new((0..size).map(|_| T::one()).collect());
+    }
+
+    pub fn multiply(&self, d: &DiagonalMatrix<T>) -> Option<DiagonalMatrix<T>> {
+        if self.size() != d.size() {
+            return None;
+        }
+
+        let diagonal = self
+            .diagonal
+            .iter()
+            .zip(&d.diagonal)
+            .map(|(a, b)| *a * *b)
+            .collect();
+        return Some(DiagonalMatrix { diagonal });
+    }
+
+    // Returns None if any diagonal entry is zero.
+    pub fn inverse(&self) -> Option<DiagonalMatrix<T>> {
+        let diagonal = self
+            .diagonal
+            .iter()
+            .map(|x| match x.is_zero() {
+                true => None,
+                false => Some(T::one() / *x),
+            })
+            .collect::<Option<Vec<T>>>()?;
+
+        return Some(DiagonalMatrix { diagonal });
+    }
+
+    pub fn pow(&self, exponent: u32) -> DiagonalMatrix<T> {
+        let diagonal = self
+            .diagonal
+            .iter()
+            .map(|x| num_traits::pow(*x, exponent as usize))
+            .collect();
+
+        return DiagonalMatrix { diagonal };
+    }
+
+    // Returns None on a size mismatch or a zero on the diagonal.
+    pub fn solve(&self, b: &[T]) -> Option<Vec<T>> {
+        if b.len() != self.size() {
+            return None;
+        }
+
+        return self
+            .diagonal
+            .iter()
+            .zip(b)
+            .map(|(d, x)| match d.is_zero() {
+                true => None,
+                false => Some(*x / *d),
+            })
+            .collect();
+    }
+
+    // D * A, which scales row i of A by the i-th diagonal entry.
+    pub fn multiply_dense(&self, m: &Matrix<T>) -> Option<Matrix<T>> {
+        if m.rows != self.size() {
+            return None;
+        }
+
+        let matrix = (0..m.matrix.len())
+            .map(|k| self.diagonal[k / m.cols] * m.matrix[k])
+            .collect();
+
+        return Some(Matrix {
+            rows: m.rows,
+            cols: m.cols,
+            matrix,
+        });
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy + Num,
+{
+    // A * D, which scales column j of A by the j-th diagonal entry.
+    pub fn multiply_diagonal(&self, d: &DiagonalMatrix<T>) -> Option<Matrix<T>> {
+        if self.cols != d.size() {
+            return None;
+        }
+
+        let matrix = (0..self.matrix.len())
+            .map(|k| self.matrix[k] * d.diagonal[k % self.cols])
+            .collect();
+
+        return Some(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            matrix,
+        });
+    }
+}
+
+impl<T> From<DiagonalMatrix<T>> for Matrix<T>
+where
+    T: Default + Copy,
+{
+    fn from(d: DiagonalMatrix<T>) -> Self {
+        return d.to_dense();
+    }
+}
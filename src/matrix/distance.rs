@@ -0,0 +1,92 @@
+use super::Matrix;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::zip;
+use num_traits::Float;
+
+// Rows per tile in `cdist`, so a block of each input stays in cache while
+// every pair between them is visited.
+const BLOCK: usize = 64;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Metric {
+    Euclidean,
+    SquaredEuclidean,
+    Manhattan,
+    Chebyshev,
+    // 1 - cos(angle). Zero vectors give NaN.
+    Cosine,
+}
+
+impl Metric {
+    pub fn distance<T: Float>(&self, x: &[T], y: &[T]) -> T {
+        let pairs = zip(x, y).map(|(a, b)| (*a, *b));
+
+        return match self {
+            Metric::Euclidean => Metric::SquaredEuclidean.distance(x, y).sqrt(),
+            Metric::SquaredEuclidean => {
+                pairs.fold(T::zero(), |acc, (a, b)| acc + (a - b) * (a - b))
+            }
+            Metric::Manhattan => pairs.fold(T::zero(), |acc, (a, b)| acc + (a - b).abs()),
+            Metric::Chebyshev => pairs.fold(T::zero(), |acc, (a, b)| acc.max((a - b).abs())),
+            Metric::Cosine => {
+                let (dot, xx, yy) = pairs
+                    .fold((T::zero(), T::zero(), T::zero()), |(d, p, q), (a, b)| {
+                        (d + a * b, p + a * a, q + b * b)
+                    });
+                T::one() - dot / (xx.sqrt() * yy.sqrt())
+            }
+        };
+    }
+}
+
+// Distance between every row of `a` and every row of `b`, as an
+// a.rows x b.rows matrix. Returns None if the rows differ in length.
+pub fn cdist<T>(a: &Matrix<T>, b: &Matrix<T>, metric: Metric) -> Option<Matrix<T>>
+where
+    T: Default + Float,
+{
+    if a.cols != b.cols {
+        return None;
+    }
+
+    let (n, m, d) = (a.rows, b.rows, a.cols);
+    let mut matrix = vec![T::zero(); n * m];
+
+    for bi in (0..n).step_by(BLOCK) {
+        for bj in (0..m).step_by(BLOCK) {
+            for i in bi..(bi + BLOCK).min(n) {
+                let x = &a.matrix[i * d..(i + 1) * d];
+                for j in bj..(bj + BLOCK).min(m) {
+                    matrix[i * m + j] = metric.distance(x, &b.matrix[j * d..(j + 1) * d]);
+                }
+            }
+        }
+    }
+
+    return Some(Matrix {
+        rows: n,
+        cols: m,
+        matrix,
+    });
+}
+
+// Condensed pairwise distances between the rows of `a`: entry for pair
+// (i, j) with i < j, ordered (0, 1), (0, 2), ..., (1, 2), ... as in
+// SciPy, so n rows give n * (n - 1) / 2 values.
+pub fn pdist<T>(a: &Matrix<T>, metric: Metric) -> Vec<T>
+where
+    T: Default + Float,
+{
+    let (n, d) = (a.rows, a.cols);
+    let mut result = Vec::with_capacity(n * n.saturating_sub(1) / 2);
+
+    for i in 0..n {
+        let x = &a.matrix[i * d..(i + 1) * d];
+        for j in i + 1..n {
+            result.push(metric.distance(x, &a.matrix[j * d..(j + 1) * d]));
+        }
+    }
+
+    return result;
+}
@@ -0,0 +1,53 @@
+use super::Matrix;
+use num_traits::Float;
+
+// Elementwise float functions. Each is a plain loop over the flat buffer,
+// which the compiler can vectorize.
+impl<T> Matrix<T>
+where
+    T: Default + Float,
+{
+    pub fn exp(&self) -> Matrix<T> {
+        return self.map(|x| x.exp());
+    }
+
+    pub fn ln(&self) -> Matrix<T> {
+        return self.map(|x| x.ln());
+    }
+
+    pub fn sqrt(&self) -> Matrix<T> {
+        return self.map(|x| x.sqrt());
+    }
+
+    pub fn abs(&self) -> Matrix<T> {
+        return self.map(|x| x.abs());
+    }
+
+    pub fn powf(&self, p: T) -> Matrix<T> {
+        return self.map(|x| x.powf(p));
+    }
+
+    // NaN entries stay NaN.
+    pub fn clamp(&self, lo: T, hi: T) -> Matrix<T> {
+        return self.map(|x| match x.is_nan() {
+            true => *x,
+            false => x.max(lo).min(hi),
+        });
+    }
+
+    // 1 / (1 + e^-x), evaluated as e^x / (1 + e^x) for negative x so that
+    // large negative inputs do not overflow.
+    pub fn sigmoid(&self) -> Matrix<T> {
+        return self.map(|x| match *x >= T::zero() {
+            true => T::one() / (T::one() + (-*x).exp()),
+            false => x.exp() / (T::one() + x.exp()),
+        });
+    }
+
+    pub fn relu(&self) -> Matrix<T> {
+        return self.map(|x| match *x > T::zero() {
+            true => *x,
+            false => T::zero(),
+        });
+    }
+}
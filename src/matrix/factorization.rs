@@ -0,0 +1,163 @@
+use super::linalg::back_substitute;
+use super::permutation::PermutationMatrix;
+use super::symmetric::SymmetricMatrix;
+use super::triangular::TriangularMatrix;
+use super::Matrix;
+use alloc::vec::Vec;
+use num_traits::Float;
+
+// P A = L U kept in one packed n×n buffer (L below the diagonal with an
+// implicit unit diagonal, U on and above it), so any number of right-hand
+// sides can be solved against one factorization.
+#[derive(Debug, PartialEq)]
+pub struct LuFactorization<T>
+where
+    T: Default,
+{
+    packed: Matrix<T>,
+    permutation: PermutationMatrix,
+}
+
+// A = L Lᵀ for a symmetric positive definite A.
+#[derive(Debug, PartialEq)]
+pub struct CholeskyFactorization<T>
+where
+    T: Default,
+{
+    l: TriangularMatrix<T>,
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Float,
+{
+    // Returns None for non-square or numerically singular matrices, using
+    // the same pivot threshold as `solve`.
+    pub fn lu_factor(&self) -> Option<LuFactorization<T>> {
+        let (packed, perm) = self.lu_packed()?;
+        let n = self.rows;
+        let scale = self
+            .matrix
+            .iter()
+            .fold(T::zero(), |acc, v| acc.max(v.abs()));
+        let tiny = T::epsilon() * scale * T::from(n).unwrap();
+
+        if (0..n).any(|k| packed[k * n + k].is_nan() || packed[k * n + k].abs() <= tiny) {
+            return None;
+        }
+
+        return Some(LuFactorization {
+            packed: Matrix {
+                rows: n,
+                cols: n,
+                matrix: packed,
+            },
+            permutation: PermutationMatrix::from_indices(perm)?,
+        });
+    }
+
+    // Solves A X_i = B_i for every right-hand side matrix in `bs`,
+    // factorizing A once.
+    pub fn solve_batch(&self, bs: &[Matrix<T>]) -> Option<Vec<Matrix<T>>> {
+        return self.lu_factor()?.solve_batch(bs);
+    }
+}
+
+impl<T> LuFactorization<T>
+where
+    T: Default + Float,
+{
+    pub fn permutation(&self) -> &PermutationMatrix {
+        return &self.permutation;
+    }
+
+    // X with A X = B, one column per right-hand side. Returns None if B
+    // does not have one row per unknown.
+    pub fn solve(&self, b: &Matrix<T>) -> Option<Matrix<T>> {
+        let mut y = self.permutation.apply_rows(b)?;
+        forward_substitute(&self.packed, &mut y, false);
+
+        return back_substitute(&self.packed, y);
+    }
+
+    pub fn solve_batch(&self, bs: &[Matrix<T>]) -> Option<Vec<Matrix<T>>> {
+        return bs.iter().map(|b| self.solve(b)).collect();
+    }
+
+    pub fn determinant(&self) -> T {
+        let n = self.packed.rows;
+        let sign = match self.permutation.sign() {
+            1 => T::one(),
+            _ => -T::one(),
+        };
+
+        return (0..n).fold(sign, |acc, k| acc * self.packed.matrix[k * n + k]);
+    }
+}
+
+impl<T> SymmetricMatrix<T>
+where
+    T: Default + Float,
+{
+    // Returns None unless the matrix is positive definite.
+    pub fn cholesky_factor(&self) -> Option<CholeskyFactorization<T>> {
+        return Some(CholeskyFactorization {
+            l: self.cholesky()?,
+        });
+    }
+}
+
+impl<T> CholeskyFactorization<T>
+where
+    T: Default + Float,
+{
+    pub fn l(&self) -> &TriangularMatrix<T> {
+        return &self.l;
+    }
+
+    // X with A X = B, from L Y = B and then Lᵀ X = Y.
+    pub fn solve(&self, b: &Matrix<T>) -> Option<Matrix<T>> {
+        let n = self.l.size();
+        let mut x = self.l.solve_matrix(b)?;
+
+        // Lᵀ(k, i) is L(i, k), which is always inside the stored triangle
+        // for i >= k.
+        let l = |i: usize, k: usize| self.l.at(i, k).copied().unwrap_or_default();
+        let m = x.cols;
+        for k in (0..n).rev() {
+            for j in 0..m {
+                let sum = (k + 1..n).fold(x.matrix[k * m + j], |acc, i| {
+                    acc - l(i, k) * x.matrix[i * m + j]
+                });
+                x.matrix[k * m + j] = sum / l(k, k);
+            }
+        }
+
+        return Some(x);
+    }
+
+    pub fn solve_batch(&self, bs: &[Matrix<T>]) -> Option<Vec<Matrix<T>>> {
+        return bs.iter().map(|b| self.solve(b)).collect();
+    }
+}
+
+// Overwrites Y with L⁻¹ Y for the lower triangle of `l`, which has a unit
+// diagonal unless `use_diagonal` is set. Assumes the shapes agree.
+fn forward_substitute<T>(l: &Matrix<T>, y: &mut Matrix<T>, use_diagonal: bool)
+where
+    T: Default + Float,
+{
+    let (n, m) = (l.rows, y.cols);
+
+    for k in 0..n {
+        for j in 0..m {
+            let sum = (0..k).fold(y.matrix[k * m + j], |acc, i| {
+                acc - l.matrix[k * n + i] * y.matrix[i * m + j]
+            });
+            y.matrix[k * m + j] = match use_diagonal {
+                true => sum / l.matrix[k * n + k],
+                false => sum,
+            };
+        }
+    }
+}
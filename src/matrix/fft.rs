@@ -0,0 +1,150 @@
+use super::Matrix;
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::{Float, FloatConst, Zero};
+
+pub use num_complex::Complex;
+
+// In-place iterative radix-2 transform. `data.len()` must be a power of
+// two.
+fn radix2<T: Float + FloatConst>(data: &mut [Complex<T>]) {
+    let n = data.len();
+    if n <= 1 {
+        return;
+    }
+
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let angle = -T::TAU() / T::from(len).unwrap();
+        let step = Complex::from_polar(T::one(), angle);
+        for chunk in data.chunks_mut(len) {
+            let mut w = Complex::new(T::one(), T::zero());
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            for (a, b) in lo.iter_mut().zip(hi.iter_mut()) {
+                let t = *b * w;
+                *b = *a - t;
+                *a = *a + t;
+                w = w * step;
+            }
+        }
+        len *= 2;
+    }
+}
+
+// Forward DFT of any length. Powers of two go straight to `radix2`; other
+// lengths use Bluestein's algorithm, which rewrites the DFT as a
+// convolution of power-of-two length.
+fn fft_in_place<T: Float + FloatConst>(data: &mut [Complex<T>]) {
+    let n = data.len();
+    if n.is_power_of_two() || n == 0 {
+        return radix2(data);
+    }
+
+    let m = (2 * n - 1).next_power_of_two();
+    // exp(-iπk²/n), reducing k² mod 2n first to keep the angle accurate.
+    let chirp: Vec<Complex<T>> = (0..n)
+        .map(|k| {
+            let k2 = (k * k) % (2 * n);
+            Complex::from_polar(
+                T::one(),
+                -T::PI() * T::from(k2).unwrap() / T::from(n).unwrap(),
+            )
+        })
+        .collect();
+
+    let mut a = vec![Complex::zero(); m];
+    for ((a, x), w) in a.iter_mut().zip(data.iter()).zip(&chirp) {
+        *a = *x * *w;
+    }
+
+    let mut b = vec![Complex::zero(); m];
+    b[0] = chirp[0].conj();
+    for k in 1..n {
+        b[k] = chirp[k].conj();
+        b[m - k] = chirp[k].conj();
+    }
+
+    radix2(&mut a);
+    radix2(&mut b);
+    a.iter_mut()
+        .zip(&b)
+        .for_each(|(x, y)| *x = (*x * *y).conj());
+    // The inverse transform, via conj(fft(conj(x))) / m.
+    radix2(&mut a);
+
+    let scale = T::one() / T::from(m).unwrap();
+    for ((x, y), w) in data.iter_mut().zip(&a).zip(&chirp) {
+        *x = y.conj().scale(scale) * *w;
+    }
+}
+
+fn ifft_in_place<T: Float + FloatConst>(data: &mut [Complex<T>]) {
+    data.iter_mut().for_each(|x| *x = x.conj());
+    fft_in_place(data);
+
+    let scale = T::one() / T::from(data.len()).unwrap();
+    data.iter_mut().for_each(|x| *x = x.conj().scale(scale));
+}
+
+impl<T> Matrix<Complex<T>>
+where
+    T: Default + Float + FloatConst,
+{
+    // 2-D discrete Fourier transform, computed as 1-D transforms of every
+    // row and then every column. Any size works; powers of two are fastest.
+    pub fn fft2(&self) -> Matrix<Complex<T>> {
+        return self.transform(fft_in_place);
+    }
+
+    // Inverse of `fft2`, including the 1 / (rows * cols) normalization.
+    pub fn ifft2(&self) -> Matrix<Complex<T>> {
+        return self.transform(ifft_in_place);
+    }
+
+    fn transform(&self, f: fn(&mut [Complex<T>])) -> Matrix<Complex<T>> {
+        let mut matrix = self.matrix.clone();
+
+        if self.cols > 0 {
+            matrix.chunks_mut(self.cols).for_each(f);
+        }
+
+        let mut column = vec![Complex::zero(); self.rows];
+        for j in 0..self.cols {
+            for (i, x) in column.iter_mut().enumerate() {
+                *x = matrix[i * self.cols + j];
+            }
+            f(&mut column);
+            for (i, x) in column.iter().enumerate() {
+                matrix[i * self.cols + j] = *x;
+            }
+        }
+
+        return Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            matrix,
+        };
+    }
+
+    pub fn re(&self) -> Matrix<T> {
+        return self.map(|x| x.re);
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Float + FloatConst,
+{
+    // Full complex spectrum of a real matrix.
+    pub fn fft2_real(&self) -> Matrix<Complex<T>> {
+        return self.map(|x| Complex::new(*x, T::zero())).fft2();
+    }
+}
@@ -0,0 +1,557 @@
+use super::Matrix;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::{Alignment, Display};
+use core::iter::zip;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BracketStyle {
+    Square,
+    Round,
+    Pipe,
+    None,
+}
+
+impl BracketStyle {
+    fn delimiters(self) -> (&'static str, &'static str) {
+        return match self {
+            BracketStyle::Square => ("[ ", " ]"),
+            BracketStyle::Round => ("( ", " )"),
+            BracketStyle::Pipe => ("| ", " |"),
+            BracketStyle::None => ("", ""),
+        };
+    }
+}
+
+// Only used by `format_float_with`. `Auto` switches to scientific notation
+// when entries span more than three orders of magnitude or are very large
+// or very small, the same rule numpy uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Notation {
+    Fixed,
+    Scientific,
+    Engineering,
+    Auto,
+}
+
+// `width` is a minimum; columns still grow to fit the widest entry so the
+// output always lines up.
+#[derive(Debug, Clone)]
+pub struct FormatOptions {
+    pub precision: Option<usize>,
+    pub width: Option<usize>,
+    pub align: Alignment,
+    pub separator: String,
+    pub brackets: BracketStyle,
+    pub notation: Notation,
+    pub shared_exponent: bool,
+    pub row_numbers: bool,
+    pub col_labels: Option<Vec<String>>,
+    pub threshold: Option<usize>,
+    pub edge_items: usize,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        return FormatOptions {
+            precision: None,
+            width: None,
+            align: Alignment::Right,
+            separator: String::from(" "),
+            brackets: BracketStyle::Square,
+            notation: Notation::Fixed,
+            shared_exponent: false,
+            row_numbers: false,
+            col_labels: None,
+            threshold: None,
+            edge_items: 3,
+        };
+    }
+}
+
+impl FormatOptions {
+    pub fn new() -> Self {
+        return Default::default();
+    }
+
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        return self;
+    }
+
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        return self;
+    }
+
+    pub fn align(mut self, align: Alignment) -> Self {
+        self.align = align;
+        return self;
+    }
+
+    pub fn separator(mut self, separator: &str) -> Self {
+        self.separator = separator.to_string();
+        return self;
+    }
+
+    pub fn brackets(mut self, brackets: BracketStyle) -> Self {
+        self.brackets = brackets;
+        return self;
+    }
+
+    pub fn notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        return self;
+    }
+
+    pub fn shared_exponent(mut self, shared_exponent: bool) -> Self {
+        self.shared_exponent = shared_exponent;
+        return self;
+    }
+
+    pub fn row_numbers(mut self, row_numbers: bool) -> Self {
+        self.row_numbers = row_numbers;
+        return self;
+    }
+
+    // Columns beyond the end of `labels` are left unlabelled.
+    pub fn col_labels(mut self, labels: &[&str]) -> Self {
+        self.col_labels = Some(labels.iter().map(|l| l.to_string()).collect());
+        return self;
+    }
+
+    // Matrices with more than `threshold` entries only show `edge_items`
+    // rows and columns from each end.
+    pub fn threshold(mut self, threshold: usize) -> Self {
+        self.threshold = Some(threshold);
+        return self;
+    }
+
+    pub fn edge_items(mut self, edge_items: usize) -> Self {
+        self.edge_items = edge_items;
+        return self;
+    }
+
+    // Picks up `{:.3}`, `{:10}` and `{:<10}` style flags. Like numpy,
+    // anything over 1000 entries is truncated.
+    pub fn from_formatter(f: &fmt::Formatter) -> Self {
+        let mut options = FormatOptions::new().threshold(1000);
+        options.precision = f.precision();
+        options.width = f.width();
+        options.align = f.align().unwrap_or(Alignment::Right);
+
+        return options;
+    }
+}
+
+fn format_value<T: Display>(x: &T, precision: Option<usize>) -> String {
+    return match precision {
+        Some(p) => format!("{:.p$}", x),
+        None => x.to_string(),
+    };
+}
+
+fn pad(cell: &str, width: usize, align: Alignment) -> String {
+    return match align {
+        Alignment::Left => format!("{:<width$}", cell),
+        Alignment::Right => format!("{:>width$}", cell),
+        Alignment::Center => format!("{:^width$}", cell),
+    };
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Display,
+{
+    pub(crate) fn format_cells(&self, precision: Option<usize>) -> Vec<String> {
+        return self
+            .matrix
+            .iter()
+            .map(|x| format_value(x, precision))
+            .collect();
+    }
+
+    // Rows of a matrix without columns are all empty, so the count cannot
+    // be recovered from the data length.
+    fn stored_rows(&self) -> usize {
+        return match self.cols {
+            0 => self.rows,
+            cols => self.matrix.len() / cols,
+        };
+    }
+
+    // One line per row, each terminated by a newline. Matrices with no
+    // entries print as empty brackets.
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        return layout(self.matrix.len(), self.cols, options, |i| {
+            format_value(&self.matrix[i], options.precision)
+        });
+    }
+
+    // The shape followed by the corner blocks of the matrix, whatever its
+    // size.
+    pub fn summary(&self) -> String {
+        let options = FormatOptions::new().threshold(0);
+
+        return format!(
+            "Matrix {}x{}\n{}",
+            self.rows,
+            self.cols,
+            self.format_with(&options)
+        );
+    }
+}
+
+// Indices to show along one axis, with None marking where the hidden
+// middle section is elided.
+fn shown(n: usize, truncate: bool, edge_items: usize) -> Vec<Option<usize>> {
+    if !truncate || n <= 2 * edge_items {
+        return (0..n).map(Some).collect();
+    }
+
+    return (0..edge_items)
+        .map(Some)
+        .chain([None])
+        .chain((n - edge_items..n).map(Some))
+        .collect();
+}
+
+// Columns are padded to a common width and rows wrapped in brackets, with
+// an optional gutter of row numbers and a line of column labels on top.
+// `cell` is only called for the entries that end up being shown.
+pub(crate) fn layout<F>(len: usize, cols: usize, options: &FormatOptions, cell: F) -> String
+where
+    F: Fn(usize) -> String,
+{
+    let (open, close) = options.brackets.delimiters();
+
+    if len == 0 || cols == 0 {
+        return format!("{}{}\n", open.trim_end(), close.trim_start());
+    }
+
+    let rows = len / cols;
+    let truncate = options.threshold.is_some_and(|t| len > t);
+    let shown_rows = shown(rows, truncate, options.edge_items);
+    let shown_cols = shown(cols, truncate, options.edge_items);
+
+    let cells: Vec<Vec<String>> = shown_rows
+        .iter()
+        .map(|i| {
+            shown_cols
+                .iter()
+                .map(|j| match (i, j) {
+                    (Some(i), Some(j)) => cell(i * cols + j),
+                    (Some(_), None) => String::from("…"),
+                    (None, Some(_)) => String::from("⋮"),
+                    (None, None) => String::from("⋱"),
+                })
+                .collect()
+        })
+        .collect();
+
+    let labels = options.col_labels.as_deref().unwrap_or(&[]);
+    let label = |j: &Option<usize>| match j {
+        Some(j) => labels.get(*j).map_or("", |l| l.as_str()),
+        None => "",
+    };
+
+    let width = cells
+        .iter()
+        .flatten()
+        .map(|c| c.as_str())
+        .chain(shown_cols.iter().map(label))
+        .map(|c| c.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(options.width.unwrap_or(0));
+    let gutter = match options.row_numbers {
+        true => rows.saturating_sub(1).to_string().len(),
+        false => 0,
+    };
+
+    let mut result = String::new();
+
+    if options.col_labels.is_some() {
+        let line: Vec<String> = shown_cols
+            .iter()
+            .map(|j| pad(label(j), width, options.align))
+            .collect();
+
+        if options.row_numbers {
+            result.push_str(&format!("{:gutter$} ", ""));
+        }
+        result.push_str(&format!("{:1$}", "", open.chars().count()));
+        result.push_str(line.join(&options.separator).trim_end());
+        result.push('\n');
+    }
+
+    for (i, row) in zip(&shown_rows, &cells) {
+        let row: Vec<String> = row.iter().map(|c| pad(c, width, options.align)).collect();
+
+        match (options.row_numbers, i) {
+            (true, Some(i)) => result.push_str(&format!("{:>gutter$} ", i)),
+            (true, None) => result.push_str(&format!("{:>gutter$} ", "")),
+            (false, _) => (),
+        }
+        result.push_str(open);
+        result.push_str(&row.join(&options.separator));
+        result.push_str(close);
+        result.push('\n');
+    }
+
+    return result;
+}
+
+// Decimal exponent of the leading digit, read back from the `{:e}` output
+// so this works without a float math library.
+fn exponent(x: f64) -> i32 {
+    let s = format!("{:e}", x);
+
+    return s[s.find('e').unwrap() + 1..].parse().unwrap();
+}
+
+fn pow10(e: i32) -> f64 {
+    let mut result = 1.0;
+    for _ in 0..e.unsigned_abs() {
+        result *= 10.0;
+    }
+
+    return match e < 0 {
+        true => 1.0 / result,
+        false => result,
+    };
+}
+
+fn format_fixed(x: f64, precision: Option<usize>) -> String {
+    return match precision {
+        Some(p) => format!("{:.p$}", x),
+        None => x.to_string(),
+    };
+}
+
+fn format_scientific(x: f64, precision: Option<usize>) -> String {
+    return match precision {
+        Some(p) => format!("{:.p$e}", x),
+        None => format!("{:e}", x),
+    };
+}
+
+fn format_engineering(x: f64, precision: Option<usize>) -> String {
+    if x == 0.0 || !x.is_finite() {
+        return format_fixed(x, precision);
+    }
+
+    let e = exponent(x).div_euclid(3) * 3;
+    return format!("{}e{}", format_fixed(x / pow10(e), precision), e);
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy + Into<f64>,
+{
+    // Like `format_with`, but also honours `notation` and
+    // `shared_exponent`. With a shared exponent the common power of ten
+    // is printed once on its own line, followed by the scaled entries.
+    pub fn format_float_with(&self, options: &FormatOptions) -> String {
+        let values: Vec<f64> = self.matrix.iter().map(|x| (*x).into()).collect();
+        let magnitudes: Vec<f64> = values
+            .iter()
+            .map(|x| x.abs())
+            .filter(|x| *x != 0.0 && x.is_finite())
+            .collect();
+        let max = magnitudes.iter().cloned().fold(0.0, f64::max);
+        let min = magnitudes.iter().cloned().fold(f64::INFINITY, f64::min);
+
+        let notation = match options.notation {
+            Notation::Auto if max >= 1e8 || min < 1e-4 || max / min > 1e3 => Notation::Scientific,
+            Notation::Auto => Notation::Fixed,
+            notation => notation,
+        };
+
+        if options.shared_exponent && notation != Notation::Fixed && max > 0.0 {
+            let e = match notation {
+                Notation::Engineering => exponent(max).div_euclid(3) * 3,
+                _ => exponent(max),
+            };
+            let scale = pow10(e);
+            let body = layout(values.len(), self.cols, options, |i| {
+                format_fixed(values[i] / scale, options.precision)
+            });
+
+            return format!("1e{} *\n{}", e, body);
+        }
+
+        return layout(values.len(), self.cols, options, |i| match notation {
+            Notation::Scientific => format_scientific(values[i], options.precision),
+            Notation::Engineering => format_engineering(values[i], options.precision),
+            _ => format_fixed(values[i], options.precision),
+        });
+    }
+}
+
+impl<T> fmt::LowerExp for Matrix<T>
+where
+    T: Default + fmt::LowerExp,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.matrix.is_empty() {
+            return write!(f, "\n[] ({}x{})\n", self.rows, self.cols);
+        }
+
+        let options = FormatOptions::from_formatter(f);
+        let body = layout(self.matrix.len(), self.cols, &options, |i| {
+            match options.precision {
+                Some(p) => format!("{:.p$e}", self.matrix[i]),
+                None => format!("{:e}", self.matrix[i]),
+            }
+        });
+
+        return write!(f, "\n{}", body);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LatexStyle {
+    Matrix,
+    Bmatrix,
+    Pmatrix,
+    Vmatrix,
+}
+
+impl LatexStyle {
+    fn environment(self) -> &'static str {
+        return match self {
+            LatexStyle::Matrix => "matrix",
+            LatexStyle::Bmatrix => "bmatrix",
+            LatexStyle::Pmatrix => "pmatrix",
+            LatexStyle::Vmatrix => "vmatrix",
+        };
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Display,
+{
+    // Emits an amsmath environment with one row per line.
+    pub fn to_latex(&self, style: LatexStyle, precision: Option<usize>) -> String {
+        let cells = self.format_cells(precision);
+        let env = style.environment();
+
+        if cells.is_empty() || self.cols == 0 {
+            return format!("\\begin{{{}}}\n\\end{{{}}}", env, env);
+        }
+
+        let rows: Vec<String> = cells.chunks(self.cols).map(|row| row.join(" & ")).collect();
+
+        return format!(
+            "\\begin{{{}}}\n{}\n\\end{{{}}}",
+            env,
+            rows.join(" \\\\\n"),
+            env
+        );
+    }
+}
+
+fn escape_markdown(s: &str) -> String {
+    return s.replace('|', "\\|");
+}
+
+fn escape_html(s: &str) -> String {
+    return s
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;");
+}
+
+fn label(labels: Option<&[&str]>, i: usize) -> String {
+    return match labels.and_then(|l| l.get(i)) {
+        Some(l) => l.to_string(),
+        None => i.to_string(),
+    };
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Display,
+{
+    // Markdown tables need a header row, so columns are numbered from zero
+    // when no labels are given. Missing labels fall back to the index too.
+    // A matrix with no columns and no row labels has no table at all.
+    pub fn to_markdown_table(
+        &self,
+        row_labels: Option<&[&str]>,
+        col_labels: Option<&[&str]>,
+    ) -> String {
+        if self.cols == 0 && row_labels.is_none() {
+            return String::new();
+        }
+
+        let rows = self.stored_rows();
+        let mut header = Vec::new();
+        let mut rule = Vec::new();
+
+        if row_labels.is_some() {
+            header.push(String::new());
+            rule.push(String::from("---"));
+        }
+        for j in 0..self.cols {
+            header.push(escape_markdown(&label(col_labels, j)));
+            rule.push(String::from("---:"));
+        }
+
+        let mut result = format!("| {} |\n| {} |\n", header.join(" | "), rule.join(" | "));
+
+        for i in 0..rows {
+            let mut line = Vec::new();
+            if row_labels.is_some() {
+                line.push(escape_markdown(&label(row_labels, i)));
+            }
+            for j in 0..self.cols {
+                line.push(escape_markdown(&self.matrix[i * self.cols + j].to_string()));
+            }
+            result.push_str(&format!("| {} |\n", line.join(" | ")));
+        }
+
+        return result;
+    }
+
+    // The header row is only written when column labels are given.
+    pub fn to_html_table(
+        &self,
+        row_labels: Option<&[&str]>,
+        col_labels: Option<&[&str]>,
+    ) -> String {
+        let mut result = String::from("<table>\n");
+
+        if col_labels.is_some() {
+            result.push_str("  <thead>\n    <tr>");
+            if row_labels.is_some() {
+                result.push_str("<th></th>");
+            }
+            for j in 0..self.cols {
+                result.push_str(&format!("<th>{}</th>", escape_html(&label(col_labels, j))));
+            }
+            result.push_str("</tr>\n  </thead>\n");
+        }
+
+        result.push_str("  <tbody>\n");
+        for i in 0..self.stored_rows() {
+            result.push_str("    <tr>");
+            if row_labels.is_some() {
+                result.push_str(&format!("<th>{}</th>", escape_html(&label(row_labels, i))));
+            }
+            for j in 0..self.cols {
+                let cell = self.matrix[i * self.cols + j].to_string();
+                result.push_str(&format!("<td>{}</td>", escape_html(&cell)));
+            }
+            result.push_str("</tr>\n");
+        }
+        result.push_str("  </tbody>\n</table>\n");
+
+        return result;
+    }
+}
@@ -0,0 +1,281 @@
+use super::Matrix;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::zip;
+use num_traits::Float;
+
+const PAGERANK_MAX_ITERATIONS: usize = 1000;
+
+// Result of `pagerank`. `residual` is the L1 change in the last iteration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageRank<T> {
+    pub ranks: Vec<T>,
+    pub iterations: usize,
+    pub residual: T,
+    pub converged: bool,
+}
+
+// Helpers for treating a square matrix as a weighted adjacency matrix,
+// where entry (i, j) is the weight of the edge from i to j and zero means
+// no edge. Methods that need a square matrix return None otherwise.
+impl<T> Matrix<T>
+where
+    T: Default + Float,
+{
+    // Weighted out-degree of every vertex, i.e. the row sums.
+    pub fn degrees(&self) -> Option<Vec<T>> {
+        if self.rows != self.cols {
+            return None;
+        }
+
+        let degrees = self
+            .rows()
+            .map(|row| row.iter().fold(T::zero(), |acc, x| acc + *x))
+            .collect();
+
+        return Some(degrees);
+    }
+
+    // L = D - A.
+    pub fn adjacency_to_laplacian(&self) -> Option<Matrix<T>> {
+        let degrees = self.degrees()?;
+        let mut result = self.map(|x| -*x);
+
+        for (i, d) in degrees.into_iter().enumerate() {
+            let index = i * self.cols + i;
+            result.matrix[index] = result.matrix[index] + d;
+        }
+
+        return Some(result);
+    }
+
+    // L = I - D^(-1/2) A D^(-1/2). Isolated vertices get an all-zero row
+    // and column, matching the usual convention for the pseudo-inverse.
+    pub fn normalized_laplacian(&self) -> Option<Matrix<T>> {
+        let scale: Vec<T> = self
+            .degrees()?
+            .into_iter()
+            .map(|d| match d > T::zero() {
+                true => T::one() / d.sqrt(),
+                false => T::zero(),
+            })
+            .collect();
+
+        return Some(self.scaled_laplacian(&scale, &scale));
+    }
+
+    // L = I - D^(-1) A, the generator of the random walk on the graph.
+    pub fn random_walk_laplacian(&self) -> Option<Matrix<T>> {
+        let scale: Vec<T> = self
+            .degrees()?
+            .into_iter()
+            .map(|d| match d > T::zero() {
+                true => T::one() / d,
+                false => T::zero(),
+            })
+            .collect();
+        let ones = vec![T::one(); self.cols];
+
+        return Some(self.scaled_laplacian(&scale, &ones));
+    }
+
+    // Whether the adjacency describes an undirected graph, that is whether
+    // it is symmetric within `tolerance`.
+    pub fn is_undirected(&self, tolerance: T) -> bool {
+        return self.is_symmetric(tolerance);
+    }
+
+    // Weak connectivity: edges are followed in both directions, so a
+    // directed graph counts as connected if its undirected version is. The
+    // empty graph is considered connected.
+    pub fn is_connected(&self) -> bool {
+        let n = self.rows;
+        if self.rows != self.cols {
+            return false;
+        }
+        if n == 0 {
+            return true;
+        }
+
+        let mut seen = vec![false; n];
+        let mut stack = vec![0];
+        seen[0] = true;
+
+        while let Some(i) = stack.pop() {
+            for (j, visited) in seen.iter_mut().enumerate() {
+                let edge =
+                    self.matrix[i * n + j] != T::zero() || self.matrix[j * n + i] != T::zero();
+                if edge && !*visited {
+                    *visited = true;
+                    stack.push(j);
+                }
+            }
+        }
+
+        return seen.into_iter().all(|x| x);
+    }
+
+    // Product over the (min, +) semiring: entry (i, j) is the minimum over k
+    // of A(i, k) + B(k, j). Missing edges are represented by infinity.
+    pub fn min_plus_product(&self, m: &Matrix<T>) -> Option<Matrix<T>> {
+        if self.cols != m.rows {
+            return None;
+        }
+
+        let mut result = Matrix {
+            rows: self.rows,
+            cols: m.cols,
+            matrix: vec![T::infinity(); self.rows * m.cols],
+        };
+
+        for (i, row) in self.rows().enumerate() {
+            for (k, a) in row.iter().enumerate() {
+                for j in 0..m.cols {
+                    let index = i * m.cols + j;
+                    result.matrix[index] = result.matrix[index].min(*a + m.matrix[k * m.cols + j]);
+                }
+            }
+        }
+
+        return Some(result);
+    }
+
+    // All-pairs shortest path lengths for a weight matrix whose missing
+    // edges are infinity. The diagonal is taken as zero and the matrix is
+    // squared under (min, +) until it stops changing, which takes at most
+    // log2(n) + 1 products. Returns None for a negative cycle.
+    pub fn shortest_paths(&self) -> Option<Matrix<T>> {
+        if self.rows != self.cols {
+            return None;
+        }
+
+        let n = self.rows;
+        let mut distance = self.map(|x| *x);
+        for i in 0..n {
+            distance.matrix[i * n + i] = distance.matrix[i * n + i].min(T::zero());
+        }
+
+        loop {
+            let next = distance.min_plus_product(&distance)?;
+
+            if (0..n).any(|i| next.matrix[i * n + i] < T::zero()) {
+                return None;
+            }
+            if next == distance {
+                return Some(distance);
+            }
+            distance = next;
+        }
+    }
+
+    // Power iteration on the Google matrix d·P + (1 - d)/n, where P is the
+    // adjacency with rows normalized to sum to one. Vertices without
+    // outgoing edges link to every vertex uniformly. Stops once the L1
+    // change drops to `tolerance`, or after a fixed iteration cap with
+    // `converged` left false.
+    pub fn pagerank(&self, damping: T, tolerance: T) -> Option<PageRank<T>> {
+        let degrees = self.degrees()?;
+        let n = self.rows;
+        let uniform = T::one() / T::from(n.max(1)).unwrap();
+        let mut ranks = vec![uniform; n];
+        let mut residual = T::infinity();
+        let mut iterations = 0;
+
+        while iterations < PAGERANK_MAX_ITERATIONS && residual > tolerance {
+            let dangling = zip(&ranks, &degrees)
+                .filter(|(_, d)| **d == T::zero())
+                .fold(T::zero(), |acc, (r, _)| acc + *r);
+            let base = (T::one() - damping) * uniform + damping * dangling * uniform;
+            let mut next = vec![base; n];
+
+            for ((r, d), row) in zip(zip(&ranks, &degrees), self.rows()) {
+                if *d == T::zero() {
+                    continue;
+                }
+                let share = damping * *r / *d;
+                for (x, w) in zip(next.iter_mut(), row) {
+                    *x = *x + share * *w;
+                }
+            }
+
+            residual = zip(&next, &ranks).fold(T::zero(), |acc, (x, y)| acc + (*x - *y).abs());
+            ranks = next;
+            iterations += 1;
+        }
+
+        return Some(PageRank {
+            ranks,
+            iterations,
+            residual,
+            converged: residual <= tolerance,
+        });
+    }
+
+    // I - diag(left) A diag(right), with the identity entry dropped for
+    // vertices whose scale is zero.
+    fn scaled_laplacian(&self, left: &[T], right: &[T]) -> Matrix<T> {
+        let n = self.rows;
+        let mut result = Matrix::new_empty(n, n);
+
+        for (i, l) in left.iter().enumerate() {
+            for (j, r) in right.iter().enumerate() {
+                let mut value = -*l * self.matrix[i * n + j] * *r;
+                if i == j && *l != T::zero() {
+                    value = value + T::one();
+                }
+                result.matrix[i * n + j] = value;
+            }
+        }
+
+        return result;
+    }
+}
+
+impl Matrix<bool> {
+    // Product over the boolean semiring: entry (i, j) is true when some k
+    // has both (i, k) and (k, j) set.
+    pub fn boolean_product(&self, m: &Matrix<bool>) -> Option<Matrix<bool>> {
+        if self.cols != m.rows {
+            return None;
+        }
+
+        let mut result = Matrix::new_empty(self.rows, m.cols);
+
+        for (i, row) in self.rows().enumerate() {
+            for (k, _) in row.iter().enumerate().filter(|(_, x)| **x) {
+                for j in 0..m.cols {
+                    result.matrix[i * m.cols + j] |= m.matrix[k * m.cols + j];
+                }
+            }
+        }
+
+        return Some(result);
+    }
+
+    // Entry (i, j) of the result is true when j is reachable from i by a
+    // path of one or more edges. Squaring R | R·R doubles the path length
+    // covered each round, so at most log2(n) + 1 products are needed.
+    pub fn transitive_closure(&self) -> Option<Matrix<bool>> {
+        if self.rows != self.cols {
+            return None;
+        }
+
+        let mut reach = Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            matrix: self.matrix.clone(),
+        };
+
+        loop {
+            let squared = reach.boolean_product(&reach)?;
+            let next: Vec<bool> = zip(&reach.matrix, &squared.matrix)
+                .map(|(x, y)| *x || *y)
+                .collect();
+
+            if next == reach.matrix {
+                return Some(reach);
+            }
+            reach.matrix = next;
+        }
+    }
+}
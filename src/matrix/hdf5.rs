@@ -0,0 +1,114 @@
+use super::Matrix;
+use ::hdf5::{Dataset, File, H5Type, Result};
+use std::marker::PhantomData;
+use std::path::Path;
+
+fn dataset_shape(dataset: &Dataset) -> Result<(usize, usize)> {
+    return match dataset.shape().as_slice() {
+        [rows, cols] => Ok((*rows, *cols)),
+        shape => Err(format!("Expected a 2-D dataset, found shape {:?}", shape).into()),
+    };
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Clone + H5Type,
+{
+    pub fn from_hdf5<P>(path: P, dataset: &str) -> Result<Matrix<T>>
+    where
+        P: AsRef<Path>,
+    {
+        let dataset = File::open(path)?.dataset(dataset)?;
+        let (rows, cols) = dataset_shape(&dataset)?;
+
+        return Ok(Matrix {
+            rows,
+            cols,
+            matrix: dataset.read_raw::<T>()?,
+        });
+    }
+
+    // Creates the file if it does not exist. Writing to a dataset name that
+    // is already present fails rather than overwriting it.
+    pub fn write_hdf5<P>(&self, path: P, dataset: &str) -> Result<()>
+    where
+        P: AsRef<Path>,
+    {
+        let dataset = File::append(path)?
+            .new_dataset::<T>()
+            .shape([self.rows, self.cols])
+            .create(dataset)?;
+
+        return dataset.write_raw(self.matrix.as_slice());
+    }
+}
+
+// Reads a 2-D dataset `chunk_rows` rows at a time, so datasets larger than
+// memory can be processed as a sequence of smaller matrices.
+pub struct Hdf5RowChunks<T> {
+    dataset: Dataset,
+    rows: usize,
+    cols: usize,
+    chunk_rows: usize,
+    next_row: usize,
+    element: PhantomData<T>,
+}
+
+impl<T> Hdf5RowChunks<T> {
+    pub fn open<P>(path: P, dataset: &str, chunk_rows: usize) -> Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        if chunk_rows == 0 {
+            return Err("chunk_rows must be greater than zero".into());
+        }
+
+        let dataset = File::open(path)?.dataset(dataset)?;
+        let (rows, cols) = dataset_shape(&dataset)?;
+
+        return Ok(Hdf5RowChunks {
+            dataset,
+            rows,
+            cols,
+            chunk_rows,
+            next_row: 0,
+            element: PhantomData,
+        });
+    }
+
+    pub fn num_rows(&self) -> usize {
+        return self.rows;
+    }
+
+    pub fn num_cols(&self) -> usize {
+        return self.cols;
+    }
+}
+
+impl<T> Iterator for Hdf5RowChunks<T>
+where
+    T: Default + Clone + H5Type,
+{
+    type Item = Result<Matrix<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_row >= self.rows {
+            return None;
+        }
+
+        let start = self.next_row;
+        let end = (start + self.chunk_rows).min(self.rows);
+        self.next_row = end;
+
+        let chunk = self
+            .dataset
+            .read_slice_2d::<T, _>((start..end, ..))
+            .map(|a| Matrix {
+                rows: end - start,
+                cols: self.cols,
+                matrix: a.iter().cloned().collect(),
+            });
+
+        return Some(chunk);
+    }
+}
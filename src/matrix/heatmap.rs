@@ -0,0 +1,151 @@
+use super::Matrix;
+use alloc::format;
+use alloc::string::String;
+
+const SHADES: [char; 5] = [' ', '░', '▒', '▓', '█'];
+
+// Stops through the 6x6x6 ANSI color cube, light to dark for the
+// sequential scales and blue to white to red for the diverging one.
+const VIRIDIS: [u8; 9] = [53, 54, 61, 67, 73, 72, 77, 149, 226];
+const DIVERGING: [u8; 9] = [19, 26, 33, 117, 231, 217, 203, 160, 124];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeatmapStyle {
+    Shades,
+    Ansi256,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColorScale {
+    Grayscale,
+    Viridis,
+    Diverging,
+}
+
+// Values are clamped to `range`, which defaults to the matrix minimum and
+// maximum.
+#[derive(Debug, Clone)]
+pub struct HeatmapOptions {
+    pub style: HeatmapStyle,
+    pub scale: ColorScale,
+    pub range: Option<(f64, f64)>,
+}
+
+impl Default for HeatmapOptions {
+    fn default() -> Self {
+        return HeatmapOptions {
+            style: HeatmapStyle::Shades,
+            scale: ColorScale::Grayscale,
+            range: None,
+        };
+    }
+}
+
+impl HeatmapOptions {
+    pub fn new() -> Self {
+        return Default::default();
+    }
+
+    pub fn style(mut self, style: HeatmapStyle) -> Self {
+        self.style = style;
+        return self;
+    }
+
+    pub fn scale(mut self, scale: ColorScale) -> Self {
+        self.scale = scale;
+        return self;
+    }
+
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some((min, max));
+        return self;
+    }
+}
+
+fn pick<V: Copy>(stops: &[V], t: f64) -> V {
+    let last = stops.len() - 1;
+    return stops[((t * last as f64 + 0.5) as usize).min(last)];
+}
+
+fn ansi_color(scale: ColorScale, t: f64) -> u8 {
+    return match scale {
+        ColorScale::Grayscale => 232 + ((t * 23.0 + 0.5) as u8).min(23),
+        ColorScale::Viridis => pick(&VIRIDIS, t),
+        ColorScale::Diverging => pick(&DIVERGING, t),
+    };
+}
+
+// Maps values onto [0, 1] for a color scale. Without an explicit range the
+// data minimum and maximum are used, centred on zero for diverging scales.
+pub(crate) fn unit_scale<I>(
+    values: I,
+    scale: ColorScale,
+    range: Option<(f64, f64)>,
+) -> impl Fn(f64) -> f64
+where
+    I: Iterator<Item = f64>,
+{
+    let (min, max) = match range {
+        Some(range) => range,
+        None => {
+            let (min, max) = values
+                .filter(|x| !x.is_nan())
+                .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), x| {
+                    (lo.min(x), hi.max(x))
+                });
+            match scale {
+                ColorScale::Diverging => {
+                    let bound = max.abs().max(min.abs());
+                    (-bound, bound)
+                }
+                _ => (min, max),
+            }
+        }
+    };
+    let span = max - min;
+
+    return move |x| match span > 0.0 {
+        true => ((x - min) / span).clamp(0.0, 1.0),
+        false => 0.5,
+    };
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy + Into<f64>,
+{
+    // Each entry is drawn two characters wide so cells come out roughly
+    // square in most terminal fonts. NaN entries are left blank.
+    pub fn render_heatmap(&self, options: &HeatmapOptions) -> String {
+        let values = self.matrix.iter().map(|x| (*x).into());
+        let unit = unit_scale(values.clone(), options.scale, options.range);
+
+        let mut result = String::new();
+
+        for (index, x) in values.enumerate() {
+            let t = unit(x);
+
+            match (options.style, x.is_nan()) {
+                (HeatmapStyle::Shades, true) => result.push_str("  "),
+                (HeatmapStyle::Shades, false) => {
+                    let shade = pick(&SHADES, t);
+                    result.push(shade);
+                    result.push(shade);
+                }
+                (HeatmapStyle::Ansi256, true) => result.push_str("\x1b[0m  "),
+                (HeatmapStyle::Ansi256, false) => {
+                    result.push_str(&format!("\x1b[48;5;{}m  ", ansi_color(options.scale, t)));
+                }
+            }
+
+            if (index + 1) % self.cols == 0 {
+                if options.style == HeatmapStyle::Ansi256 {
+                    result.push_str("\x1b[0m");
+                }
+                result.push('\n');
+            }
+        }
+
+        return result;
+    }
+}
@@ -0,0 +1,41 @@
+use super::Matrix;
+use ::image::{DynamicImage, GrayImage, ImageBuffer, Luma};
+
+// Images map to matrices with one row per pixel row, so a W x H image
+// becomes an H x W matrix.
+impl Matrix<u8> {
+    pub fn from_gray_image(image: &GrayImage) -> Matrix<u8> {
+        return Matrix {
+            rows: image.height() as usize,
+            cols: image.width() as usize,
+            matrix: image.as_raw().clone(),
+        };
+    }
+
+    // Colour images are converted to 8-bit luma first.
+    pub fn from_image(image: &DynamicImage) -> Matrix<u8> {
+        return Matrix::from_gray_image(&image.to_luma8());
+    }
+
+    // Returns None if the dimensions do not fit in u32.
+    pub fn to_gray_image(&self) -> Option<GrayImage> {
+        let width = u32::try_from(self.cols).ok()?;
+        let height = u32::try_from(self.rows).ok()?;
+
+        return ImageBuffer::<Luma<u8>, _>::from_raw(width, height, self.matrix.clone());
+    }
+}
+
+impl Matrix<f32> {
+    // Pixel values are scaled into [0, 1].
+    pub fn from_gray_image_normalized(image: &GrayImage) -> Matrix<f32> {
+        return Matrix::from_gray_image(image).map(|x| *x as f32 / 255.0);
+    }
+
+    // Values are clamped to [0, 1] before being scaled back to 0-255.
+    pub fn to_gray_image_normalized(&self) -> Option<GrayImage> {
+        return self
+            .map(|x| (x.clamp(0.0, 1.0) * 255.0).round() as u8)
+            .to_gray_image();
+    }
+}
@@ -0,0 +1,38 @@
+use super::symmetric::SymmetricMatrix;
+use super::{Axis, Matrix};
+use num_traits::Num;
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy,
+{
+    // Applies `kernel` to every pair of rows. The kernel is assumed to be
+    // symmetric, so it is evaluated once per unordered pair and the result
+    // is stored packed.
+    pub fn kernel_matrix<F>(&self, kernel: F) -> SymmetricMatrix<T>
+    where
+        F: Fn(&[T], &[T]) -> T,
+    {
+        let d = self.cols;
+        let row = |i: usize| &self.matrix[i * d..(i + 1) * d];
+
+        return SymmetricMatrix::from_fn(self.rows, |i, j| kernel(row(i), row(j)));
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy + Num,
+{
+    // Inner products between the rows (X Xᵀ, for `Axis::Rows`) or the
+    // columns (Xᵀ X, for `Axis::Cols`). Only one triangle is computed.
+    pub fn gram(&self, axis: Axis) -> SymmetricMatrix<T> {
+        let (count, _) = self.lane_shape(axis);
+
+        return SymmetricMatrix::from_fn(count, |i, j| {
+            self.lane(axis, i)
+                .zip(self.lane(axis, j))
+                .fold(T::zero(), |acc, (x, y)| acc + *x * *y)
+        });
+    }
+}
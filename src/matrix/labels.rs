@@ -0,0 +1,39 @@
+use super::{Axis, Matrix};
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::{One, Zero};
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy + Zero + One,
+{
+    // One row per label with a one in the label's column. Fails if any
+    // label is not below `num_classes`.
+    pub fn one_hot(labels: &[usize], num_classes: usize) -> Result<Self, &'static str> {
+        let mut matrix = vec![T::zero(); labels.len() * num_classes];
+
+        for (i, label) in labels.iter().enumerate() {
+            if *label >= num_classes {
+                return Err("Label out of range for the number of classes");
+            }
+            matrix[i * num_classes + label] = T::one();
+        }
+
+        return Ok(Matrix {
+            rows: labels.len(),
+            cols: num_classes,
+            matrix,
+        });
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy + PartialOrd,
+{
+    // Decodes one-hot rows or per-class scores back to labels: the column
+    // of each row's largest entry. None if there are no columns.
+    pub fn argmax_rows(&self) -> Option<Vec<usize>> {
+        return self.argmax_axis(Axis::Rows);
+    }
+}
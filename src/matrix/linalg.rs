@@ -0,0 +1,446 @@
+use super::decomposition::{householder_vector, reflect};
+use super::permutation::PermutationMatrix;
+use super::Matrix;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use num_traits::{Float, Num, One, Zero};
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy + Zero + One,
+{
+    pub fn identity(size: usize) -> Self {
+        let mut result = Matrix {
+            rows: size,
+            cols: size,
+            matrix: vec![T::zero(); size * size],
+        };
+
+        for i in 0..size {
+            result.matrix[i * size + i] = T::one();
+        }
+
+        return result;
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy,
+{
+    pub fn transpose(&self) -> Matrix<T> {
+        let matrix = (0..self.rows * self.cols)
+            .map(|k| self.matrix[(k % self.rows) * self.cols + k / self.rows])
+            .collect();
+
+        return Matrix {
+            rows: self.cols,
+            cols: self.rows,
+            matrix,
+        };
+    }
+}
+
+const JACOBI_SWEEPS: usize = 64;
+
+// Plain triple loop for element types that only provide `Num`, such as
+// the matrix powers below. Assumes the shapes already agree.
+pub(crate) fn product<T>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T>
+where
+    T: Default + Copy + Num,
+{
+    let mut matrix = vec![T::zero(); a.rows * b.cols];
+
+    for i in 0..a.rows {
+        for k in 0..a.cols {
+            let x = a.matrix[i * a.cols + k];
+            for j in 0..b.cols {
+                matrix[i * b.cols + j] = matrix[i * b.cols + j] + x * b.matrix[k * b.cols + j];
+            }
+        }
+    }
+
+    return Matrix {
+        rows: a.rows,
+        cols: b.cols,
+        matrix,
+    };
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy + Num,
+{
+    // Repeated squaring, so only O(log n) products are needed. Returns
+    // None for non-square matrices.
+    pub fn pow(&self, mut exponent: u32) -> Option<Matrix<T>> {
+        if self.rows != self.cols {
+            return None;
+        }
+
+        let mut result = Matrix::identity(self.rows);
+        let mut base = Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            matrix: self.matrix.clone(),
+        };
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = product(&result, &base);
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = product(&base, &base);
+            }
+        }
+
+        return Some(result);
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Float,
+{
+    // Solves A X = B by Gaussian elimination with partial pivoting. Returns
+    // None if A is not square, the shapes disagree, or A is singular to
+    // working precision.
+    pub fn solve(&self, b: &Matrix<T>) -> Option<Matrix<T>> {
+        let n = self.rows;
+        if self.cols != n || b.rows != n {
+            return None;
+        }
+
+        let m = b.cols;
+        let mut a = self.matrix.clone();
+        let mut x = b.matrix.clone();
+        let scale = a.iter().fold(T::zero(), |acc, v| acc.max(v.abs()));
+        let tiny = T::epsilon() * scale * T::from(n).unwrap();
+
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|i, j| {
+                    a[i * n + k]
+                        .abs()
+                        .partial_cmp(&a[j * n + k].abs())
+                        .unwrap_or(Ordering::Equal)
+                })
+                .unwrap();
+
+            if a[pivot * n + k].is_nan() || a[pivot * n + k].abs() <= tiny {
+                return None;
+            }
+
+            if pivot != k {
+                for j in 0..n {
+                    a.swap(k * n + j, pivot * n + j);
+                }
+                for j in 0..m {
+                    x.swap(k * m + j, pivot * m + j);
+                }
+            }
+
+            for i in k + 1..n {
+                let factor = a[i * n + k] / a[k * n + k];
+                for j in k..n {
+                    a[i * n + j] = a[i * n + j] - factor * a[k * n + j];
+                }
+                for j in 0..m {
+                    x[i * m + j] = x[i * m + j] - factor * x[k * m + j];
+                }
+            }
+        }
+
+        for k in (0..n).rev() {
+            for j in 0..m {
+                let sum = (k + 1..n).fold(x[k * m + j], |acc, i| acc - a[k * n + i] * x[i * m + j]);
+                x[k * m + j] = sum / a[k * n + k];
+            }
+        }
+
+        return Some(Matrix {
+            rows: n,
+            cols: m,
+            matrix: x,
+        });
+    }
+
+    // LU factorization with partial pivoting, P A = L U, where L is unit
+    // lower triangular and U upper triangular. Singular matrices still
+    // factor, with a zero on the diagonal of U. Returns None for
+    // non-square input.
+    pub fn lu(&self) -> Option<(PermutationMatrix, Matrix<T>, Matrix<T>)> {
+        let (a, perm) = self.lu_packed()?;
+        let n = self.rows;
+
+        let mut l = Matrix::identity(n);
+        let mut u = Matrix::new_empty(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                match j < i {
+                    true => l.matrix[i * n + j] = a[i * n + j],
+                    false => u.matrix[i * n + j] = a[i * n + j],
+                }
+            }
+        }
+
+        return Some((PermutationMatrix::from_indices(perm)?, l, u));
+    }
+
+    // Product of U's diagonal from the LU factorization, with the sign of
+    // the row permutation. Can overflow or underflow for large matrices;
+    // see `ln_det`.
+    pub fn determinant(&self) -> Option<T> {
+        let (sign, ln_abs) = self.ln_det()?;
+
+        return Some(match sign == T::zero() {
+            true => T::zero(),
+            false => sign * ln_abs.exp(),
+        });
+    }
+
+    // (sign, ln |det A|) from the LU factorization, summing logarithms so
+    // that it stays finite where the determinant itself would not. A
+    // singular matrix gives (0, -inf). Returns None for non-square input.
+    pub fn ln_det(&self) -> Option<(T, T)> {
+        let (a, perm) = self.lu_packed()?;
+        let n = self.rows;
+        let mut sign = match PermutationMatrix::from_indices(perm)?.sign() {
+            1 => T::one(),
+            _ => -T::one(),
+        };
+        let mut ln_abs = T::zero();
+
+        for i in 0..n {
+            let pivot = a[i * n + i];
+            if pivot == T::zero() {
+                return Some((T::zero(), T::neg_infinity()));
+            }
+            if pivot < T::zero() {
+                sign = -sign;
+            }
+            ln_abs = ln_abs + pivot.abs().ln();
+        }
+
+        return Some((sign, ln_abs));
+    }
+
+    // L and U packed into one buffer (L below the diagonal, without its
+    // unit diagonal) and the row order, so (P A) row i is A row perm[i].
+    pub(crate) fn lu_packed(&self) -> Option<(Vec<T>, Vec<usize>)> {
+        let n = self.rows;
+        if self.cols != n {
+            return None;
+        }
+
+        let mut a = self.matrix.clone();
+        let mut perm: Vec<usize> = (0..n).collect();
+
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|i, j| {
+                    a[i * n + k]
+                        .abs()
+                        .partial_cmp(&a[j * n + k].abs())
+                        .unwrap_or(Ordering::Equal)
+                })
+                .unwrap();
+
+            if pivot != k {
+                for j in 0..n {
+                    a.swap(k * n + j, pivot * n + j);
+                }
+                perm.swap(k, pivot);
+            }
+
+            if a[k * n + k] == T::zero() {
+                continue;
+            }
+
+            for i in k + 1..n {
+                let factor = a[i * n + k] / a[k * n + k];
+                a[i * n + k] = factor;
+                for j in k + 1..n {
+                    a[i * n + j] = a[i * n + j] - factor * a[k * n + j];
+                }
+            }
+        }
+
+        return Some((a, perm));
+    }
+
+    // Thin QR factorization A = Q R of an m x n matrix with m >= n, by
+    // Householder reflections: Q is m x n with orthonormal columns and R is
+    // n x n upper triangular. Returns None for wide matrices.
+    pub fn qr(&self) -> Option<(Matrix<T>, Matrix<T>)> {
+        let (m, n) = (self.rows, self.cols);
+        if m < n {
+            return None;
+        }
+
+        let mut a = self.matrix.clone();
+        let mut reflectors = Vec::with_capacity(n);
+
+        for k in 0..n {
+            let x: Vec<T> = (k..m).map(|i| a[i * n + k]).collect();
+            let v = householder_vector(&x);
+            for j in k..n {
+                reflect(&v, (k..m).map(|i| i * n + j), &mut a);
+            }
+            reflectors.push(v);
+        }
+
+        let mut q = vec![T::zero(); m * n];
+        for j in 0..n {
+            q[j * n + j] = T::one();
+        }
+        for (k, v) in reflectors.iter().enumerate().rev() {
+            for j in 0..n {
+                reflect(v, (k..m).map(|i| i * n + j), &mut q);
+            }
+        }
+
+        let mut r = vec![T::zero(); n * n];
+        for i in 0..n {
+            for j in i..n {
+                r[i * n + j] = a[i * n + j];
+            }
+        }
+
+        return Some((
+            Matrix {
+                rows: m,
+                cols: n,
+                matrix: q,
+            },
+            Matrix {
+                rows: n,
+                cols: n,
+                matrix: r,
+            },
+        ));
+    }
+
+    // Least-squares solution of A X ≈ B through the QR factorization, which
+    // avoids squaring the condition number the way the normal equations
+    // do. Returns None for wide or rank-deficient A, or mismatched B.
+    pub fn lstsq(&self, b: &Matrix<T>) -> Option<Matrix<T>> {
+        if b.rows != self.rows {
+            return None;
+        }
+
+        let (q, r) = self.qr()?;
+        let n = self.cols;
+        let scale = r.matrix.iter().fold(T::zero(), |acc, v| acc.max(v.abs()));
+        let tiny = T::epsilon() * scale * T::from(self.rows.max(1)).unwrap();
+        if (0..n).any(|k| r.matrix[k * n + k].is_nan() || r.matrix[k * n + k].abs() <= tiny) {
+            return None;
+        }
+
+        let y = product(&q.transpose(), b);
+
+        return back_substitute(&r, y);
+    }
+
+    // Eigenvalues (ascending) and eigenvectors (as columns) of a symmetric
+    // matrix, by cyclic Jacobi rotations. Only the upper triangle is read.
+    // Jacobi is slower than tridiagonal QR but simple and accurate to
+    // working precision. Returns None for non-square input.
+    pub fn symmetric_eigen(&self) -> Option<(Vec<T>, Matrix<T>)> {
+        let n = self.rows;
+        if self.cols != n {
+            return None;
+        }
+
+        let mut a = vec![T::zero(); n * n];
+        for i in 0..n {
+            for j in i..n {
+                a[i * n + j] = self.matrix[i * n + j];
+                a[j * n + i] = self.matrix[i * n + j];
+            }
+        }
+        let mut v = Matrix::identity(n);
+
+        for _ in 0..JACOBI_SWEEPS {
+            let off = (0..n)
+                .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+                .fold(T::zero(), |acc, (i, j)| acc + a[i * n + j] * a[i * n + j]);
+            let total = a.iter().fold(T::zero(), |acc, x| acc + *x * *x);
+            if off <= T::epsilon() * T::epsilon() * total {
+                break;
+            }
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    if a[p * n + q] == T::zero() {
+                        continue;
+                    }
+
+                    // Rotation angle that zeroes a[p][q].
+                    let theta = (a[q * n + q] - a[p * n + p]) / (a[p * n + q] + a[p * n + q]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                    let c = T::one() / (t * t + T::one()).sqrt();
+                    let s = t * c;
+
+                    for k in 0..n {
+                        let (x, y) = (a[k * n + p], a[k * n + q]);
+                        a[k * n + p] = c * x - s * y;
+                        a[k * n + q] = s * x + c * y;
+                    }
+                    for k in 0..n {
+                        let (x, y) = (a[p * n + k], a[q * n + k]);
+                        a[p * n + k] = c * x - s * y;
+                        a[q * n + k] = s * x + c * y;
+                    }
+                    for k in 0..n {
+                        let (x, y) = (v.matrix[k * n + p], v.matrix[k * n + q]);
+                        v.matrix[k * n + p] = c * x - s * y;
+                        v.matrix[k * n + q] = s * x + c * y;
+                    }
+                }
+            }
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|i, j| {
+            a[i * n + i]
+                .partial_cmp(&a[j * n + j])
+                .unwrap_or(Ordering::Equal)
+        });
+
+        let values = order.iter().map(|i| a[i * n + i]).collect();
+        let vectors = Matrix {
+            rows: n,
+            cols: n,
+            matrix: (0..n * n)
+                .map(|k| v.matrix[(k / n) * n + order[k % n]])
+                .collect(),
+        };
+
+        return Some((values, vectors));
+    }
+}
+
+// Solves R X = Y for upper triangular R. Assumes the diagonal is nonzero.
+pub(crate) fn back_substitute<T>(r: &Matrix<T>, mut y: Matrix<T>) -> Option<Matrix<T>>
+where
+    T: Default + Float,
+{
+    let (n, m) = (r.rows, y.cols);
+    if y.rows != n {
+        return None;
+    }
+
+    for k in (0..n).rev() {
+        for j in 0..m {
+            let sum = (k + 1..n).fold(y.matrix[k * m + j], |acc, i| {
+                acc - r.matrix[k * n + i] * y.matrix[i * m + j]
+            });
+            y.matrix[k * m + j] = sum / r.matrix[k * n + k];
+        }
+    }
+
+    return Some(y);
+}
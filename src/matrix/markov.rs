@@ -0,0 +1,129 @@
+use super::Matrix;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::zip;
+use num_traits::Float;
+
+// The canonical-form pieces of an absorbing chain. Row i of
+// `probabilities` is transient state `transient[i]`, and column j is
+// absorbing state `absorbing[j]`.
+#[derive(Debug, PartialEq)]
+pub struct Absorption<T>
+where
+    T: Default,
+{
+    pub transient: Vec<usize>,
+    pub absorbing: Vec<usize>,
+    pub probabilities: Matrix<T>,
+    pub expected_steps: Vec<T>,
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Float,
+{
+    // Square, non-negative, and every row sums to one within `tolerance`.
+    pub fn is_row_stochastic(&self, tolerance: T) -> bool {
+        if self.rows != self.cols || self.matrix.iter().any(|x| *x < T::zero()) {
+            return false;
+        }
+
+        return self.rows().all(|row| {
+            let sum = row.iter().fold(T::zero(), |acc, x| acc + *x);
+            (sum - T::one()).abs() <= tolerance
+        });
+    }
+
+    // Power iteration on the lazy chain (P + I) / 2, which has the same
+    // stationary distribution but also converges for periodic chains.
+    // Returns None if the matrix is not row-stochastic or the iteration has
+    // not settled within `max_iterations`.
+    pub fn stationary_distribution(&self, tolerance: T, max_iterations: usize) -> Option<Vec<T>> {
+        if !self.is_row_stochastic(tolerance.max(T::epsilon().sqrt())) {
+            return None;
+        }
+
+        let n = self.rows;
+        let half = T::from(0.5).unwrap();
+        let mut pi = vec![T::one() / T::from(n).unwrap(); n];
+
+        for _ in 0..max_iterations {
+            let mut next = vec![T::zero(); n];
+            for (p, row) in zip(&pi, self.rows()) {
+                for (x, y) in zip(next.iter_mut(), row) {
+                    *x = *x + *p * *y;
+                }
+            }
+
+            let mut change = T::zero();
+            for (x, p) in zip(next.iter_mut(), &pi) {
+                *x = half * (*x + *p);
+                change = change + (*x - *p).abs();
+            }
+            pi = next;
+
+            if change <= tolerance {
+                return Some(pi);
+            }
+        }
+
+        return None;
+    }
+
+    // Distribution over states after `steps` transitions, i.e. P^n.
+    pub fn n_step_transition(&self, steps: u32) -> Option<Matrix<T>> {
+        return self.pow(steps);
+    }
+
+    // States with P[i][i] = 1 are absorbing. With Q the transient block and
+    // R the transient-to-absorbing block, the absorption probabilities are
+    // B = (I - Q)⁻¹ R and the expected number of steps is (I - Q)⁻¹ 1.
+    // Returns None if some transient state can never be absorbed.
+    pub fn absorption_probabilities(&self) -> Option<Absorption<T>> {
+        if self.rows != self.cols {
+            return None;
+        }
+
+        let n = self.rows;
+        let (absorbing, transient): (Vec<usize>, Vec<usize>) =
+            (0..n).partition(|i| self.matrix[i * n + i] == T::one());
+        let t = transient.len();
+
+        let mut system = Matrix::identity(t);
+        for (a, i) in transient.iter().enumerate() {
+            for (b, j) in transient.iter().enumerate() {
+                system.matrix[a * t + b] = system.matrix[a * t + b] - self.matrix[i * n + j];
+            }
+        }
+
+        let mut rhs = Matrix::new_empty(t, absorbing.len() + 1);
+        for (a, i) in transient.iter().enumerate() {
+            for (b, j) in absorbing.iter().enumerate() {
+                rhs.matrix[a * rhs.cols + b] = self.matrix[i * n + j];
+            }
+            rhs.matrix[a * rhs.cols + absorbing.len()] = T::one();
+        }
+
+        let solution = match t {
+            0 => rhs,
+            _ => system.solve(&rhs)?,
+        };
+        let k = absorbing.len();
+        let probabilities = Matrix {
+            rows: t,
+            cols: k,
+            matrix: solution
+                .rows()
+                .flat_map(|row| row[..k].iter().cloned())
+                .collect(),
+        };
+        let expected_steps = solution.rows().map(|row| row[k]).collect();
+
+        return Some(Absorption {
+            transient,
+            absorbing,
+            probabilities,
+            expected_steps,
+        });
+    }
+}
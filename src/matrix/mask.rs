@@ -0,0 +1,141 @@
+use super::Matrix;
+use alloc::vec::Vec;
+use core::iter::zip;
+
+// Elementwise comparisons producing boolean masks. Like `add` and
+// `matrix_add`, each has a scalar form and a `matrix_` form that returns
+// None on a shape mismatch. NaN compares false everywhere.
+impl<T> Matrix<T>
+where
+    T: Default + Copy + PartialOrd,
+{
+    pub fn gt(&self, value: T) -> Matrix<bool> {
+        return self.map(|x| *x > value);
+    }
+
+    pub fn lt(&self, value: T) -> Matrix<bool> {
+        return self.map(|x| *x < value);
+    }
+
+    pub fn ge(&self, value: T) -> Matrix<bool> {
+        return self.map(|x| *x >= value);
+    }
+
+    pub fn le(&self, value: T) -> Matrix<bool> {
+        return self.map(|x| *x <= value);
+    }
+
+    pub fn eq_elementwise(&self, value: T) -> Matrix<bool> {
+        return self.map(|x| *x == value);
+    }
+
+    pub fn matrix_gt(&self, m: &Matrix<T>) -> Option<Matrix<bool>> {
+        return self.zip_map(m, |x, y| x > y);
+    }
+
+    pub fn matrix_lt(&self, m: &Matrix<T>) -> Option<Matrix<bool>> {
+        return self.zip_map(m, |x, y| x < y);
+    }
+
+    pub fn matrix_ge(&self, m: &Matrix<T>) -> Option<Matrix<bool>> {
+        return self.zip_map(m, |x, y| x >= y);
+    }
+
+    pub fn matrix_le(&self, m: &Matrix<T>) -> Option<Matrix<bool>> {
+        return self.zip_map(m, |x, y| x <= y);
+    }
+
+    pub fn matrix_eq_elementwise(&self, m: &Matrix<T>) -> Option<Matrix<bool>> {
+        return self.zip_map(m, |x, y| x == y);
+    }
+}
+
+// Selection and assignment by mask. Each fails when the mask's shape
+// differs from the matrix.
+impl<T> Matrix<T>
+where
+    T: Default + Copy,
+{
+    // Takes each entry from `self` where the mask is true and from `other`
+    // where it is false.
+    pub fn select(&self, mask: &Matrix<bool>, other: &Matrix<T>) -> Option<Matrix<T>> {
+        if !self.same_shape(mask) || self.rows != other.rows || self.cols != other.cols {
+            return None;
+        }
+
+        let matrix = zip(zip(&self.matrix, &other.matrix), &mask.matrix)
+            .map(|((x, y), keep)| match keep {
+                true => *x,
+                false => *y,
+            })
+            .collect();
+
+        return Some(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            matrix,
+        });
+    }
+
+    // Keeps the entries where the mask is true and replaces the rest with
+    // `value`.
+    pub fn where_(&self, mask: &Matrix<bool>, value: T) -> Option<Matrix<T>> {
+        if !self.same_shape(mask) {
+            return None;
+        }
+
+        let matrix = zip(&self.matrix, &mask.matrix)
+            .map(|(x, keep)| match keep {
+                true => *x,
+                false => value,
+            })
+            .collect();
+
+        return Some(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            matrix,
+        });
+    }
+
+    // The entries where the mask is true, in row-major order.
+    pub fn filter(&self, mask: &Matrix<bool>) -> Option<Vec<T>> {
+        if !self.same_shape(mask) {
+            return None;
+        }
+
+        let kept = zip(&self.matrix, &mask.matrix)
+            .filter(|(_, keep)| **keep)
+            .map(|(x, _)| *x)
+            .collect();
+
+        return Some(kept);
+    }
+
+    // Overwrites every entry where the mask is true. Like `set`, returns
+    // self for chaining.
+    pub fn set_where(&mut self, mask: &Matrix<bool>, value: T) -> Result<&mut Self, &str> {
+        return self.apply_where(mask, |_| value);
+    }
+
+    pub fn apply_where<F>(&mut self, mask: &Matrix<bool>, map: F) -> Result<&mut Self, &str>
+    where
+        F: Fn(&T) -> T,
+    {
+        if !self.same_shape(mask) {
+            return Err("Mask shape does not match matrix");
+        }
+
+        for (x, keep) in zip(self.matrix.iter_mut(), &mask.matrix) {
+            if *keep {
+                *x = map(x);
+            }
+        }
+
+        return Ok(self);
+    }
+
+    fn same_shape(&self, mask: &Matrix<bool>) -> bool {
+        return self.rows == mask.rows && self.cols == mask.cols;
+    }
+}
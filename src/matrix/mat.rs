@@ -0,0 +1,416 @@
+use super::Matrix;
+use std::fmt;
+use std::fmt::Display;
+use std::io;
+use std::io::{Read, Write};
+
+const HEADER_LEN: usize = 128;
+
+const MI_INT8: u32 = 1;
+const MI_UINT8: u32 = 2;
+const MI_INT16: u32 = 3;
+const MI_UINT16: u32 = 4;
+const MI_INT32: u32 = 5;
+const MI_UINT32: u32 = 6;
+const MI_SINGLE: u32 = 7;
+const MI_DOUBLE: u32 = 9;
+const MI_INT64: u32 = 12;
+const MI_UINT64: u32 = 13;
+const MI_MATRIX: u32 = 14;
+const MI_COMPRESSED: u32 = 15;
+
+const FLAG_COMPLEX: u32 = 0x0800;
+
+// Numeric element types with a MATLAB array class. Stored data may use a
+// narrower type than the class (MATLAB does this to save space), so values
+// are widened to i64, u64 or f64 according to the stored type and then
+// cast, which keeps 64-bit integers exact.
+pub trait MatElement: Copy {
+    const CLASS: u8;
+    const MI_TYPE: u32;
+
+    fn from_i64(x: i64) -> Self;
+    fn from_u64(x: u64) -> Self;
+    fn from_f64(x: f64) -> Self;
+    fn extend_le_bytes(self, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_mat_element {
+    ($($t:ty => $class:literal, $mi:expr),*) => {
+        $(impl MatElement for $t {
+            const CLASS: u8 = $class;
+            const MI_TYPE: u32 = $mi;
+
+            fn from_i64(x: i64) -> Self {
+                return x as $t;
+            }
+
+            fn from_u64(x: u64) -> Self {
+                return x as $t;
+            }
+
+            fn from_f64(x: f64) -> Self {
+                return x as $t;
+            }
+
+            fn extend_le_bytes(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+        })*
+    };
+}
+
+impl_mat_element!(
+    f64 => 6, MI_DOUBLE,
+    f32 => 7, MI_SINGLE,
+    i8 => 8, MI_INT8,
+    u8 => 9, MI_UINT8,
+    i16 => 10, MI_INT16,
+    u16 => 11, MI_UINT16,
+    i32 => 12, MI_INT32,
+    u32 => 13, MI_UINT32,
+    i64 => 14, MI_INT64,
+    u64 => 15, MI_UINT64
+);
+
+#[derive(Debug)]
+pub enum MatError {
+    Io(io::Error),
+    Header(String),
+    Truncated,
+    Unsupported(String),
+    NotFound(String),
+}
+
+impl Display for MatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            MatError::Io(e) => write!(f, "I/O error: {}", e),
+            MatError::Header(h) => write!(f, "Invalid MAT-file header: {}", h),
+            MatError::Truncated => write!(f, "MAT-file data ended unexpectedly"),
+            MatError::Unsupported(s) => write!(f, "Unsupported MAT-file content: {}", s),
+            MatError::NotFound(name) => write!(f, "No numeric variable named {}", name),
+        };
+    }
+}
+
+impl std::error::Error for MatError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        return match self {
+            MatError::Io(e) => Some(e),
+            _ => None,
+        };
+    }
+}
+
+impl From<io::Error> for MatError {
+    fn from(e: io::Error) -> Self {
+        return MatError::Io(e);
+    }
+}
+
+struct Cursor<'a> {
+    buf: &'a [u8],
+    pos: usize,
+    swap: bool,
+}
+
+struct Element<'a> {
+    data_type: u32,
+    data: &'a [u8],
+}
+
+impl<'a> Cursor<'a> {
+    fn u32_at(&self, pos: usize) -> Result<u32, MatError> {
+        let bytes: [u8; 4] = self
+            .buf
+            .get(pos..pos + 4)
+            .ok_or(MatError::Truncated)?
+            .try_into()
+            .unwrap();
+
+        return Ok(match self.swap {
+            true => u32::from_be_bytes(bytes),
+            false => u32::from_le_bytes(bytes),
+        });
+    }
+
+    // Reads one tagged element, handling the small element format where the
+    // size and type share the first word and the data fits in the second.
+    fn next_element(&mut self, padded: bool) -> Result<Option<Element<'a>>, MatError> {
+        if self.pos >= self.buf.len() {
+            return Ok(None);
+        }
+
+        let first = self.u32_at(self.pos)?;
+        let (data_type, size, start, end) = if first >> 16 != 0 {
+            let size = (first >> 16) as usize;
+            (first & 0xffff, size, self.pos + 4, self.pos + 8)
+        } else {
+            let size = self.u32_at(self.pos + 4)? as usize;
+            let end = match padded {
+                true => self.pos + 8 + size.div_ceil(8) * 8,
+                false => self.pos + 8 + size,
+            };
+            (first, size, self.pos + 8, end)
+        };
+
+        let data = self
+            .buf
+            .get(start..start + size)
+            .ok_or(MatError::Truncated)?;
+        self.pos = end.min(self.buf.len());
+
+        return Ok(Some(Element { data_type, data }));
+    }
+}
+
+fn decode_values<T>(element: &Element, swap: bool) -> Result<Vec<T>, MatError>
+where
+    T: MatElement,
+{
+    macro_rules! decode {
+        ($t:ty, $wide:ty, $from:ident) => {
+            element
+                .data
+                .chunks_exact(std::mem::size_of::<$t>())
+                .map(|b| match swap {
+                    true => T::$from(<$t>::from_be_bytes(b.try_into().unwrap()) as $wide),
+                    false => T::$from(<$t>::from_le_bytes(b.try_into().unwrap()) as $wide),
+                })
+                .collect()
+        };
+    }
+
+    return Ok(match element.data_type {
+        MI_INT8 => decode!(i8, i64, from_i64),
+        MI_UINT8 => decode!(u8, u64, from_u64),
+        MI_INT16 => decode!(i16, i64, from_i64),
+        MI_UINT16 => decode!(u16, u64, from_u64),
+        MI_INT32 => decode!(i32, i64, from_i64),
+        MI_UINT32 => decode!(u32, u64, from_u64),
+        MI_SINGLE => decode!(f32, f64, from_f64),
+        MI_DOUBLE => decode!(f64, f64, from_f64),
+        MI_INT64 => decode!(i64, i64, from_i64),
+        MI_UINT64 => decode!(u64, u64, from_u64),
+        t => return Err(MatError::Unsupported(format!("data type {}", t))),
+    });
+}
+
+// Returns None for arrays that are not real numeric matrices, such as
+// cells, structs, character arrays and sparse or complex data.
+fn parse_matrix<T>(data: &[u8], swap: bool) -> Result<Option<(String, Matrix<T>)>, MatError>
+where
+    T: Default + MatElement,
+{
+    let mut cursor = Cursor {
+        buf: data,
+        pos: 0,
+        swap,
+    };
+    let mut next = || cursor.next_element(true)?.ok_or(MatError::Truncated);
+
+    let flags = next()?;
+    let flags = Cursor {
+        buf: flags.data,
+        pos: 0,
+        swap,
+    }
+    .u32_at(0)?;
+    let class = flags & 0xff;
+
+    if !(6..=15).contains(&class) || flags & FLAG_COMPLEX != 0 {
+        return Ok(None);
+    }
+
+    let dims: Vec<i64> = decode_values(&next()?, swap)?;
+    let (rows, cols) = match dims.as_slice() {
+        [rows, cols] => (*rows, *cols),
+        _ => return Ok(None),
+    };
+    let invalid = || MatError::Header(format!("invalid dimensions {}x{}", rows, cols));
+    let (rows, cols) = match (usize::try_from(rows), usize::try_from(cols)) {
+        (Ok(rows), Ok(cols)) => (rows, cols),
+        _ => return Err(invalid()),
+    };
+    let len = rows.checked_mul(cols).ok_or_else(invalid)?;
+
+    let name = String::from_utf8_lossy(next()?.data).into_owned();
+    let values: Vec<T> = decode_values(&next()?, swap)?;
+
+    if values.len() != len {
+        return Err(MatError::Truncated);
+    }
+
+    let matrix = (0..len)
+        .map(|i| values[(i % cols) * rows + i / cols])
+        .collect();
+
+    return Ok(Some((name, Matrix { rows, cols, matrix })));
+}
+
+#[cfg(feature = "zlib")]
+fn inflate(data: &[u8]) -> Result<Vec<u8>, MatError> {
+    let mut out = Vec::new();
+    flate2::read::ZlibDecoder::new(data).read_to_end(&mut out)?;
+
+    return Ok(out);
+}
+
+#[cfg(not(feature = "zlib"))]
+fn inflate(_data: &[u8]) -> Result<Vec<u8>, MatError> {
+    return Err(MatError::Unsupported(String::from(
+        "compressed variables require the zlib feature",
+    )));
+}
+
+// Reads every real, 2-D numeric variable in a Level 5 MAT-file. Other
+// variables are skipped. Compressed (v7) variables need the zlib feature.
+pub fn read_mat<T, R>(mut reader: R) -> Result<Vec<(String, Matrix<T>)>, MatError>
+where
+    T: Default + MatElement,
+    R: Read,
+{
+    let mut buf = Vec::new();
+    reader.read_to_end(&mut buf)?;
+
+    if buf.len() < HEADER_LEN {
+        return Err(MatError::Header(String::from("file is too short")));
+    }
+
+    let swap = match &buf[126..128] {
+        b"IM" => false,
+        b"MI" => true,
+        _ => return Err(MatError::Header(String::from("bad endian indicator"))),
+    };
+
+    let mut cursor = Cursor {
+        buf: &buf[HEADER_LEN..],
+        pos: 0,
+        swap,
+    };
+    let mut result = Vec::new();
+
+    while let Some(element) = cursor.next_element(false)? {
+        let variable = match element.data_type {
+            MI_MATRIX => parse_matrix(element.data, swap)?,
+            MI_COMPRESSED => {
+                let inflated = inflate(element.data)?;
+                let mut inner = Cursor {
+                    buf: &inflated,
+                    pos: 0,
+                    swap,
+                };
+                match inner.next_element(true)? {
+                    Some(e) if e.data_type == MI_MATRIX => parse_matrix(e.data, swap)?,
+                    _ => None,
+                }
+            }
+            _ => None,
+        };
+
+        if let Some(variable) = variable {
+            result.push(variable);
+        }
+
+        // Top-level matrix sizes include their own padding, but be lenient
+        // with writers that leave it out.
+        if element.data_type == MI_MATRIX {
+            cursor.pos = cursor.pos.next_multiple_of(8).min(cursor.buf.len());
+        }
+    }
+
+    return Ok(result);
+}
+
+fn too_large() -> io::Error {
+    return io::Error::new(
+        io::ErrorKind::InvalidInput,
+        "Matrix is too large for a Level 5 MAT-file",
+    );
+}
+
+fn push_element(out: &mut Vec<u8>, data_type: u32, data: &[u8]) -> io::Result<()> {
+    let len = u32::try_from(data.len()).map_err(|_| too_large())?;
+
+    out.extend_from_slice(&data_type.to_le_bytes());
+    out.extend_from_slice(&len.to_le_bytes());
+    out.extend_from_slice(data);
+    out.resize(out.len().next_multiple_of(8), 0);
+
+    return Ok(());
+}
+
+// Writes an uncompressed little-endian Level 5 MAT-file that MATLAB can
+// load with `load` (and that `save -v6` would produce).
+pub fn write_mat<T, W>(mut writer: W, variables: &[(&str, &Matrix<T>)]) -> io::Result<()>
+where
+    T: Default + MatElement,
+    W: Write,
+{
+    let mut header = format!(
+        "MATLAB 5.0 MAT-file, Platform: {}, Created by: matrix",
+        std::env::consts::OS
+    )
+    .into_bytes();
+    header.resize(116, b' ');
+    header.extend_from_slice(&[0; 8]);
+    header.extend_from_slice(&0x0100u16.to_le_bytes());
+    header.extend_from_slice(b"IM");
+    writer.write_all(&header)?;
+
+    for (name, m) in variables {
+        let mut body = Vec::new();
+
+        let mut flags = Vec::new();
+        flags.extend_from_slice(&(T::CLASS as u32).to_le_bytes());
+        flags.extend_from_slice(&0u32.to_le_bytes());
+        push_element(&mut body, MI_UINT32, &flags)?;
+
+        let rows = i32::try_from(m.rows).map_err(|_| too_large())?;
+        let cols = i32::try_from(m.cols).map_err(|_| too_large())?;
+        let mut dims = Vec::new();
+        dims.extend_from_slice(&rows.to_le_bytes());
+        dims.extend_from_slice(&cols.to_le_bytes());
+        push_element(&mut body, MI_INT32, &dims)?;
+
+        push_element(&mut body, MI_INT8, name.as_bytes())?;
+
+        let mut data = Vec::new();
+        for j in 0..m.cols {
+            for i in 0..m.rows {
+                m.matrix[i * m.cols + j].extend_le_bytes(&mut data);
+            }
+        }
+        push_element(&mut body, T::MI_TYPE, &data)?;
+
+        let mut element = Vec::new();
+        push_element(&mut element, MI_MATRIX, &body)?;
+        writer.write_all(&element)?;
+    }
+
+    return writer.flush();
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + MatElement,
+{
+    pub fn from_mat<R>(reader: R, name: &str) -> Result<Matrix<T>, MatError>
+    where
+        R: Read,
+    {
+        return read_mat(reader)?
+            .into_iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, m)| m)
+            .ok_or_else(|| MatError::NotFound(name.to_string()));
+    }
+
+    pub fn write_mat<W>(&self, writer: W, name: &str) -> io::Result<()>
+    where
+        W: Write,
+    {
+        return write_mat(writer, &[(name, self)]);
+    }
+}
@@ -0,0 +1,315 @@
+use super::bytes::{data_len, read_header, write_header, BinaryElement, BytesError, HEADER_LEN};
+use super::linalg::product;
+use super::{Axis, Matrix};
+use memmap2::{Mmap, MmapMut};
+use num_traits::Num;
+use std::fmt;
+use std::fmt::Display;
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+// A matrix file in the `to_bytes` format, mapped into memory rather than
+// read. Pages are loaded by the OS as entries are touched, so files far
+// larger than RAM can be walked row by row. Entries are decoded on access,
+// which keeps the API safe for every element type and byte order.
+#[derive(Debug)]
+pub struct MmapMatrix<T> {
+    map: Mmap,
+    path: PathBuf,
+    rows: usize,
+    cols: usize,
+    element: PhantomData<T>,
+}
+
+#[derive(Debug)]
+pub enum MmapError {
+    Io(io::Error),
+    Format(BytesError),
+    DimensionMismatch,
+    OutputAliasesInput,
+}
+
+impl Display for MmapError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            MmapError::Io(e) => write!(f, "I/O error: {}", e),
+            MmapError::Format(e) => write!(f, "Invalid matrix file: {}", e),
+            MmapError::DimensionMismatch => write!(f, "Matrix dimensions do not agree"),
+            MmapError::OutputAliasesInput => write!(f, "Output file is one of the mapped inputs"),
+        };
+    }
+}
+
+impl std::error::Error for MmapError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        return match self {
+            MmapError::Io(e) => Some(e),
+            MmapError::Format(e) => Some(e),
+            MmapError::DimensionMismatch | MmapError::OutputAliasesInput => None,
+        };
+    }
+}
+
+impl From<io::Error> for MmapError {
+    fn from(e: io::Error) -> Self {
+        return MmapError::Io(e);
+    }
+}
+
+impl From<BytesError> for MmapError {
+    fn from(e: BytesError) -> Self {
+        return MmapError::Format(e);
+    }
+}
+
+impl<T> MmapMatrix<T>
+where
+    T: Default + BinaryElement,
+{
+    // The file must not be modified or truncated while it is mapped;
+    // other processes doing so is undefined behaviour, as with any mmap.
+    pub fn open<P>(path: P) -> Result<Self, MmapError>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(&path)?;
+        let path = path.as_ref().canonicalize()?;
+        let map = unsafe { Mmap::map(&file)? };
+        let (dtype, rows, cols) = read_header(&map)?;
+
+        if dtype != T::DTYPE {
+            return Err(BytesError::DtypeMismatch {
+                expected: T::DTYPE,
+                found: dtype,
+            }
+            .into());
+        }
+
+        let expected = data_len(rows, cols, T::SIZE)?;
+        if map.len() - HEADER_LEN != expected {
+            return Err(BytesError::LengthMismatch {
+                expected,
+                found: map.len() - HEADER_LEN,
+            }
+            .into());
+        }
+
+        return Ok(MmapMatrix {
+            map,
+            path,
+            rows,
+            cols,
+            element: PhantomData,
+        });
+    }
+
+    pub fn num_rows(&self) -> usize {
+        return self.rows;
+    }
+
+    pub fn num_cols(&self) -> usize {
+        return self.cols;
+    }
+
+    pub fn at(&self, row: usize, col: usize) -> Option<T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+
+        return Some(self.decode(row * self.cols + col));
+    }
+
+    pub fn get_row(&self, i: usize) -> Option<Vec<T>> {
+        if i >= self.rows {
+            return None;
+        }
+
+        return Some(
+            (0..self.cols)
+                .map(|j| self.decode(i * self.cols + j))
+                .collect(),
+        );
+    }
+
+    // Decodes one row at a time, so only the current row is held in memory.
+    pub fn rows(&self) -> impl Iterator<Item = Vec<T>> + '_ {
+        return (0..self.rows).map(|i| self.get_row(i).unwrap());
+    }
+
+    // Copies a block of whole rows into an ordinary matrix. Returns None if
+    // the range extends past the last row.
+    pub fn slice_rows(&self, range: Range<usize>) -> Option<Matrix<T>> {
+        if range.start > range.end || range.end > self.rows {
+            return None;
+        }
+
+        let cols = self.cols;
+        let matrix = (range.start * cols..range.end * cols)
+            .map(|k| self.decode(k))
+            .collect();
+
+        return Some(Matrix {
+            rows: range.len(),
+            cols,
+            matrix,
+        });
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        return self.slice_rows(0..self.rows).unwrap();
+    }
+
+    // Consecutive blocks of whole rows, each decoded into an ordinary
+    // matrix of at most `budget` bytes (but always at least one row), for
+    // streaming over the file with bounded memory.
+    pub fn row_blocks(&self, budget: usize) -> impl Iterator<Item = Matrix<T>> + '_ {
+        let height = (budget / (self.cols * T::SIZE).max(1)).max(1);
+
+        return (0..self.rows).step_by(height).map(move |start| {
+            self.slice_rows(start..(start + height).min(self.rows))
+                .unwrap()
+        });
+    }
+
+    fn tile(&self, rows: Range<usize>, cols: Range<usize>) -> Matrix<T> {
+        let width = cols.len();
+        let matrix = rows
+            .clone()
+            .flat_map(|i| cols.clone().map(move |j| i * self.cols + j))
+            .map(|k| self.decode(k))
+            .collect();
+
+        return Matrix {
+            rows: rows.len(),
+            cols: width,
+            matrix,
+        };
+    }
+
+    fn decode(&self, k: usize) -> T {
+        let start = HEADER_LEN + k * T::SIZE;
+        return T::from_le_slice(&self.map[start..start + T::SIZE]);
+    }
+}
+
+// Out-of-core operations. `budget` is the number of bytes of decoded
+// entries held in memory at once; the OS page cache is not counted.
+impl<T> MmapMatrix<T>
+where
+    T: Default + BinaryElement + Num,
+{
+    // Sums per row (`Axis::Rows`) or per column (`Axis::Cols`), streaming
+    // blocks of rows.
+    pub fn sum_axis(&self, axis: Axis, budget: usize) -> Vec<T> {
+        let mut sums = match axis {
+            Axis::Rows => Vec::with_capacity(self.rows),
+            Axis::Cols => vec![T::zero(); self.cols],
+        };
+
+        for block in self.row_blocks(budget) {
+            let partial = block.sum_axis(axis);
+            match axis {
+                Axis::Rows => sums.extend(partial),
+                Axis::Cols => sums.iter_mut().zip(partial).for_each(|(s, p)| *s = *s + p),
+            }
+        }
+
+        return sums;
+    }
+
+    // Writes self · b to `path` in the `to_bytes` format and maps the
+    // result. Works through square tiles sized so that one tile each of
+    // self, b and the product fit in `budget`, so neither operand nor the
+    // product is ever fully in memory.
+    pub fn matmul_to_file<P>(
+        &self,
+        b: &MmapMatrix<T>,
+        path: P,
+        budget: usize,
+    ) -> Result<MmapMatrix<T>, MmapError>
+    where
+        P: AsRef<Path>,
+    {
+        if self.cols != b.rows {
+            return Err(MmapError::DimensionMismatch);
+        }
+
+        // Truncating a file that is still mapped would pull the pages out
+        // from under self or b, so an output that resolves to either input
+        // is refused before it is opened.
+        if let Ok(out) = path.as_ref().canonicalize() {
+            if out == self.path || out == b.path {
+                return Err(MmapError::OutputAliasesInput);
+            }
+        }
+
+        let (m, n, inner) = (self.rows, b.cols, self.cols);
+        let len = data_len(m, n, T::SIZE)?;
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&path)?;
+        file.set_len((HEADER_LEN + len) as u64)?;
+
+        let mut out = unsafe { MmapMut::map_mut(&file)? };
+        let mut header = Vec::with_capacity(HEADER_LEN);
+        write_header(&mut header, T::DTYPE, m, n);
+        out[..HEADER_LEN].copy_from_slice(&header);
+
+        let side = ((budget / (3 * T::SIZE)) as f64).sqrt() as usize;
+        let side = side.max(1);
+        let mut bytes = Vec::with_capacity(side * T::SIZE);
+
+        for i in (0..m).step_by(side) {
+            let rows = i..(i + side).min(m);
+            for j in (0..n).step_by(side) {
+                let cols = j..(j + side).min(n);
+                let mut c = Matrix::new_empty(rows.len(), cols.len());
+                c.fill(T::zero());
+
+                for k in (0..inner).step_by(side) {
+                    let ks = k..(k + side).min(inner);
+                    let partial = product(
+                        &self.tile(rows.clone(), ks.clone()),
+                        &b.tile(ks, cols.clone()),
+                    );
+                    c.matrix
+                        .iter_mut()
+                        .zip(partial.matrix)
+                        .for_each(|(x, p)| *x = *x + p);
+                }
+
+                for (r, row) in c.rows().enumerate() {
+                    bytes.clear();
+                    row.iter().for_each(|x| x.extend_le_bytes(&mut bytes));
+                    let start = HEADER_LEN + ((i + r) * n + j) * T::SIZE;
+                    out[start..start + bytes.len()].copy_from_slice(&bytes);
+                }
+            }
+        }
+
+        out.flush()?;
+        drop(out);
+
+        return MmapMatrix::open(path);
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + BinaryElement,
+{
+    // Maps a file written from `to_bytes` instead of reading it; see
+    // `MmapMatrix`.
+    pub fn open_mmap<P>(path: P) -> Result<MmapMatrix<T>, MmapError>
+    where
+        P: AsRef<Path>,
+    {
+        return MmapMatrix::open(path);
+    }
+}
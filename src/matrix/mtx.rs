@@ -0,0 +1,297 @@
+use super::Matrix;
+use num_traits::One;
+use std::fmt;
+use std::fmt::Display;
+use std::io;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::ops::Neg;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MtxFormat {
+    Array,
+    Coordinate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MtxSymmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+}
+
+// The `field` keyword written in the banner for each element type.
+pub trait MtxField {
+    const FIELD: &'static str;
+}
+
+macro_rules! impl_mtx_field {
+    ($field:literal, $($t:ty),*) => {
+        $(impl MtxField for $t {
+            const FIELD: &'static str = $field;
+        })*
+    };
+}
+
+impl_mtx_field!("real", f32, f64);
+impl_mtx_field!("integer", i8, i16, i32, i64, i128, isize);
+
+#[derive(Debug)]
+pub enum MtxError {
+    Io(io::Error),
+    Header(String),
+    Unsupported(String),
+    Parse { line: usize, value: String },
+    IndexOutOfBounds { line: usize, row: usize, col: usize },
+    EntryCount { expected: usize, found: usize },
+}
+
+impl Display for MtxError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            MtxError::Io(e) => write!(f, "I/O error: {}", e),
+            MtxError::Header(h) => write!(f, "Invalid Matrix Market header: {}", h),
+            MtxError::Unsupported(s) => write!(f, "Unsupported Matrix Market qualifier: {}", s),
+            MtxError::Parse { line, value } => {
+                write!(f, "Could not parse {:?} at line {}", value, line)
+            }
+            MtxError::IndexOutOfBounds { line, row, col } => {
+                write!(
+                    f,
+                    "Entry ({}, {}) at line {} is out of bounds",
+                    row, col, line
+                )
+            }
+            MtxError::EntryCount { expected, found } => {
+                write!(f, "Expected {} entries, found {}", expected, found)
+            }
+        };
+    }
+}
+
+impl std::error::Error for MtxError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        return match self {
+            MtxError::Io(e) => Some(e),
+            _ => None,
+        };
+    }
+}
+
+impl From<io::Error> for MtxError {
+    fn from(e: io::Error) -> Self {
+        return MtxError::Io(e);
+    }
+}
+
+fn parse_token<V>(token: Option<&str>, line: usize) -> Result<V, MtxError>
+where
+    V: FromStr,
+{
+    let token = token.unwrap_or("");
+
+    return token.parse::<V>().map_err(|_| MtxError::Parse {
+        line,
+        value: token.to_string(),
+    });
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy + FromStr + Neg<Output = T> + One,
+{
+    // Reads both the dense `array` and sparse `coordinate` variants into a
+    // dense matrix. Symmetric and skew-symmetric storage is expanded, and
+    // `pattern` entries are read as one.
+    pub fn from_matrix_market<R>(reader: R) -> Result<Matrix<T>, MtxError>
+    where
+        R: Read,
+    {
+        let mut lines = BufReader::new(reader).lines().enumerate();
+
+        let banner = match lines.next() {
+            Some((_, line)) => line?,
+            None => return Err(MtxError::Header(String::from("missing banner"))),
+        };
+        let banner_lower = banner.to_lowercase();
+        let banner_tokens: Vec<&str> = banner_lower.split_whitespace().collect();
+
+        if banner_tokens.len() != 5
+            || banner_tokens[0] != "%%matrixmarket"
+            || banner_tokens[1] != "matrix"
+        {
+            return Err(MtxError::Header(banner));
+        }
+
+        let format = match banner_tokens[2] {
+            "array" => MtxFormat::Array,
+            "coordinate" => MtxFormat::Coordinate,
+            other => return Err(MtxError::Unsupported(other.to_string())),
+        };
+        let pattern = match banner_tokens[3] {
+            "real" | "integer" | "double" => false,
+            "pattern" if format == MtxFormat::Coordinate => true,
+            other => return Err(MtxError::Unsupported(other.to_string())),
+        };
+        let symmetry = match banner_tokens[4] {
+            "general" => MtxSymmetry::General,
+            "symmetric" => MtxSymmetry::Symmetric,
+            "skew-symmetric" => MtxSymmetry::SkewSymmetric,
+            other => return Err(MtxError::Unsupported(other.to_string())),
+        };
+
+        let mut data = lines.filter_map(|(i, line)| match line {
+            Ok(l) if l.trim().is_empty() || l.trim_start().starts_with('%') => None,
+            Ok(l) => Some(Ok((i + 1, l))),
+            Err(e) => Some(Err(e)),
+        });
+
+        let (size_line_no, size_line) = match data.next() {
+            Some(line) => line?,
+            None => return Err(MtxError::Header(String::from("missing size line"))),
+        };
+        let mut size = size_line.split_whitespace();
+        let rows: usize = parse_token(size.next(), size_line_no)?;
+        let cols: usize = parse_token(size.next(), size_line_no)?;
+        if rows.checked_mul(cols).is_none() {
+            return Err(MtxError::Header(size_line));
+        }
+
+        let mut result = Matrix::new_empty(rows, cols);
+
+        match format {
+            MtxFormat::Array => {
+                // Array entries are listed in column-major order, and only the
+                // lower triangle is stored for the symmetric variants. The
+                // positions are walked as entries arrive rather than listed
+                // up front.
+                let mut positions = (0..cols).flat_map(move |j| {
+                    let start = match symmetry {
+                        MtxSymmetry::General => 0,
+                        MtxSymmetry::Symmetric => j,
+                        MtxSymmetry::SkewSymmetric => j + 1,
+                    };
+                    return (start..rows).map(move |i| (i, j));
+                });
+
+                let mut found = 0;
+                for line in data {
+                    let (line_no, line) = line?;
+                    let value: T = parse_token(line.split_whitespace().next(), line_no)?;
+
+                    let (i, j) = match positions.next() {
+                        Some(pos) => pos,
+                        None => {
+                            return Err(MtxError::EntryCount {
+                                expected: found,
+                                found: found + 1,
+                            })
+                        }
+                    };
+                    result.store(i, j, value, symmetry);
+                    found += 1;
+                }
+
+                let missing = positions.count();
+                if missing != 0 {
+                    return Err(MtxError::EntryCount {
+                        expected: found + missing,
+                        found,
+                    });
+                }
+            }
+            MtxFormat::Coordinate => {
+                let entries: usize = parse_token(size.next(), size_line_no)?;
+
+                let mut found = 0;
+                for line in data {
+                    let (line_no, line) = line?;
+                    let mut tokens = line.split_whitespace();
+                    let row: usize = parse_token(tokens.next(), line_no)?;
+                    let col: usize = parse_token(tokens.next(), line_no)?;
+                    let value: T = match pattern {
+                        true => T::one(),
+                        false => parse_token(tokens.next(), line_no)?,
+                    };
+
+                    if row == 0 || col == 0 || row > rows || col > cols {
+                        return Err(MtxError::IndexOutOfBounds {
+                            line: line_no,
+                            row,
+                            col,
+                        });
+                    }
+                    result.store(row - 1, col - 1, value, symmetry);
+                    found += 1;
+                }
+
+                if found != entries {
+                    return Err(MtxError::EntryCount {
+                        expected: entries,
+                        found,
+                    });
+                }
+            }
+        }
+
+        return Ok(result);
+    }
+
+    fn store(&mut self, row: usize, col: usize, value: T, symmetry: MtxSymmetry) {
+        let _ = self.set(row, col, value);
+
+        if row != col {
+            let _ = match symmetry {
+                MtxSymmetry::General => Ok(self),
+                MtxSymmetry::Symmetric => self.set(col, row, value),
+                MtxSymmetry::SkewSymmetric => self.set(col, row, -value),
+            };
+        }
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy + PartialEq + Display + MtxField,
+{
+    // Writes a `general` matrix. The coordinate variant lists only
+    // entries that differ from the default value.
+    pub fn write_matrix_market<W>(&self, mut writer: W, format: MtxFormat) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let zero = T::default();
+
+        match format {
+            MtxFormat::Array => {
+                writeln!(writer, "%%MatrixMarket matrix array {} general", T::FIELD)?;
+                writeln!(writer, "{} {}", self.rows, self.cols)?;
+
+                for j in 0..self.cols {
+                    for i in 0..self.rows {
+                        writeln!(writer, "{}", self.at_or_default(i, j))?;
+                    }
+                }
+            }
+            MtxFormat::Coordinate => {
+                let entries = self.matrix.iter().filter(|x| **x != zero).count();
+
+                writeln!(
+                    writer,
+                    "%%MatrixMarket matrix coordinate {} general",
+                    T::FIELD
+                )?;
+                writeln!(writer, "{} {} {}", self.rows, self.cols, entries)?;
+
+                for (index, x) in self.matrix.iter().enumerate() {
+                    if *x != zero {
+                        let (i, j) = (index / self.cols, index % self.cols);
+                        writeln!(writer, "{} {} {}", i + 1, j + 1, x)?;
+                    }
+                }
+            }
+        }
+
+        return writer.flush();
+    }
+}
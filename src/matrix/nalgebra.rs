@@ -0,0 +1,82 @@
+use super::Matrix;
+use ::nalgebra::{DMatrix, Dim, RawStorage, SMatrix, Scalar};
+use alloc::vec::Vec;
+
+// nalgebra stores matrices in column-major order, so every conversion
+// reorders the elements. This is done in a single pass without an
+// intermediate buffer.
+fn from_nalgebra<T, R, C, S>(m: &::nalgebra::Matrix<T, R, C, S>) -> Matrix<T>
+where
+    T: Default + Scalar,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+{
+    let (rows, cols) = m.shape();
+    let matrix: Vec<T> = (0..rows)
+        .flat_map(|i| (0..cols).map(move |j| m[(i, j)].clone()))
+        .collect();
+
+    return Matrix { rows, cols, matrix };
+}
+
+impl<T> From<Matrix<T>> for DMatrix<T>
+where
+    T: Default + Scalar,
+{
+    fn from(m: Matrix<T>) -> Self {
+        return DMatrix::from_row_iterator(m.rows, m.cols, m.matrix);
+    }
+}
+
+impl<T> From<&Matrix<T>> for DMatrix<T>
+where
+    T: Default + Scalar,
+{
+    fn from(m: &Matrix<T>) -> Self {
+        return DMatrix::from_row_slice(m.rows, m.cols, &m.matrix);
+    }
+}
+
+impl<T> From<DMatrix<T>> for Matrix<T>
+where
+    T: Default + Scalar,
+{
+    fn from(m: DMatrix<T>) -> Self {
+        return from_nalgebra(&m);
+    }
+}
+
+impl<T> From<&DMatrix<T>> for Matrix<T>
+where
+    T: Default + Scalar,
+{
+    fn from(m: &DMatrix<T>) -> Self {
+        return from_nalgebra(m);
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<SMatrix<T, R, C>> for Matrix<T>
+where
+    T: Default + Scalar,
+{
+    fn from(m: SMatrix<T, R, C>) -> Self {
+        return from_nalgebra(&m);
+    }
+}
+
+// Fails when the matrix does not have exactly R rows and C columns.
+impl<T, const R: usize, const C: usize> TryFrom<Matrix<T>> for SMatrix<T, R, C>
+where
+    T: Default + Scalar,
+{
+    type Error = &'static str;
+
+    fn try_from(m: Matrix<T>) -> Result<Self, Self::Error> {
+        if m.rows != R || m.cols != C {
+            return Err("Matrix dimensions do not match the target size");
+        }
+
+        return Ok(SMatrix::from_row_iterator(m.matrix));
+    }
+}
@@ -0,0 +1,76 @@
+use super::Matrix;
+use ::ndarray::{Array2, ArrayD, ArrayView2, ArrayViewMut2, Ix2, ShapeError};
+use alloc::vec::Vec;
+
+// Matrix storage is a row-major Vec, which is exactly an owned ndarray in
+// standard layout, so conversions in that direction move the buffer.
+impl<T> From<Matrix<T>> for Array2<T>
+where
+    T: Default,
+{
+    fn from(m: Matrix<T>) -> Self {
+        return Array2::from_shape_vec((m.rows, m.cols), m.matrix).unwrap();
+    }
+}
+
+// Arrays in standard layout are moved without copying. Transposed, sliced
+// or Fortran-ordered arrays are copied into row-major order.
+impl<T> From<Array2<T>> for Matrix<T>
+where
+    T: Default + Clone,
+{
+    fn from(a: Array2<T>) -> Self {
+        let (rows, cols) = a.dim();
+
+        let matrix = if a.is_standard_layout() {
+            match a.into_raw_vec_and_offset() {
+                (v, Some(0) | None) if v.len() == rows * cols => v,
+                (v, Some(offset)) => v[offset..offset + rows * cols].to_vec(),
+                (v, None) => v,
+            }
+        } else {
+            a.iter().cloned().collect::<Vec<T>>()
+        };
+
+        return Matrix { rows, cols, matrix };
+    }
+}
+
+impl<T> TryFrom<ArrayD<T>> for Matrix<T>
+where
+    T: Default + Clone,
+{
+    type Error = ShapeError;
+
+    fn try_from(a: ArrayD<T>) -> Result<Self, Self::Error> {
+        return Ok(a.into_dimensionality::<Ix2>()?.into());
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default,
+{
+    pub fn as_array_view(&self) -> ArrayView2<'_, T> {
+        return ArrayView2::from_shape((self.rows, self.cols), &self.matrix).unwrap();
+    }
+
+    pub fn as_array_view_mut(&mut self) -> ArrayViewMut2<'_, T> {
+        return ArrayViewMut2::from_shape((self.rows, self.cols), &mut self.matrix).unwrap();
+    }
+}
+
+impl<T> From<ArrayView2<'_, T>> for Matrix<T>
+where
+    T: Default + Clone,
+{
+    fn from(a: ArrayView2<'_, T>) -> Self {
+        let (rows, cols) = a.dim();
+
+        return Matrix {
+            rows,
+            cols,
+            matrix: a.iter().cloned().collect(),
+        };
+    }
+}
@@ -0,0 +1,90 @@
+use super::{Axis, Matrix};
+use alloc::vec::Vec;
+use num_traits::Float;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Norm {
+    // Absolute values sum to one.
+    L1,
+    // Unit Euclidean length.
+    L2,
+    // Rescaled onto [0, 1].
+    MinMax,
+}
+
+// How one row or column was mapped: normalized = (x - offset) / scale.
+// Slices with zero scale (all zeros, or constant for MinMax) map to zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Scaling<T> {
+    pub offset: T,
+    pub scale: T,
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Float,
+{
+    pub fn normalize_rows(&self, norm: Norm) -> Matrix<T> {
+        return self.normalize_axis(Axis::Rows, norm).0;
+    }
+
+    pub fn normalize_cols(&self, norm: Norm) -> Matrix<T> {
+        return self.normalize_axis(Axis::Cols, norm).0;
+    }
+
+    // Also returns the scaling of every row or column, so the same
+    // transform can be applied to new data or undone.
+    pub fn normalize_axis(&self, axis: Axis, norm: Norm) -> (Matrix<T>, Vec<Scaling<T>>) {
+        let (count, _) = self.lane_shape(axis);
+        let scalings: Vec<Scaling<T>> = (0..count)
+            .map(|i| {
+                let lane = self.lane(axis, i);
+                match norm {
+                    Norm::L1 => Scaling {
+                        offset: T::zero(),
+                        scale: lane.fold(T::zero(), |acc, x| acc + x.abs()),
+                    },
+                    Norm::L2 => Scaling {
+                        offset: T::zero(),
+                        scale: lane.fold(T::zero(), |acc, x| acc + *x * *x).sqrt(),
+                    },
+                    Norm::MinMax => {
+                        let (lo, hi) = lane
+                            .fold((T::infinity(), T::neg_infinity()), |(lo, hi), x| {
+                                (lo.min(*x), hi.max(*x))
+                            });
+                        Scaling {
+                            offset: lo,
+                            scale: hi - lo,
+                        }
+                    }
+                }
+            })
+            .collect();
+
+        let matrix = self
+            .matrix
+            .iter()
+            .enumerate()
+            .map(|(index, x)| {
+                let lane = match axis {
+                    Axis::Rows => index / self.cols,
+                    Axis::Cols => index % self.cols,
+                };
+                let s = scalings[lane];
+                match s.scale == T::zero() {
+                    true => T::zero(),
+                    false => (*x - s.offset) / s.scale,
+                }
+            })
+            .collect();
+
+        let result = Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            matrix,
+        };
+
+        return (result, scalings);
+    }
+}
@@ -0,0 +1,315 @@
+use super::Matrix;
+use std::fmt;
+use std::fmt::Display;
+use std::io;
+use std::io::{Read, Write};
+
+#[cfg(feature = "npz")]
+use std::io::Seek;
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+// Element types that have a fixed-size NumPy dtype. `KIND` and `SIZE` form
+// the dtype string, e.g. 'f' and 8 for `<f8`.
+pub trait NpyElement: Sized + Copy {
+    const KIND: char;
+    const SIZE: usize;
+
+    fn from_le_slice(bytes: &[u8]) -> Self;
+    fn from_be_slice(bytes: &[u8]) -> Self;
+    fn extend_le_bytes(self, out: &mut Vec<u8>);
+}
+
+macro_rules! impl_npy_element {
+    ($kind:literal, $($t:ty),*) => {
+        $(impl NpyElement for $t {
+            const KIND: char = $kind;
+            const SIZE: usize = std::mem::size_of::<$t>();
+
+            fn from_le_slice(bytes: &[u8]) -> Self {
+                return <$t>::from_le_bytes(bytes.try_into().unwrap());
+            }
+
+            fn from_be_slice(bytes: &[u8]) -> Self {
+                return <$t>::from_be_bytes(bytes.try_into().unwrap());
+            }
+
+            fn extend_le_bytes(self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+        })*
+    };
+}
+
+impl_npy_element!('f', f32, f64);
+impl_npy_element!('i', i8, i16, i32, i64);
+impl_npy_element!('u', u8, u16, u32, u64);
+
+#[derive(Debug)]
+pub enum NpyError {
+    Io(io::Error),
+    Header(String),
+    DtypeMismatch {
+        expected: String,
+        found: String,
+    },
+    Shape(Vec<usize>),
+    #[cfg(feature = "npz")]
+    Zip(zip::result::ZipError),
+}
+
+impl Display for NpyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            NpyError::Io(e) => write!(f, "I/O error: {}", e),
+            NpyError::Header(h) => write!(f, "Invalid .npy header: {}", h),
+            NpyError::DtypeMismatch { expected, found } => {
+                write!(f, "Expected dtype {}, found {}", expected, found)
+            }
+            NpyError::Shape(shape) => write!(f, "Cannot load array of shape {:?}", shape),
+            #[cfg(feature = "npz")]
+            NpyError::Zip(e) => write!(f, "Zip error: {}", e),
+        };
+    }
+}
+
+impl std::error::Error for NpyError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        return match self {
+            NpyError::Io(e) => Some(e),
+            #[cfg(feature = "npz")]
+            NpyError::Zip(e) => Some(e),
+            _ => None,
+        };
+    }
+}
+
+impl From<io::Error> for NpyError {
+    fn from(e: io::Error) -> Self {
+        return NpyError::Io(e);
+    }
+}
+
+#[cfg(feature = "npz")]
+impl From<zip::result::ZipError> for NpyError {
+    fn from(e: zip::result::ZipError) -> Self {
+        return NpyError::Zip(e);
+    }
+}
+
+// Returns the text following `'key':` in the header dictionary.
+fn dict_value<'a>(header: &'a str, key: &str) -> Result<&'a str, NpyError> {
+    let pattern = format!("'{}':", key);
+
+    return match header.find(&pattern) {
+        Some(i) => Ok(header[i + pattern.len()..].trim_start()),
+        None => Err(NpyError::Header(format!("missing key {}", key))),
+    };
+}
+
+// Reads exactly `len` bytes, growing the buffer as data arrives rather than
+// allocating `len` up front, so a hostile header cannot force a huge
+// allocation for a short stream.
+fn read_bytes<R>(reader: R, len: usize) -> Result<Vec<u8>, NpyError>
+where
+    R: Read,
+{
+    let mut bytes = Vec::new();
+    reader.take(len as u64).read_to_end(&mut bytes)?;
+
+    if bytes.len() != len {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+    }
+
+    return Ok(bytes);
+}
+
+fn parse_header(header: &str) -> Result<(String, bool, Vec<usize>), NpyError> {
+    let descr = dict_value(header, "descr")?;
+    let quote = descr.chars().next().unwrap_or('\'');
+    let descr = descr
+        .get(1..)
+        .and_then(|d| d.split(quote).next())
+        .ok_or_else(|| NpyError::Header(header.to_string()))?;
+
+    let fortran_order = match dict_value(header, "fortran_order")? {
+        s if s.starts_with("True") => true,
+        s if s.starts_with("False") => false,
+        _ => return Err(NpyError::Header(header.to_string())),
+    };
+
+    let shape = dict_value(header, "shape")?;
+    let shape = shape
+        .strip_prefix('(')
+        .and_then(|s| s.split(')').next())
+        .ok_or_else(|| NpyError::Header(header.to_string()))?;
+    let shape = shape
+        .split(',')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse::<usize>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| NpyError::Header(header.to_string()))?;
+
+    return Ok((descr.to_string(), fortran_order, shape));
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + NpyElement,
+{
+    // One-dimensional arrays load as a single row. Fortran-ordered data is
+    // transposed into row-major order, and big-endian data is byte-swapped.
+    pub fn from_npy<R>(mut reader: R) -> Result<Matrix<T>, NpyError>
+    where
+        R: Read,
+    {
+        let mut magic = [0u8; 8];
+        reader.read_exact(&mut magic)?;
+
+        if &magic[..6] != MAGIC {
+            return Err(NpyError::Header(String::from("missing magic string")));
+        }
+
+        let header_len = match magic[6] {
+            1 => {
+                let mut len = [0u8; 2];
+                reader.read_exact(&mut len)?;
+                u16::from_le_bytes(len) as usize
+            }
+            2 | 3 => {
+                let mut len = [0u8; 4];
+                reader.read_exact(&mut len)?;
+                u32::from_le_bytes(len) as usize
+            }
+            v => return Err(NpyError::Header(format!("unsupported version {}", v))),
+        };
+
+        let header = read_bytes(&mut reader, header_len)?;
+        let header = String::from_utf8_lossy(&header);
+        let (descr, fortran_order, shape) = parse_header(&header)?;
+
+        let expected = format!("{}{}", T::KIND, T::SIZE);
+        let (big_endian, dtype) = match descr.split_at(descr.len().min(1)) {
+            (">", dtype) => (true, dtype),
+            ("<" | "|" | "=", dtype) => (false, dtype),
+            _ => (false, descr.as_str()),
+        };
+        if dtype != expected {
+            return Err(NpyError::DtypeMismatch {
+                expected,
+                found: descr,
+            });
+        }
+
+        let (rows, cols) = match shape.as_slice() {
+            [n] => (1, *n),
+            [rows, cols] => (*rows, *cols),
+            _ => return Err(NpyError::Shape(shape)),
+        };
+
+        let len = rows
+            .checked_mul(cols)
+            .and_then(|n| n.checked_mul(T::SIZE))
+            .ok_or_else(|| NpyError::Shape(shape.clone()))?;
+        let bytes = read_bytes(&mut reader, len)?;
+
+        let values: Vec<T> = bytes
+            .chunks_exact(T::SIZE)
+            .map(|b| match big_endian {
+                true => T::from_be_slice(b),
+                false => T::from_le_slice(b),
+            })
+            .collect();
+
+        let matrix = match fortran_order {
+            false => values,
+            true => (0..rows * cols)
+                .map(|i| values[(i % cols) * rows + i / cols])
+                .collect(),
+        };
+
+        return Ok(Matrix { rows, cols, matrix });
+    }
+
+    pub fn to_npy<W>(&self, mut writer: W) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let byte_order = if T::SIZE == 1 { '|' } else { '<' };
+        let mut header = format!(
+            "{{'descr': '{}{}{}', 'fortran_order': False, 'shape': ({}, {}), }}",
+            byte_order,
+            T::KIND,
+            T::SIZE,
+            self.rows,
+            self.cols
+        );
+
+        // The header is padded with spaces and a newline so that the data
+        // starts on a 64-byte boundary.
+        let v1 = header.len() + 64 <= u16::MAX as usize;
+        let prefix_len = MAGIC.len() + if v1 { 4 } else { 6 };
+        let padding = (64 - (prefix_len + header.len() + 1) % 64) % 64;
+        header.push_str(&" ".repeat(padding));
+        header.push('\n');
+
+        writer.write_all(MAGIC)?;
+        if v1 {
+            writer.write_all(&[1, 0])?;
+            writer.write_all(&(header.len() as u16).to_le_bytes())?;
+        } else {
+            writer.write_all(&[2, 0])?;
+            writer.write_all(&(header.len() as u32).to_le_bytes())?;
+        }
+        writer.write_all(header.as_bytes())?;
+
+        let mut bytes = Vec::with_capacity(self.matrix.len() * T::SIZE);
+        self.matrix
+            .iter()
+            .for_each(|x| x.extend_le_bytes(&mut bytes));
+        writer.write_all(&bytes)?;
+
+        return writer.flush();
+    }
+}
+
+// Reads every array in an archive written by `numpy.savez` or
+// `numpy.savez_compressed`. Names have their `.npy` suffix removed.
+#[cfg(feature = "npz")]
+pub fn read_npz<T, R>(reader: R) -> Result<Vec<(String, Matrix<T>)>, NpyError>
+where
+    T: Default + NpyElement,
+    R: Read + Seek,
+{
+    let mut archive = zip::ZipArchive::new(reader)?;
+    let mut result = Vec::with_capacity(archive.len());
+
+    for i in 0..archive.len() {
+        let file = archive.by_index(i)?;
+        let name = file.name()?.trim_end_matches(".npy").to_string();
+
+        result.push((name, Matrix::from_npy(file)?));
+    }
+
+    return Ok(result);
+}
+
+#[cfg(feature = "npz")]
+pub fn write_npz<T, W>(writer: W, arrays: &[(&str, &Matrix<T>)]) -> Result<(), NpyError>
+where
+    T: Default + NpyElement,
+    W: Write + Seek,
+{
+    let mut archive = zip::ZipWriter::new(writer);
+    let options =
+        zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+
+    for (name, m) in arrays {
+        archive.start_file(format!("{}.npy", name), options)?;
+        m.to_npy(&mut archive)?;
+    }
+    archive.finish()?;
+
+    return Ok(());
+}
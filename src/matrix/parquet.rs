@@ -0,0 +1,47 @@
+use super::Matrix;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+use parquet::errors::ParquetError;
+use parquet::file::reader::ChunkReader;
+use std::io::Write;
+
+impl Matrix<f64> {
+    pub fn write_parquet<W>(&self, writer: W, names: Option<&[&str]>) -> Result<(), ParquetError>
+    where
+        W: Write + Send,
+    {
+        let batch = self.to_record_batch(names)?;
+        let mut writer = ArrowWriter::try_new(writer, batch.schema(), None)?;
+        writer.write(&batch)?;
+        writer.close()?;
+
+        return Ok(());
+    }
+
+    // Reads every row group of the file and stacks them into one matrix.
+    pub fn from_parquet<R>(reader: R, names: Option<&[&str]>) -> Result<Matrix<f64>, ParquetError>
+    where
+        R: ChunkReader + 'static,
+    {
+        let batches = ParquetRecordBatchReaderBuilder::try_new(reader)?.build()?;
+        let mut result: Option<Matrix<f64>> = None;
+
+        for batch in batches {
+            let m = Matrix::from_record_batch(&batch?, names)?;
+
+            match result.as_mut() {
+                Some(r) => {
+                    r.matrix.extend(m.matrix);
+                    r.rows += m.rows;
+                }
+                None => result = Some(m),
+            }
+        }
+
+        return Ok(result.unwrap_or(Matrix {
+            rows: 0,
+            cols: names.map_or(0, |n| n.len()),
+            matrix: Vec::new(),
+        }));
+    }
+}
@@ -0,0 +1,183 @@
+use super::linalg::product;
+use super::{Axis, Matrix};
+use alloc::vec::Vec;
+use num_traits::Float;
+
+// A fitted principal component analysis. Row i of `components` is the
+// i-th principal axis, ordered by decreasing explained variance. Each axis
+// is signed so that its largest entry is positive, which makes results
+// reproducible.
+#[derive(Debug, PartialEq)]
+pub struct Pca<T>
+where
+    T: Default,
+{
+    pub mean: Vec<T>,
+    pub components: Matrix<T>,
+    pub explained_variance: Vec<T>,
+    pub explained_variance_ratio: Vec<T>,
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Float,
+{
+    // Fits PCA with one observation per row, from the eigendecomposition of
+    // the sample covariance. Returns None with fewer than two observations
+    // or when `n_components` exceeds the number of features.
+    pub fn pca(&self, n_components: usize) -> Option<Pca<T>> {
+        let d = self.cols;
+        if n_components > d {
+            return None;
+        }
+
+        let covariance = self.covariance(Axis::Rows, 1)?;
+        let (values, vectors) = covariance.symmetric_eigen()?;
+        let total = values
+            .iter()
+            .fold(T::zero(), |acc, v| acc + v.max(T::zero()));
+
+        let mut components = Matrix::new_empty(n_components, d);
+        for (c, j) in (d - n_components..d).rev().enumerate() {
+            let axis: Vec<T> = (0..d).map(|i| vectors.matrix[i * d + j]).collect();
+            let largest = axis.iter().fold(T::zero(), |m, x| match x.abs() > m.abs() {
+                true => *x,
+                false => m,
+            });
+            let sign = match largest < T::zero() {
+                true => -T::one(),
+                false => T::one(),
+            };
+            for (i, x) in axis.into_iter().enumerate() {
+                components.matrix[c * d + i] = sign * x;
+            }
+        }
+
+        let explained_variance: Vec<T> = values
+            .iter()
+            .rev()
+            .take(n_components)
+            .map(|v| v.max(T::zero()))
+            .collect();
+        let explained_variance_ratio = explained_variance.iter().map(|v| *v / total).collect();
+
+        return Some(Pca {
+            mean: self.mean_axis(Axis::Cols),
+            components,
+            explained_variance,
+            explained_variance_ratio,
+        });
+    }
+}
+
+impl<T> Pca<T>
+where
+    T: Default + Float,
+{
+    // Coordinates of each row of `x` along the principal axes.
+    pub fn project(&self, x: &Matrix<T>) -> Option<Matrix<T>> {
+        if x.cols != self.mean.len() {
+            return None;
+        }
+
+        let centered = Matrix {
+            rows: x.rows,
+            cols: x.cols,
+            matrix: x
+                .matrix
+                .iter()
+                .enumerate()
+                .map(|(k, v)| *v - self.mean[k % x.cols])
+                .collect(),
+        };
+
+        return Some(product(&centered, &self.components.transpose()));
+    }
+
+    // Maps projected coordinates back to the original feature space. This
+    // is exact when every component was kept.
+    pub fn inverse_transform(&self, z: &Matrix<T>) -> Option<Matrix<T>> {
+        if z.cols != self.components.rows {
+            return None;
+        }
+
+        let mut result = product(z, &self.components);
+        let d = result.cols;
+        for (k, v) in result.matrix.iter_mut().enumerate() {
+            *v = *v + self.mean[k % d];
+        }
+
+        return Some(result);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WhiteningMethod {
+    // Rotates onto the principal axes and rescales: Λ^(-1/2) Vᵀ.
+    Pca,
+    // Same scaling but rotated back, C^(-1/2), so the result stays as close
+    // as possible to the original features.
+    Zca,
+}
+
+// A fitted whitening transform: whitened = (x - mean) transformᵀ.
+#[derive(Debug, PartialEq)]
+pub struct Whitening<T>
+where
+    T: Default,
+{
+    pub mean: Vec<T>,
+    pub transform: Matrix<T>,
+}
+
+impl<T> Whitening<T>
+where
+    T: Default + Float,
+{
+    pub fn apply(&self, x: &Matrix<T>) -> Option<Matrix<T>> {
+        if x.cols != self.mean.len() {
+            return None;
+        }
+
+        let mut centered = x.map(|v| *v);
+        for (k, v) in centered.matrix.iter_mut().enumerate() {
+            *v = *v - self.mean[k % x.cols];
+        }
+
+        return Some(product(&centered, &self.transform.transpose()));
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Float,
+{
+    // Decorrelates the features (one observation per row) and scales them
+    // to unit variance, from the eigendecomposition of the covariance.
+    // `epsilon` is added to every eigenvalue so near-constant directions
+    // are not blown up. Returns the whitened data and the transform.
+    pub fn whiten(&self, method: WhiteningMethod, epsilon: T) -> Option<(Matrix<T>, Whitening<T>)> {
+        let (values, vectors) = self.covariance(Axis::Rows, 1)?.symmetric_eigen()?;
+        let d = self.cols;
+
+        // Λ^(-1/2) Vᵀ, row i scaled by the i-th eigenvalue.
+        let mut transform = vectors.transpose();
+        for (i, value) in values.iter().enumerate() {
+            let scale = T::one() / (value.max(T::zero()) + epsilon).sqrt();
+            for x in &mut transform.matrix[i * d..(i + 1) * d] {
+                *x = *x * scale;
+            }
+        }
+
+        if method == WhiteningMethod::Zca {
+            transform = product(&vectors, &transform);
+        }
+
+        let whitening = Whitening {
+            mean: self.mean_axis(Axis::Cols),
+            transform,
+        };
+
+        return Some((whitening.apply(self)?, whitening));
+    }
+}
@@ -0,0 +1,156 @@
+use super::Matrix;
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::{One, Zero};
+
+// Row i of P is the unit vector e_{p[i]}, so P * A takes row p[i] of A as
+// its row i. Permuting rows this way is how pivoted factorisations record
+// their row exchanges.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PermutationMatrix {
+    indices: Vec<usize>,
+}
+
+impl PermutationMatrix {
+    pub fn identity(size: usize) -> Self {
+        return PermutationMatrix {
+            indices: (0..size).collect(),
+        };
+    }
+
+    // Returns None unless every index in 0..len appears exactly once.
+    pub fn from_indices(indices: Vec<usize>) -> Option<Self> {
+        let mut seen = vec![false; indices.len()];
+
+        for i in &indices {
+            match seen.get_mut(*i) {
+                Some(s) if !*s => *s = true,
+                _ => return None,
+            }
+        }
+
+        return Some(PermutationMatrix { indices });
+    }
+
+    pub fn size(&self) -> usize {
+        return self.indices.len();
+    }
+
+    pub fn indices(&self) -> &[usize] {
+        return &self.indices;
+    }
+
+    // Exchanges rows i and j of the permutation, as a pivot step does.
+    pub fn swap(&mut self, i: usize, j: usize) -> &mut Self {
+        self.indices.swap(i, j);
+        return self;
+    }
+
+    // P * Q, which applies Q first and then P.
+    pub fn compose(&self, q: &PermutationMatrix) -> Option<PermutationMatrix> {
+        if self.size() != q.size() {
+            return None;
+        }
+
+        let indices = self.indices.iter().map(|i| q.indices[*i]).collect();
+        return Some(PermutationMatrix { indices });
+    }
+
+    // The inverse of a permutation matrix is its transpose.
+    pub fn inverse(&self) -> PermutationMatrix {
+        let mut indices = vec![0; self.size()];
+
+        for (i, p) in self.indices.iter().enumerate() {
+            indices[*p] = i;
+        }
+
+        return PermutationMatrix { indices };
+    }
+
+    // +1 for an even permutation and -1 for an odd one, which is also the
+    // determinant.
+    pub fn sign(&self) -> i32 {
+        let mut visited = vec![false; self.size()];
+        let mut sign = 1;
+
+        for start in 0..self.size() {
+            let mut length = 0;
+            let mut i = start;
+            while !visited[i] {
+                visited[i] = true;
+                i = self.indices[i];
+                length += 1;
+            }
+            if length > 0 && length % 2 == 0 {
+                sign = -sign;
+            }
+        }
+
+        return sign;
+    }
+
+    pub fn apply_vector<T>(&self, v: &[T]) -> Option<Vec<T>>
+    where
+        T: Copy,
+    {
+        if v.len() != self.size() {
+            return None;
+        }
+
+        return Some(self.indices.iter().map(|i| v[*i]).collect());
+    }
+
+    // P * A, moving whole rows.
+    pub fn apply_rows<T>(&self, m: &Matrix<T>) -> Option<Matrix<T>>
+    where
+        T: Default + Copy,
+    {
+        if m.rows != self.size() {
+            return None;
+        }
+
+        let mut matrix = Vec::with_capacity(m.matrix.len());
+        for i in &self.indices {
+            matrix.extend_from_slice(&m.matrix[i * m.cols..(i + 1) * m.cols]);
+        }
+
+        return Some(Matrix {
+            rows: m.rows,
+            cols: m.cols,
+            matrix,
+        });
+    }
+
+    // A * P, which sends column k of A to column p[k].
+    pub fn apply_cols<T>(&self, m: &Matrix<T>) -> Option<Matrix<T>>
+    where
+        T: Default + Copy,
+    {
+        if m.cols != self.size() {
+            return None;
+        }
+
+        let mut result = Matrix::new_empty(m.rows, m.cols);
+        for r in 0..m.rows {
+            for (k, p) in self.indices.iter().enumerate() {
+                result.matrix[r * m.cols + p] = m.matrix[r * m.cols + k];
+            }
+        }
+
+        return Some(result);
+    }
+
+    pub fn to_dense<T>(&self) -> Matrix<T>
+    where
+        T: Default + Copy + Zero + One,
+    {
+        let n = self.size();
+        let mut result = Matrix::from_iter(n, n, (0..n * n).map(|_| T::zero())).unwrap();
+
+        for (i, p) in self.indices.iter().enumerate() {
+            result.matrix[i * n + p] = T::one();
+        }
+
+        return result;
+    }
+}
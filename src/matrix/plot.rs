@@ -0,0 +1,216 @@
+use super::heatmap::{unit_scale, ColorScale};
+use super::Matrix;
+use plotters::prelude::*;
+use std::fmt;
+use std::fmt::Display;
+use std::path::Path;
+
+// Stops for the RGB scales, sampled from matplotlib's viridis and RdBu.
+const VIRIDIS: [(u8, u8, u8); 5] = [
+    (68, 1, 84),
+    (59, 82, 139),
+    (33, 145, 140),
+    (94, 201, 98),
+    (253, 231, 37),
+];
+const DIVERGING: [(u8, u8, u8); 5] = [
+    (33, 102, 172),
+    (146, 197, 222),
+    (247, 247, 247),
+    (244, 165, 130),
+    (178, 24, 43),
+];
+
+#[derive(Debug)]
+pub enum PlotError {
+    Empty,
+    Drawing(String),
+}
+
+impl Display for PlotError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return match self {
+            PlotError::Empty => write!(f, "Cannot plot a matrix with no entries"),
+            PlotError::Drawing(e) => write!(f, "Drawing failed: {}", e),
+        };
+    }
+}
+
+impl std::error::Error for PlotError {}
+
+impl<E> From<DrawingAreaErrorKind<E>> for PlotError
+where
+    E: std::error::Error + Send + Sync,
+{
+    fn from(e: DrawingAreaErrorKind<E>) -> Self {
+        return PlotError::Drawing(e.to_string());
+    }
+}
+
+// The image is `width` by `height` pixels, split evenly between the
+// matrix entries. Values are clamped to `range`, which defaults to the
+// matrix minimum and maximum.
+#[derive(Debug, Clone)]
+pub struct PlotOptions {
+    pub width: u32,
+    pub height: u32,
+    pub scale: ColorScale,
+    pub range: Option<(f64, f64)>,
+}
+
+impl Default for PlotOptions {
+    fn default() -> Self {
+        return PlotOptions {
+            width: 600,
+            height: 600,
+            scale: ColorScale::Viridis,
+            range: None,
+        };
+    }
+}
+
+impl PlotOptions {
+    pub fn new() -> Self {
+        return Default::default();
+    }
+
+    pub fn size(mut self, width: u32, height: u32) -> Self {
+        self.width = width;
+        self.height = height;
+        return self;
+    }
+
+    pub fn scale(mut self, scale: ColorScale) -> Self {
+        self.scale = scale;
+        return self;
+    }
+
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some((min, max));
+        return self;
+    }
+}
+
+fn interpolate(stops: &[(u8, u8, u8)], t: f64) -> RGBColor {
+    let position = t * (stops.len() - 1) as f64;
+    let i = (position as usize).min(stops.len() - 2);
+    let f = position - i as f64;
+    let lerp = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * f).round() as u8;
+    let (a, b) = (stops[i], stops[i + 1]);
+
+    return RGBColor(lerp(a.0, b.0), lerp(a.1, b.1), lerp(a.2, b.2));
+}
+
+fn color(scale: ColorScale, t: f64) -> RGBColor {
+    return match scale {
+        ColorScale::Grayscale => interpolate(&[(0, 0, 0), (255, 255, 255)], t),
+        ColorScale::Viridis => interpolate(&VIRIDIS, t),
+        ColorScale::Diverging => interpolate(&DIVERGING, t),
+    };
+}
+
+// Fills one rectangle per entry, skipping entries without a color.
+fn draw_cells<DB, F>(
+    root: DrawingArea<DB, plotters::coord::Shift>,
+    rows: usize,
+    cols: usize,
+    cell_color: F,
+) -> Result<(), PlotError>
+where
+    DB: DrawingBackend,
+    F: Fn(usize) -> Option<RGBColor>,
+{
+    root.fill(&WHITE)?;
+
+    let (width, height) = root.dim_in_pixel();
+    let x = |j: usize| (j as u64 * width as u64 / cols as u64) as i32;
+    let y = |i: usize| (i as u64 * height as u64 / rows as u64) as i32;
+
+    for i in 0..rows {
+        for j in 0..cols {
+            if let Some(c) = cell_color(i * cols + j) {
+                let corners = [(x(j), y(i)), (x(j + 1), y(i + 1))];
+                root.draw(&Rectangle::new(corners, c.filled()))?;
+            }
+        }
+    }
+
+    root.present()?;
+    return Ok(());
+}
+
+// Picks the backend from the file extension: `.svg` writes a vector
+// image, anything else a bitmap (PNG for `.png`).
+fn render<P, F>(
+    path: P,
+    options: &PlotOptions,
+    rows: usize,
+    cols: usize,
+    cell_color: F,
+) -> Result<(), PlotError>
+where
+    P: AsRef<Path>,
+    F: Fn(usize) -> Option<RGBColor>,
+{
+    if rows == 0 || cols == 0 {
+        return Err(PlotError::Empty);
+    }
+
+    let path = path.as_ref();
+    let size = (options.width, options.height);
+
+    return match path.extension().and_then(|e| e.to_str()) {
+        Some("svg") => draw_cells(
+            SVGBackend::new(path, size).into_drawing_area(),
+            rows,
+            cols,
+            cell_color,
+        ),
+        _ => draw_cells(
+            BitMapBackend::new(path, size).into_drawing_area(),
+            rows,
+            cols,
+            cell_color,
+        ),
+    };
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy + Into<f64>,
+{
+    // NaN entries are left white.
+    pub fn plot_heatmap<P>(&self, path: P, options: &PlotOptions) -> Result<(), PlotError>
+    where
+        P: AsRef<Path>,
+    {
+        let values: Vec<f64> = self.matrix.iter().map(|x| (*x).into()).collect();
+        let unit = unit_scale(values.iter().cloned(), options.scale, options.range);
+
+        return render(path, options, self.rows, self.cols, |i| match values[i] {
+            x if x.is_nan() => None,
+            x => Some(color(options.scale, unit(x))),
+        });
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + PartialEq,
+{
+    // Sparsity plot: entries that differ from the default value are drawn
+    // black on a white background. The color scale and range are unused.
+    pub fn spy<P>(&self, path: P, options: &PlotOptions) -> Result<(), PlotError>
+    where
+        P: AsRef<Path>,
+    {
+        let zero = T::default();
+
+        return render(path, options, self.rows, self.cols, |i| {
+            match self.matrix[i] != zero {
+                true => Some(BLACK),
+                false => None,
+            }
+        });
+    }
+}
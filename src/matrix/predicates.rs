@@ -0,0 +1,98 @@
+use super::linalg::product;
+use super::symmetric::SymmetricMatrix;
+use super::Matrix;
+use num_traits::Float;
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy,
+{
+    pub fn is_square(&self) -> bool {
+        return self.rows == self.cols;
+    }
+}
+
+// Structure checks for dispatching to specialised algorithms. Entries
+// within `tolerance` of the required value count as matching, so pass zero
+// for an exact test. All of them are false for non-square matrices.
+impl<T> Matrix<T>
+where
+    T: Default + Float,
+{
+    pub fn is_symmetric(&self, tolerance: T) -> bool {
+        let n = self.rows;
+
+        return self.is_square()
+            && (0..n).all(|i| {
+                (0..i).all(|j| (self.matrix[i * n + j] - self.matrix[j * n + i]).abs() <= tolerance)
+            });
+    }
+
+    pub fn is_diagonal(&self, tolerance: T) -> bool {
+        return self.all_off(tolerance, |i, j| i != j);
+    }
+
+    // Zero below the diagonal.
+    pub fn is_upper_triangular(&self, tolerance: T) -> bool {
+        return self.all_off(tolerance, |i, j| i > j);
+    }
+
+    // Zero above the diagonal.
+    pub fn is_lower_triangular(&self, tolerance: T) -> bool {
+        return self.all_off(tolerance, |i, j| i < j);
+    }
+
+    pub fn is_identity(&self, tolerance: T) -> bool {
+        let n = self.rows;
+
+        return self.is_diagonal(tolerance)
+            && (0..n).all(|i| (self.matrix[i * n + i] - T::one()).abs() <= tolerance);
+    }
+
+    // QᵀQ = I within `tolerance` per entry.
+    pub fn is_orthogonal(&self, tolerance: T) -> bool {
+        return self.is_square() && product(&self.transpose(), self).is_identity(tolerance);
+    }
+
+    // Attempts a Cholesky factorization, which succeeds exactly when the
+    // matrix is symmetric positive definite. Symmetry is checked up to
+    // rounding relative to the largest entry.
+    pub fn is_positive_definite(&self) -> bool {
+        let largest = self.matrix.iter().fold(T::zero(), |m, x| m.max(x.abs()));
+        let tolerance = T::from(self.rows).unwrap() * T::epsilon() * largest;
+
+        return self.is_symmetric(tolerance)
+            && SymmetricMatrix::from_lower(self)
+                .and_then(|s| s.cholesky())
+                .is_some();
+    }
+
+    // Symmetric within `tolerance` with no eigenvalue below -`tolerance`.
+    // Uses the full eigendecomposition, so it is much slower than
+    // `is_positive_definite`.
+    pub fn is_positive_semidefinite(&self, tolerance: T) -> bool {
+        if !self.is_symmetric(tolerance) {
+            return false;
+        }
+
+        return match self.symmetric_eigen() {
+            Some((values, _)) => values.iter().all(|v| *v >= -tolerance),
+            None => false,
+        };
+    }
+
+    // Every entry where `zero(i, j)` holds is within `tolerance` of zero.
+    fn all_off<F>(&self, tolerance: T, zero: F) -> bool
+    where
+        F: Fn(usize, usize) -> bool,
+    {
+        let n = self.cols;
+
+        return self.is_square()
+            && self
+                .matrix
+                .iter()
+                .enumerate()
+                .all(|(k, x)| !zero(k / n, k % n) || x.abs() <= tolerance);
+    }
+}
@@ -0,0 +1,83 @@
+use super::{Axis, Matrix};
+use alloc::vec::Vec;
+
+// Element queries. `any` and `all` stop at the first entry that decides
+// the answer, and the axis variants give one answer per row (`Axis::Rows`)
+// or per column (`Axis::Cols`).
+impl<T> Matrix<T>
+where
+    T: Default + Copy,
+{
+    pub fn any<F>(&self, f: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        return self.matrix.iter().any(f);
+    }
+
+    // True for an empty matrix.
+    pub fn all<F>(&self, f: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        return self.matrix.iter().all(f);
+    }
+
+    pub fn count<F>(&self, mut f: F) -> usize
+    where
+        F: FnMut(&T) -> bool,
+    {
+        return self.matrix.iter().filter(|x| f(x)).count();
+    }
+
+    pub fn any_axis<F>(&self, axis: Axis, mut f: F) -> Vec<bool>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let (count, _) = self.lane_shape(axis);
+
+        return (0..count).map(|i| self.lane(axis, i).any(&mut f)).collect();
+    }
+
+    pub fn all_axis<F>(&self, axis: Axis, mut f: F) -> Vec<bool>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let (count, _) = self.lane_shape(axis);
+
+        return (0..count).map(|i| self.lane(axis, i).all(&mut f)).collect();
+    }
+
+    pub fn count_axis<F>(&self, axis: Axis, mut f: F) -> Vec<usize>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let (count, _) = self.lane_shape(axis);
+
+        return (0..count)
+            .map(|i| self.lane(axis, i).filter(|x| f(x)).count())
+            .collect();
+    }
+
+    // Row-major fold over every entry.
+    pub fn fold<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &T) -> B,
+    {
+        return self.matrix.iter().fold(init, f);
+    }
+
+    // Folds each row (`Axis::Rows`) or column (`Axis::Cols`) separately,
+    // each starting from a copy of `init`.
+    pub fn fold_axis<B, F>(&self, axis: Axis, init: B, mut f: F) -> Vec<B>
+    where
+        B: Clone,
+        F: FnMut(B, &T) -> B,
+    {
+        let (count, _) = self.lane_shape(axis);
+
+        return (0..count)
+            .map(|i| self.lane(axis, i).fold(init.clone(), &mut f))
+            .collect();
+    }
+}
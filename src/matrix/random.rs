@@ -0,0 +1,208 @@
+use super::permutation::PermutationMatrix;
+use super::Matrix;
+use alloc::vec::Vec;
+use num_traits::Float;
+use rand::distributions::Standard;
+use rand::prelude::Distribution;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+use rand_distr::StandardNormal;
+
+// ChaCha8 produces the same stream on every platform and, unlike StdRng,
+// is not allowed to change between rand releases, so seeded matrices stay
+// reproducible. Pass the result to any of the random constructors.
+pub fn seeded_rng(seed: u64) -> ChaCha8Rng {
+    return ChaCha8Rng::seed_from_u64(seed);
+}
+
+impl<T> Matrix<T>
+where
+    T: Default,
+{
+    // Fills the matrix with independent samples from `distribution`, e.g.
+    // `rand_distr::Uniform::new(-1.0, 1.0)` or `rand_distr::Exp::new(2.0)`.
+    pub fn random_with<D, R>(rows: usize, cols: usize, distribution: &D, rng: &mut R) -> Self
+    where
+        D: Distribution<T>,
+        R: Rng + ?Sized,
+    {
+        return Matrix {
+            rows,
+            cols,
+            matrix: (0..rows * cols).map(|_| distribution.sample(rng)).collect(),
+        };
+    }
+
+    // Each entry is drawn from `distribution` with probability `density`
+    // and left at the default value otherwise.
+    pub fn random_sparse<D, R>(
+        rows: usize,
+        cols: usize,
+        density: f64,
+        distribution: &D,
+        rng: &mut R,
+    ) -> Result<Self, &'static str>
+    where
+        D: Distribution<T>,
+        R: Rng + ?Sized,
+    {
+        if !(0.0..=1.0).contains(&density) {
+            return Err("Density must be between 0 and 1");
+        }
+
+        let matrix = (0..rows * cols)
+            .map(|_| match rng.gen_bool(density) {
+                true => distribution.sample(rng),
+                false => T::default(),
+            })
+            .collect();
+
+        return Ok(Matrix { rows, cols, matrix });
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default,
+    Standard: Distribution<T>,
+{
+    // Samples rand's standard distribution: uniform on [0, 1) for floats
+    // and over the whole range for integers.
+    pub fn random<R>(rows: usize, cols: usize, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        return Matrix::random_with(rows, cols, &Standard, rng);
+    }
+
+    pub fn random_seeded(rows: usize, cols: usize, seed: u64) -> Self {
+        return Matrix::random(rows, cols, &mut seeded_rng(seed));
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default,
+    StandardNormal: Distribution<T>,
+{
+    // Entries are independent samples with mean 0 and variance 1.
+    pub fn random_normal<R>(rows: usize, cols: usize, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        return Matrix::random_with(rows, cols, &StandardNormal, rng);
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Float,
+    StandardNormal: Distribution<T>,
+{
+    // Orthonormalises the columns of a Gaussian matrix with modified
+    // Gram-Schmidt. R then has a positive diagonal, which makes Q uniformly
+    // distributed over the orthogonal group.
+    pub fn random_orthogonal<R>(n: usize, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        let mut q = Matrix::<T>::random_normal(n, n, rng);
+
+        for j in 0..n {
+            for k in 0..j {
+                let dot = (0..n).fold(T::zero(), |acc, i| {
+                    acc + q.matrix[i * n + k] * q.matrix[i * n + j]
+                });
+                for i in 0..n {
+                    q.matrix[i * n + j] = q.matrix[i * n + j] - dot * q.matrix[i * n + k];
+                }
+            }
+
+            let norm = (0..n)
+                .fold(T::zero(), |acc, i| acc + q.matrix[i * n + j].powi(2))
+                .sqrt();
+            for i in 0..n {
+                q.matrix[i * n + j] = q.matrix[i * n + j] / norm;
+            }
+        }
+
+        return q;
+    }
+
+    // AᵀA + εI for a Gaussian A, which is symmetric positive definite for
+    // any positive `epsilon`.
+    pub fn random_spd<R>(n: usize, epsilon: T, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+    {
+        let a = Matrix::<T>::random_normal(n, n, rng);
+        let mut matrix = Vec::with_capacity(n * n);
+
+        for i in 0..n {
+            for j in 0..n {
+                let dot = (0..n).fold(T::zero(), |acc, k| {
+                    acc + a.matrix[k * n + i] * a.matrix[k * n + j]
+                });
+                matrix.push(match i == j {
+                    true => dot + epsilon,
+                    false => dot,
+                });
+            }
+        }
+
+        return Matrix {
+            rows: n,
+            cols: n,
+            matrix,
+        };
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy,
+{
+    // Puts the rows in a uniformly random order. Returns the permutation
+    // that was applied, so labels kept alongside can be shuffled to match
+    // with `apply_vector`.
+    pub fn shuffle_rows<R>(&mut self, rng: &mut R) -> PermutationMatrix
+    where
+        R: Rng + ?Sized,
+    {
+        let mut order: Vec<usize> = (0..self.rows).collect();
+        order.shuffle(rng);
+
+        let permutation = PermutationMatrix::from_indices(order).unwrap();
+        self.matrix = permutation.apply_rows(self).unwrap().matrix;
+
+        return permutation;
+    }
+
+    // Randomly splits the rows into two matrices, the first holding
+    // `fraction` of them rounded to the nearest row, e.g. a train/test
+    // split. Each part keeps the original row order.
+    pub fn partition_rows<R>(
+        &self,
+        fraction: f64,
+        rng: &mut R,
+    ) -> Result<(Matrix<T>, Matrix<T>), &'static str>
+    where
+        R: Rng + ?Sized,
+    {
+        if !(0.0..=1.0).contains(&fraction) {
+            return Err("Fraction must be between 0 and 1");
+        }
+
+        let count = (fraction * self.rows as f64).round() as usize;
+        let mut order: Vec<usize> = (0..self.rows).collect();
+        let mut first = order.partial_shuffle(rng, count).0.to_vec();
+        first.sort_unstable();
+
+        return Ok(self.split_rows_at(&first).unwrap());
+    }
+
+    pub fn shuffle_rows_seeded(&mut self, seed: u64) -> PermutationMatrix {
+        return self.shuffle_rows(&mut seeded_rng(seed));
+    }
+}
@@ -0,0 +1,69 @@
+use super::Matrix;
+use num_traits::{Float, Num, NumCast};
+
+impl<T> Matrix<T>
+where
+    T: Default + Float,
+{
+    // `rows * cols` evenly spaced values from `start` to `end` inclusive,
+    // laid out row-major. A single element is just `start`.
+    pub fn linspace(start: T, end: T, rows: usize, cols: usize) -> Self {
+        let n = rows * cols;
+        let step = match n {
+            0 | 1 => T::zero(),
+            n => (end - start) / T::from(n - 1).unwrap(),
+        };
+
+        let matrix = (0..n)
+            .map(|k| match k + 1 == n && n > 1 {
+                true => end,
+                false => start + step * T::from(k).unwrap(),
+            })
+            .collect();
+
+        return Matrix { rows, cols, matrix };
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy + Num + NumCast,
+{
+    // start, start + step, start + 2 * step, ... laid out row-major. Each
+    // value is computed from its index, so float steps do not accumulate
+    // rounding error.
+    pub fn arange(start: T, step: T, rows: usize, cols: usize) -> Self {
+        let matrix = (0..rows * cols)
+            .map(|k| start + step * T::from(k).unwrap())
+            .collect();
+
+        return Matrix { rows, cols, matrix };
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy,
+{
+    // Coordinate matrices with `ys.len()` rows and `xs.len()` columns, so
+    // entry (i, j) of the pair is the point (xs[j], ys[i]). This matches
+    // numpy's default `xy` indexing.
+    pub fn meshgrid(xs: &[T], ys: &[T]) -> (Self, Self) {
+        let (rows, cols) = (ys.len(), xs.len());
+        let x = (0..rows * cols).map(|k| xs[k % cols]).collect();
+        let y = (0..rows * cols).map(|k| ys[k / cols]).collect();
+
+        return (
+            Matrix {
+                rows,
+                cols,
+                matrix: x,
+            },
+            Matrix {
+                rows,
+                cols,
+                matrix: y,
+            },
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use super::linalg::product;
+use super::Matrix;
+use alloc::vec::Vec;
+use core::iter::zip;
+use num_traits::Float;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Solver {
+    // Householder QR of X. Slower but accurate for ill-conditioned X.
+    Qr,
+    // Solves XᵀX β = Xᵀy directly, which squares the condition number.
+    NormalEquations,
+}
+
+#[derive(Debug, Clone)]
+pub struct RegressionOptions {
+    pub intercept: bool,
+    pub solver: Solver,
+}
+
+impl Default for RegressionOptions {
+    fn default() -> Self {
+        return RegressionOptions {
+            intercept: true,
+            solver: Solver::Qr,
+        };
+    }
+}
+
+impl RegressionOptions {
+    pub fn new() -> Self {
+        return Default::default();
+    }
+
+    // Fits an extra constant term, as if X had a leading column of ones.
+    pub fn intercept(mut self, intercept: bool) -> Self {
+        self.intercept = intercept;
+        return self;
+    }
+
+    pub fn solver(mut self, solver: Solver) -> Self {
+        self.solver = solver;
+        return self;
+    }
+}
+
+// `intercept` is zero when the options did not ask for one. Residuals are
+// y - ŷ for every observation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearRegression<T> {
+    pub coefficients: Vec<T>,
+    pub intercept: T,
+    pub residuals: Vec<T>,
+    pub r_squared: T,
+}
+
+impl<T> LinearRegression<T>
+where
+    T: Default + Float,
+{
+    // Predictions for new observations, one per row of `x`.
+    pub fn predict(&self, x: &Matrix<T>) -> Option<Vec<T>> {
+        if x.cols != self.coefficients.len() {
+            return None;
+        }
+
+        let predictions = (0..x.rows)
+            .map(|i| {
+                let row = &x.matrix[i * x.cols..(i + 1) * x.cols];
+                zip(row, &self.coefficients).fold(self.intercept, |acc, (a, b)| acc + *a * *b)
+            })
+            .collect();
+
+        return Some(predictions);
+    }
+}
+
+// Ordinary least squares with one observation per row of `x`. Returns None
+// if `y` does not have one value per row, or if the design matrix is rank
+// deficient (collinear features, or fewer observations than unknowns).
+pub fn linear_regression<T>(
+    x: &Matrix<T>,
+    y: &[T],
+    options: &RegressionOptions,
+) -> Option<LinearRegression<T>>
+where
+    T: Default + Float,
+{
+    if y.len() != x.rows {
+        return None;
+    }
+
+    let design = design_matrix(x, options.intercept);
+    let target = Matrix::from_flat(y.len(), 1, y.to_vec()).ok()?;
+
+    let beta = match options.solver {
+        Solver::Qr => design.lstsq(&target)?,
+        Solver::NormalEquations => {
+            let xt = design.transpose();
+            product(&xt, &design).solve(&product(&xt, &target))?
+        }
+    };
+
+    let (intercept, coefficients) = match options.intercept {
+        true => (beta.matrix[0], beta.matrix[1..].to_vec()),
+        false => (T::zero(), beta.matrix),
+    };
+
+    let fitted = LinearRegression {
+        coefficients,
+        intercept,
+        residuals: Vec::new(),
+        r_squared: T::zero(),
+    };
+    let residuals: Vec<T> = zip(y, fitted.predict(x)?).map(|(a, b)| *a - b).collect();
+
+    let mean = y.iter().fold(T::zero(), |acc, v| acc + *v) / T::from(y.len()).unwrap();
+    let total = y
+        .iter()
+        .fold(T::zero(), |acc, v| acc + (*v - mean) * (*v - mean));
+    let unexplained = residuals.iter().fold(T::zero(), |acc, r| acc + *r * *r);
+
+    return Some(LinearRegression {
+        residuals,
+        r_squared: T::one() - unexplained / total,
+        ..fitted
+    });
+}
+
+// Ridge regression: minimizes |Xβ - y|² + λ|β|² by solving the augmented
+// least-squares problem [X; √λ I] β ≈ [y; 0] with QR, which stays stable
+// even when XᵀX is singular. No intercept is fitted, so center the data
+// first if the constant term should not be penalized. Returns None for a
+// negative `lambda` or mismatched `y`.
+pub fn solve_ridge<T>(x: &Matrix<T>, y: &[T], lambda: T) -> Option<Vec<T>>
+where
+    T: Default + Float,
+{
+    if y.len() != x.rows || lambda < T::zero() {
+        return None;
+    }
+
+    let (m, n) = (x.rows, x.cols);
+    let root = lambda.sqrt();
+    let mut augmented = Matrix::new_empty(m + n, n);
+    augmented.matrix[..m * n].copy_from_slice(&x.matrix);
+    for j in 0..n {
+        augmented.matrix[(m + j) * n + j] = root;
+    }
+
+    let mut target = Matrix::new_empty(m + n, 1);
+    target.matrix[..m].copy_from_slice(y);
+
+    return Some(augmented.lstsq(&target)?.matrix);
+}
+
+pub(crate) fn design_matrix<T>(x: &Matrix<T>, intercept: bool) -> Matrix<T>
+where
+    T: Default + Float,
+{
+    if !intercept {
+        return x.map(|v| *v);
+    }
+
+    let cols = x.cols + 1;
+    let matrix = (0..x.rows * cols)
+        .map(|k| match k % cols {
+            0 => T::one(),
+            j => x.matrix[(k / cols) * x.cols + j - 1],
+        })
+        .collect();
+
+    return Matrix {
+        rows: x.rows,
+        cols,
+        matrix,
+    };
+}
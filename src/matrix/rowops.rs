@@ -0,0 +1,175 @@
+use super::Matrix;
+use alloc::vec::Vec;
+use num_traits::Num;
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy,
+{
+    // Replaces each row with `f(row)`. Every call must return the same
+    // length, which becomes the column count; returns None otherwise.
+    pub fn map_rows<F, U>(&self, mut f: F) -> Option<Matrix<U>>
+    where
+        F: FnMut(&[T]) -> Vec<U>,
+        U: Default,
+    {
+        let (mut matrix, mut cols) = (Vec::new(), None);
+
+        for i in 0..self.rows {
+            let row = f(&self.matrix[i * self.cols..(i + 1) * self.cols]);
+            if *cols.get_or_insert(row.len()) != row.len() {
+                return None;
+            }
+            matrix.extend(row);
+        }
+
+        return Some(Matrix {
+            rows: self.rows,
+            cols: cols.unwrap_or(0),
+            matrix,
+        });
+    }
+
+    // Replaces each column with `f(column)`, e.g. a per-feature transform.
+    // Every call must return the same length, which becomes the row count.
+    pub fn map_cols<F, U>(&self, mut f: F) -> Option<Matrix<U>>
+    where
+        F: FnMut(&[T]) -> Vec<U>,
+        U: Default + Copy,
+    {
+        let mut columns = Vec::with_capacity(self.cols);
+        for j in 0..self.cols {
+            let column: Vec<T> = (0..self.rows)
+                .map(|i| self.matrix[i * self.cols + j])
+                .collect();
+            columns.push(f(&column));
+        }
+
+        let rows = columns.first().map_or(0, |c| c.len());
+        if columns.iter().any(|c| c.len() != rows) {
+            return None;
+        }
+
+        let matrix = (0..rows * self.cols)
+            .map(|k| columns[k % self.cols][k / self.cols])
+            .collect();
+
+        return Some(Matrix {
+            rows,
+            cols: self.cols,
+            matrix,
+        });
+    }
+}
+
+// Bulk copies into an existing matrix. Rows are copied as whole slices
+// rather than entry by entry through `set`.
+impl<T> Matrix<T>
+where
+    T: Default + Copy,
+{
+    pub fn copy_from(&mut self, other: &Matrix<T>) -> Result<&mut Self, &str> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err("Dimensions do not match");
+        }
+
+        self.matrix.copy_from_slice(&other.matrix);
+        return Ok(self);
+    }
+
+    // Overwrites every entry in place, keeping the allocation.
+    pub fn fill(&mut self, value: T) -> &mut Self {
+        self.matrix.fill(value);
+        return self;
+    }
+
+    pub fn fill_with<F>(&mut self, mut f: F) -> &mut Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let cols = self.cols;
+        for (k, x) in self.matrix.iter_mut().enumerate() {
+            *x = f(k / cols, k % cols);
+        }
+        return self;
+    }
+
+    pub fn set_row(&mut self, i: usize, values: &[T]) -> Result<&mut Self, &str> {
+        if i >= self.rows {
+            return Err("Index out of bounds");
+        }
+        if values.len() != self.cols {
+            return Err("Length does not match dimensions");
+        }
+
+        self.matrix[i * self.cols..(i + 1) * self.cols].copy_from_slice(values);
+        return Ok(self);
+    }
+
+    pub fn set_col(&mut self, j: usize, values: &[T]) -> Result<&mut Self, &str> {
+        if j >= self.cols {
+            return Err("Index out of bounds");
+        }
+        if values.len() != self.rows {
+            return Err("Length does not match dimensions");
+        }
+
+        for (i, v) in values.iter().enumerate() {
+            self.matrix[i * self.cols + j] = *v;
+        }
+        return Ok(self);
+    }
+
+    // Copies all of `src` with its top-left corner at (row, col). Fails
+    // unless it fits entirely inside self.
+    pub fn copy_submatrix_from(
+        &mut self,
+        src: &Matrix<T>,
+        (row, col): (usize, usize),
+    ) -> Result<&mut Self, &str> {
+        if row + src.rows > self.rows || col + src.cols > self.cols {
+            return Err("Submatrix does not fit");
+        }
+
+        for i in 0..src.rows {
+            let start = (row + i) * self.cols + col;
+            self.matrix[start..start + src.cols]
+                .copy_from_slice(&src.matrix[i * src.cols..(i + 1) * src.cols]);
+        }
+        return Ok(self);
+    }
+}
+
+// Elementary row operations, applied in place. Like `set`, they return
+// self for chaining and fail on an out-of-range row.
+impl<T> Matrix<T>
+where
+    T: Default + Copy + Num,
+{
+    pub fn scale_row(&mut self, i: usize, s: T) -> Result<&mut Self, &str> {
+        if i >= self.rows {
+            return Err("Index out of bounds");
+        }
+
+        for x in &mut self.matrix[i * self.cols..(i + 1) * self.cols] {
+            *x = *x * s;
+        }
+
+        return Ok(self);
+    }
+
+    // Row `dst` += `alpha` * row `src`.
+    pub fn row_axpy(&mut self, dst: usize, src: usize, alpha: T) -> Result<&mut Self, &str> {
+        if dst >= self.rows || src >= self.rows {
+            return Err("Index out of bounds");
+        }
+
+        let cols = self.cols;
+        for j in 0..cols {
+            let x = self.matrix[src * cols + j];
+            self.matrix[dst * cols + j] = self.matrix[dst * cols + j] + alpha * x;
+        }
+
+        return Ok(self);
+    }
+}
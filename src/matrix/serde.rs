@@ -0,0 +1,58 @@
+use super::Matrix;
+use alloc::vec::Vec;
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+impl<T> Serialize for Matrix<T>
+where
+    T: Default + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Matrix", 3)?;
+        state.serialize_field("rows", &self.rows)?;
+        state.serialize_field("cols", &self.cols)?;
+        state.serialize_field("data", &self.matrix)?;
+
+        return state.end();
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename = "Matrix")]
+struct RawMatrix<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<'de, T> Deserialize<'de> for Matrix<T>
+where
+    T: Default + Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = RawMatrix::<T>::deserialize(deserializer)?;
+        let expected = raw
+            .rows
+            .checked_mul(raw.cols)
+            .ok_or_else(|| de::Error::custom("matrix dimensions overflow usize"))?;
+
+        if raw.data.len() != expected {
+            return Err(de::Error::invalid_length(
+                raw.data.len(),
+                &"rows * cols elements in data",
+            ));
+        }
+
+        return Ok(Matrix {
+            rows: raw.rows,
+            cols: raw.cols,
+            matrix: raw.data,
+        });
+    }
+}
@@ -0,0 +1,86 @@
+use super::Matrix;
+use alloc::sync::Arc;
+use core::ops::Deref;
+
+// A reference-counted matrix for read-mostly fan-out: `clone` only bumps
+// the count, every read method of `Matrix` is available through `Deref`,
+// and the data is copied only when a shared handle is first mutated.
+#[derive(Debug)]
+pub struct ArcMatrix<T>
+where
+    T: Default,
+{
+    inner: Arc<Matrix<T>>,
+}
+
+impl<T> Clone for ArcMatrix<T>
+where
+    T: Default,
+{
+    fn clone(&self) -> Self {
+        return ArcMatrix {
+            inner: Arc::clone(&self.inner),
+        };
+    }
+}
+
+impl<T> ArcMatrix<T>
+where
+    T: Default + Copy,
+{
+    pub fn new(m: Matrix<T>) -> Self {
+        return ArcMatrix { inner: Arc::new(m) };
+    }
+
+    // Mutable access, copying the data first if any other handle shares it.
+    pub fn make_mut(&mut self) -> &mut Matrix<T> {
+        if Arc::get_mut(&mut self.inner).is_none() {
+            self.inner = Arc::new(self.inner.map(|x| *x));
+        }
+
+        return Arc::get_mut(&mut self.inner).unwrap();
+    }
+
+    // Takes the matrix back, copying only if it is still shared.
+    pub fn into_matrix(self) -> Matrix<T> {
+        return Arc::try_unwrap(self.inner).unwrap_or_else(|shared| shared.map(|x| *x));
+    }
+
+    pub fn is_shared(&self) -> bool {
+        return Arc::strong_count(&self.inner) > 1;
+    }
+
+    pub fn ptr_eq(&self, other: &ArcMatrix<T>) -> bool {
+        return Arc::ptr_eq(&self.inner, &other.inner);
+    }
+}
+
+impl<T> Deref for ArcMatrix<T>
+where
+    T: Default,
+{
+    type Target = Matrix<T>;
+
+    fn deref(&self) -> &Matrix<T> {
+        return &self.inner;
+    }
+}
+
+impl<T> From<Matrix<T>> for ArcMatrix<T>
+where
+    T: Default + Copy,
+{
+    fn from(m: Matrix<T>) -> Self {
+        return ArcMatrix::new(m);
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy,
+{
+    // Moves the matrix behind a reference count without copying it.
+    pub fn into_shared(self) -> ArcMatrix<T> {
+        return ArcMatrix::new(self);
+    }
+}
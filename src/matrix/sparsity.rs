@@ -0,0 +1,45 @@
+use super::Matrix;
+use alloc::vec::Vec;
+use num_traits::Float;
+
+// Sparsity statistics for deciding whether a sparse representation pays
+// off. Entries with magnitude at most `tolerance` count as zero; NaN counts
+// as nonzero.
+impl<T> Matrix<T>
+where
+    T: Default + Float,
+{
+    pub fn count_nonzero(&self, tolerance: T) -> usize {
+        return self
+            .matrix
+            .iter()
+            .filter(|x| is_nonzero(**x, tolerance))
+            .count();
+    }
+
+    // Fraction of entries that are nonzero, zero for an empty matrix.
+    pub fn density(&self, tolerance: T) -> T {
+        if self.matrix.is_empty() {
+            return T::zero();
+        }
+
+        let nonzero = T::from(self.count_nonzero(tolerance)).unwrap();
+
+        return nonzero / T::from(self.matrix.len()).unwrap();
+    }
+
+    // (row, col) of every nonzero entry in row-major order.
+    pub fn nonzero_indices(&self, tolerance: T) -> Vec<(usize, usize)> {
+        return self
+            .matrix
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| is_nonzero(**x, tolerance))
+            .map(|(k, _)| (k / self.cols, k % self.cols))
+            .collect();
+    }
+}
+
+fn is_nonzero<T: Float>(x: T, tolerance: T) -> bool {
+    return x.is_nan() || x.abs() > tolerance;
+}
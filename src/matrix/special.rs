@@ -0,0 +1,109 @@
+use super::Matrix;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Neg;
+use num_traits::{Float, Num};
+
+// H[i][j] = 1 / (i + j + 1). Notoriously ill-conditioned, which is what
+// makes it a useful stress test.
+pub fn hilbert<T>(n: usize) -> Matrix<T>
+where
+    T: Default + Float,
+{
+    let matrix = (0..n * n)
+        .map(|k| T::one() / T::from(k / n + k % n + 1).unwrap())
+        .collect();
+
+    return Matrix {
+        rows: n,
+        cols: n,
+        matrix,
+    };
+}
+
+// Row i is [1, x_i, x_i^2, ..., x_i^(cols - 1)], in increasing powers.
+pub fn vandermonde<T>(xs: &[T], cols: usize) -> Matrix<T>
+where
+    T: Default + Copy + Num,
+{
+    let mut matrix = Vec::with_capacity(xs.len() * cols);
+
+    for x in xs {
+        let mut power = T::one();
+        for _ in 0..cols {
+            matrix.push(power);
+            power = power * *x;
+        }
+    }
+
+    return Matrix {
+        rows: xs.len(),
+        cols,
+        matrix,
+    };
+}
+
+// Constant along each diagonal, with T[i][j] = col[i - j] below the
+// diagonal and row[j - i] above it. The diagonal comes from `col[0]`, so
+// `row[0]` is ignored.
+pub fn toeplitz<T>(col: &[T], row: &[T]) -> Matrix<T>
+where
+    T: Default + Copy,
+{
+    let (rows, cols) = (col.len(), row.len());
+    let matrix = (0..rows * cols)
+        .map(|k| match (k / cols, k % cols) {
+            (i, j) if i >= j => col[i - j],
+            (i, j) => row[j - i],
+        })
+        .collect();
+
+    return Matrix { rows, cols, matrix };
+}
+
+// Each column is the one before it shifted down by one, wrapping around,
+// so C[i][j] = c[(i - j) mod n] with `c` as the first column.
+pub fn circulant<T>(c: &[T]) -> Matrix<T>
+where
+    T: Default + Copy,
+{
+    let n = c.len();
+    let matrix = (0..n * n).map(|k| c[(k / n + n - k % n) % n]).collect();
+
+    return Matrix {
+        rows: n,
+        cols: n,
+        matrix,
+    };
+}
+
+// For a0 x^n + a1 x^(n-1) + ... + an, the first row is -[a1, ..., an] / a0
+// with ones on the subdiagonal, so the eigenvalues are the polynomial's
+// roots. Returns None for fewer than two coefficients or a zero leading
+// coefficient.
+pub fn companion<T>(coefficients: &[T]) -> Option<Matrix<T>>
+where
+    T: Default + Copy + Num + Neg<Output = T>,
+{
+    let n = coefficients.len().checked_sub(1).filter(|n| *n > 0)?;
+    let lead = coefficients[0];
+
+    if lead == T::zero() {
+        return None;
+    }
+
+    let mut result = Matrix {
+        rows: n,
+        cols: n,
+        matrix: vec![T::zero(); n * n],
+    };
+
+    for j in 0..n {
+        result.matrix[j] = -coefficients[j + 1] / lead;
+    }
+    for i in 1..n {
+        result.matrix[i * n + i - 1] = T::one();
+    }
+
+    return Some(result);
+}
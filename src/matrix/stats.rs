@@ -0,0 +1,324 @@
+use super::{Axis, Matrix};
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::{Float, Num};
+
+// Axis-wise reductions return one value per row for `Axis::Rows` and one
+// per column for `Axis::Cols`.
+impl<T> Matrix<T>
+where
+    T: Default + Copy + Num,
+{
+    pub fn sum_axis(&self, axis: Axis) -> Vec<T> {
+        return self.fold_axis(axis, T::zero(), |acc, x| acc + *x);
+    }
+
+    pub fn sum(&self) -> T {
+        return self.fold(T::zero(), |acc, x| acc + *x);
+    }
+
+    // Running totals along each row (`Axis::Rows`) or down each column
+    // (`Axis::Cols`). Applying both gives a summed-area table.
+    pub fn cumsum_axis(&self, axis: Axis) -> Matrix<T> {
+        return self.scan_axis(axis, |acc, x| acc + x);
+    }
+
+    pub fn cumprod_axis(&self, axis: Axis) -> Matrix<T> {
+        return self.scan_axis(axis, |acc, x| acc * x);
+    }
+
+    // The `n`-th discrete difference along each row (`Axis::Rows`) or down
+    // each column (`Axis::Cols`). Every order shortens that direction by
+    // one, down to zero length.
+    pub fn diff_axis(&self, axis: Axis, n: usize) -> Matrix<T> {
+        let mut result = Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            matrix: self.matrix.clone(),
+        };
+
+        for _ in 0..n {
+            let (rows, cols) = match axis {
+                Axis::Rows => (result.rows, result.cols.saturating_sub(1)),
+                Axis::Cols => (result.rows.saturating_sub(1), result.cols),
+            };
+            let (di, dj) = match axis {
+                Axis::Rows => (0, 1),
+                Axis::Cols => (1, 0),
+            };
+            let matrix = (0..rows * cols)
+                .map(|k| {
+                    let (i, j) = (k / cols, k % cols);
+                    let next = result.matrix[(i + di) * result.cols + j + dj];
+                    next - result.matrix[i * result.cols + j]
+                })
+                .collect();
+
+            result = Matrix { rows, cols, matrix };
+        }
+
+        return result;
+    }
+
+    fn scan_axis<F>(&self, axis: Axis, f: F) -> Matrix<T>
+    where
+        F: Fn(T, T) -> T,
+    {
+        let mut result = Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            matrix: self.matrix.clone(),
+        };
+        let step = match axis {
+            Axis::Rows => 1,
+            Axis::Cols => self.cols,
+        };
+
+        for index in 0..result.matrix.len() {
+            let continues = match axis {
+                Axis::Rows => index % self.cols != 0,
+                Axis::Cols => index >= self.cols,
+            };
+            if continues {
+                result.matrix[index] = f(result.matrix[index - step], result.matrix[index]);
+            }
+        }
+
+        return result;
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy + PartialOrd,
+{
+    // None when the slices are empty. Incomparable values such as NaN are
+    // skipped unless they come first, and ties keep the first occurrence.
+    pub fn min_axis(&self, axis: Axis) -> Option<Vec<T>> {
+        return self.extreme_axis(axis, |x, m| x < m, |(_, x)| x);
+    }
+
+    pub fn max_axis(&self, axis: Axis) -> Option<Vec<T>> {
+        return self.extreme_axis(axis, |x, m| x > m, |(_, x)| x);
+    }
+
+    pub fn min(&self) -> Option<T> {
+        return extreme(self.matrix.iter(), |x, m| x < m).map(|(_, x)| x);
+    }
+
+    pub fn max(&self) -> Option<T> {
+        return extreme(self.matrix.iter(), |x, m| x > m).map(|(_, x)| x);
+    }
+
+    // Position of each slice's extreme within that slice, so a column index
+    // per row for `Axis::Rows`.
+    pub fn argmin_axis(&self, axis: Axis) -> Option<Vec<usize>> {
+        return self.extreme_axis(axis, |x, m| x < m, |(i, _)| i);
+    }
+
+    pub fn argmax_axis(&self, axis: Axis) -> Option<Vec<usize>> {
+        return self.extreme_axis(axis, |x, m| x > m, |(i, _)| i);
+    }
+
+    // (row, col) of the first smallest element.
+    pub fn argmin(&self) -> Option<(usize, usize)> {
+        let (index, _) = extreme(self.matrix.iter(), |x, m| x < m)?;
+
+        return Some((index / self.cols, index % self.cols));
+    }
+
+    pub fn argmax(&self) -> Option<(usize, usize)> {
+        let (index, _) = extreme(self.matrix.iter(), |x, m| x > m)?;
+
+        return Some((index / self.cols, index % self.cols));
+    }
+
+    fn extreme_axis<F, P, R>(&self, axis: Axis, better: F, pick: P) -> Option<Vec<R>>
+    where
+        F: Fn(&T, &T) -> bool + Copy,
+        P: Fn((usize, T)) -> R,
+    {
+        let (count, _) = self.lane_shape(axis);
+
+        return (0..count)
+            .map(|i| extreme(self.lane(axis, i), better).map(&pick))
+            .collect();
+    }
+}
+
+// Index and value of the element that `better` prefers over all others.
+fn extreme<'a, T, I, F>(values: I, better: F) -> Option<(usize, T)>
+where
+    T: Copy + 'a,
+    I: Iterator<Item = &'a T>,
+    F: Fn(&T, &T) -> bool,
+{
+    let mut values = values.enumerate();
+    let (index, first) = values.next()?;
+
+    return Some(
+        values.fold((index, *first), |(i, m), (j, x)| match better(x, &m) {
+            true => (j, *x),
+            false => (i, m),
+        }),
+    );
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Float,
+{
+    // NaN for empty slices.
+    pub fn mean_axis(&self, axis: Axis) -> Vec<T> {
+        let (_, len) = self.lane_shape(axis);
+        let n = T::from(len).unwrap();
+
+        return self.sum_axis(axis).into_iter().map(|s| s / n).collect();
+    }
+
+    // Per-slice variance with `ddof` delta degrees of freedom, computed in
+    // one pass with Welford's update. NaN when a slice has no more than
+    // `ddof` elements.
+    pub fn var_axis(&self, axis: Axis, ddof: usize) -> Vec<T> {
+        let (count, _) = self.lane_shape(axis);
+
+        return (0..count)
+            .map(|i| welford_variance(self.lane(axis, i), ddof))
+            .collect();
+    }
+
+    pub fn std_axis(&self, axis: Axis, ddof: usize) -> Vec<T> {
+        return self
+            .var_axis(axis, ddof)
+            .into_iter()
+            .map(|v| v.sqrt())
+            .collect();
+    }
+
+    pub fn mean(&self) -> T {
+        return self.sum() / T::from(self.matrix.len()).unwrap();
+    }
+
+    pub fn var(&self, ddof: usize) -> T {
+        return welford_variance(self.matrix.iter(), ddof);
+    }
+
+    pub fn std(&self, ddof: usize) -> T {
+        return self.var(ddof).sqrt();
+    }
+
+    // log(sum(exp(x))) of every row, shifted by the row maximum so large
+    // inputs do not overflow. Empty rows give negative infinity and rows
+    // containing +inf give +inf.
+    pub fn logsumexp_rows(&self) -> Vec<T> {
+        return (0..self.rows)
+            .map(|i| logsumexp(&self.matrix[i * self.cols..(i + 1) * self.cols]))
+            .collect();
+    }
+
+    // exp(x - logsumexp(row)) for every entry, so each row sums to one.
+    // Softmax is undefined for a row that is entirely -inf, and such rows
+    // come out as NaN, matching NumPy and SciPy. Entries of +inf also give
+    // NaN, while the rest of their row is zero.
+    pub fn softmax_rows(&self) -> Matrix<T> {
+        let mut result = Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            matrix: self.matrix.clone(),
+        };
+
+        for row in result.matrix.chunks_mut(self.cols.max(1)) {
+            let lse = logsumexp(row);
+            row.iter_mut().for_each(|x| *x = (*x - lse).exp());
+        }
+
+        return result;
+    }
+
+    // Covariance of the variables, where `axis` picks whether each row or
+    // each column is one observation. Uses Welford's single-pass update of
+    // the means and co-moments, which avoids the cancellation of the naive
+    // sum-of-products formula. Returns None with no more than `ddof`
+    // observations.
+    pub fn covariance(&self, axis: Axis, ddof: usize) -> Option<Matrix<T>> {
+        let (count, p) = self.lane_shape(axis);
+        if count <= ddof {
+            return None;
+        }
+
+        let mut mean = vec![T::zero(); p];
+        let mut delta = vec![T::zero(); p];
+        let mut comoment = vec![T::zero(); p * p];
+
+        for k in 0..count {
+            let n = T::from(k + 1).unwrap();
+            for ((d, m), x) in delta
+                .iter_mut()
+                .zip(mean.iter_mut())
+                .zip(self.lane(axis, k))
+            {
+                *d = *x - *m;
+                *m = *m + *d / n;
+            }
+            for (i, x) in self.lane(axis, k).enumerate() {
+                for j in 0..p {
+                    comoment[i * p + j] = comoment[i * p + j] + delta[j] * (*x - mean[i]);
+                }
+            }
+        }
+
+        let scale = T::from(count - ddof).unwrap();
+
+        return Some(Matrix {
+            rows: p,
+            cols: p,
+            matrix: comoment.into_iter().map(|c| c / scale).collect(),
+        });
+    }
+
+    // Pearson correlation coefficients. Variables with zero variance give
+    // NaN in their row and column.
+    pub fn correlation(&self, axis: Axis) -> Option<Matrix<T>> {
+        let mut result = self.covariance(axis, 1)?;
+        let p = result.rows;
+        let std: Vec<T> = (0..p).map(|i| result.matrix[i * p + i].sqrt()).collect();
+
+        for (i, si) in std.iter().enumerate() {
+            for (j, sj) in std.iter().enumerate() {
+                result.matrix[i * p + j] = result.matrix[i * p + j] / (*si * *sj);
+            }
+        }
+
+        return Some(result);
+    }
+}
+
+fn welford_variance<'a, T, I>(values: I, ddof: usize) -> T
+where
+    T: Float + 'a,
+    I: Iterator<Item = &'a T>,
+{
+    let (count, _, m2) = values.fold((0, T::zero(), T::zero()), |(k, mean, m2), x| {
+        let delta = *x - mean;
+        let mean = mean + delta / T::from(k + 1).unwrap();
+        (k + 1, mean, m2 + delta * (*x - mean))
+    });
+
+    return match count > ddof {
+        true => m2 / T::from(count - ddof).unwrap(),
+        false => T::nan(),
+    };
+}
+
+fn logsumexp<T: Float>(values: &[T]) -> T {
+    let max = values.iter().fold(T::neg_infinity(), |m, x| m.max(*x));
+    if max.is_infinite() {
+        return max;
+    }
+
+    let sum = values
+        .iter()
+        .fold(T::zero(), |acc, x| acc + (*x - max).exp());
+
+    return max + sum.ln();
+}
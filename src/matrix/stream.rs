@@ -0,0 +1,129 @@
+use super::bytes::BinaryElement;
+use super::Matrix;
+use std::io;
+use std::io::{ErrorKind, Read, Write};
+use std::marker::PhantomData;
+
+// Row-at-a-time binary I/O. Rows are raw little-endian entries, the same
+// bytes as the data section of `to_bytes` without its header, so neither
+// side ever holds more than one row of bytes besides the matrix itself.
+
+// Decodes rows of `cols` entries from a reader until it is exhausted.
+// Ending partway through a row is an `UnexpectedEof` error.
+#[derive(Debug)]
+pub struct RowReader<R, T> {
+    reader: R,
+    cols: usize,
+    buffer: Vec<u8>,
+    element: PhantomData<T>,
+}
+
+impl<R, T> RowReader<R, T>
+where
+    R: Read,
+    T: BinaryElement,
+{
+    pub fn new(reader: R, cols: usize) -> Self {
+        return RowReader {
+            reader,
+            cols,
+            buffer: vec![0; cols * T::SIZE],
+            element: PhantomData,
+        };
+    }
+
+    // Fills the buffer with the next row. Ok(false) means the reader ended
+    // cleanly on a row boundary.
+    fn fill(&mut self) -> io::Result<bool> {
+        let mut filled = 0;
+
+        while filled < self.buffer.len() {
+            match self.reader.read(&mut self.buffer[filled..]) {
+                Ok(0) if filled == 0 => return Ok(false),
+                Ok(0) => {
+                    return Err(io::Error::new(
+                        ErrorKind::UnexpectedEof,
+                        "Input ends inside a row",
+                    ))
+                }
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        return Ok(true);
+    }
+}
+
+impl<R, T> Iterator for RowReader<R, T>
+where
+    R: Read,
+    T: BinaryElement,
+{
+    type Item = io::Result<Vec<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.cols == 0 {
+            return None;
+        }
+
+        return match self.fill() {
+            Ok(true) => Some(Ok(self
+                .buffer
+                .chunks_exact(T::SIZE)
+                .map(T::from_le_slice)
+                .collect())),
+            Ok(false) => None,
+            Err(e) => Some(Err(e)),
+        };
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + BinaryElement,
+{
+    pub fn write_rows_to<W>(&self, mut writer: W) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let mut row = Vec::with_capacity(self.cols * T::SIZE);
+
+        for i in 0..self.rows {
+            row.clear();
+            self.matrix[i * self.cols..(i + 1) * self.cols]
+                .iter()
+                .for_each(|x| x.extend_le_bytes(&mut row));
+            writer.write_all(&row)?;
+        }
+
+        return writer.flush();
+    }
+
+    // Reads rows of `cols` entries until the reader is exhausted, decoding
+    // straight into the matrix's own buffer.
+    pub fn read_rows_from<R>(reader: R, cols: usize) -> io::Result<Matrix<T>>
+    where
+        R: Read,
+    {
+        if cols == 0 {
+            return Err(io::Error::new(
+                ErrorKind::InvalidInput,
+                "Rows must have at least one column",
+            ));
+        }
+
+        let mut rows = RowReader::<R, T>::new(reader, cols);
+        let mut matrix = Vec::new();
+        while rows.fill()? {
+            matrix.extend(rows.buffer.chunks_exact(T::SIZE).map(T::from_le_slice));
+        }
+
+        return Ok(Matrix {
+            rows: matrix.len() / cols,
+            cols,
+            matrix,
+        });
+    }
+}
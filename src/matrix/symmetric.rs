@@ -0,0 +1,220 @@
+use super::triangular::{Triangle, TriangularMatrix};
+use super::Matrix;
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::{Float, Num};
+
+// Stores the lower triangle row by row, so entry (i, j) with i >= j lives
+// at i * (i + 1) / 2 + j and (j, i) shares it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymmetricMatrix<T> {
+    size: usize,
+    data: Vec<T>,
+}
+
+fn packed_index(row: usize, col: usize) -> usize {
+    let (i, j) = match row >= col {
+        true => (row, col),
+        false => (col, row),
+    };
+
+    return i * (i + 1) / 2 + j;
+}
+
+impl<T> SymmetricMatrix<T>
+where
+    T: Default + Copy,
+{
+    pub fn new(size: usize) -> Self {
+        return SymmetricMatrix {
+            size,
+            data: vec![T::default(); size * (size + 1) / 2],
+        };
+    }
+
+    // Calls `f(i, j)` once for every i >= j, in packed order.
+    pub fn from_fn<F>(size: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let data = (0..size)
+            .flat_map(|i| (0..=i).map(move |j| (i, j)))
+            .map(|(i, j)| f(i, j))
+            .collect();
+
+        return SymmetricMatrix { size, data };
+    }
+
+    // Takes the lower triangle of `m` without checking the upper one.
+    pub fn from_lower(m: &Matrix<T>) -> Option<Self> {
+        if m.rows != m.cols {
+            return None;
+        }
+
+        let n = m.rows;
+        let data = (0..n)
+            .flat_map(|i| (0..=i).map(move |j| m.matrix[i * n + j]))
+            .collect();
+
+        return Some(SymmetricMatrix { size: n, data });
+    }
+
+    pub fn size(&self) -> usize {
+        return self.size;
+    }
+
+    pub fn packed(&self) -> &[T] {
+        return &self.data;
+    }
+
+    pub fn at(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= self.size || col >= self.size {
+            return None;
+        }
+
+        return self.data.get(packed_index(row, col));
+    }
+
+    // Sets both (row, col) and (col, row).
+    pub fn set(&mut self, row: usize, col: usize, value: T) -> Result<&mut Self, &str> {
+        if row >= self.size || col >= self.size {
+            return Err("Index out of bounds");
+        }
+
+        self.data[packed_index(row, col)] = value;
+        return Ok(self);
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        let n = self.size;
+        let matrix = (0..n * n)
+            .map(|k| self.data[packed_index(k / n, k % n)])
+            .collect();
+
+        return Matrix {
+            rows: n,
+            cols: n,
+            matrix,
+        };
+    }
+}
+
+impl<T> SymmetricMatrix<T>
+where
+    T: Default + Copy + PartialEq,
+{
+    // Returns None unless `m` is square and equal to its transpose.
+    pub fn from_dense(m: &Matrix<T>) -> Option<Self> {
+        if m.rows != m.cols {
+            return None;
+        }
+
+        let n = m.rows;
+        let symmetric = (0..n).all(|i| (0..i).all(|j| m.matrix[i * n + j] == m.matrix[j * n + i]));
+
+        return match symmetric {
+            true => SymmetricMatrix::from_lower(m),
+            false => None,
+        };
+    }
+}
+
+impl<T> SymmetricMatrix<T>
+where
+    T: Default + Copy + Num,
+{
+    // Walks the packed triangle once, applying each off-diagonal entry to
+    // both of its positions.
+    pub fn multiply_vector(&self, v: &[T]) -> Option<Vec<T>> {
+        if v.len() != self.size {
+            return None;
+        }
+
+        let mut result = vec![T::zero(); self.size];
+        let mut k = 0;
+
+        for i in 0..self.size {
+            for j in 0..i {
+                let a = self.data[k];
+                result[i] = result[i] + a * v[j];
+                result[j] = result[j] + a * v[i];
+                k += 1;
+            }
+            result[i] = result[i] + self.data[k] * v[i];
+            k += 1;
+        }
+
+        return Some(result);
+    }
+
+    pub fn multiply(&self, m: &Matrix<T>) -> Option<Matrix<T>> {
+        if m.rows != self.size {
+            return None;
+        }
+
+        let (n, cols) = (self.size, m.cols);
+        let mut result = vec![T::zero(); n * cols];
+        let mut k = 0;
+
+        for i in 0..n {
+            for j in 0..=i {
+                let a = self.data[k];
+                for c in 0..cols {
+                    result[i * cols + c] = result[i * cols + c] + a * m.matrix[j * cols + c];
+                    if i != j {
+                        result[j * cols + c] = result[j * cols + c] + a * m.matrix[i * cols + c];
+                    }
+                }
+                k += 1;
+            }
+        }
+
+        return Some(Matrix {
+            rows: n,
+            cols,
+            matrix: result,
+        });
+    }
+}
+
+impl<T> SymmetricMatrix<T>
+where
+    T: Default + Float,
+{
+    // Lower-triangular L with A = L Lᵀ, or None if A is not positive
+    // definite. L is packed the same way as `data`.
+    pub fn cholesky(&self) -> Option<TriangularMatrix<T>> {
+        let n = self.size;
+        let mut l = vec![T::zero(); self.data.len()];
+
+        for i in 0..n {
+            for j in 0..=i {
+                let dot = (0..j).fold(T::zero(), |acc, k| {
+                    acc + l[packed_index(i, k)] * l[packed_index(j, k)]
+                });
+                let a = self.data[packed_index(i, j)];
+
+                if i == j {
+                    let d = a - dot;
+                    if d <= T::zero() || d.is_nan() {
+                        return None;
+                    }
+                    l[packed_index(i, i)] = d.sqrt();
+                } else {
+                    l[packed_index(i, j)] = (a - dot) / l[packed_index(j, j)];
+                }
+            }
+        }
+
+        return Some(TriangularMatrix::from_packed(n, Triangle::Lower, l));
+    }
+}
+
+impl<T> From<SymmetricMatrix<T>> for Matrix<T>
+where
+    T: Default + Copy,
+{
+    fn from(s: SymmetricMatrix<T>) -> Self {
+        return s.to_dense();
+    }
+}
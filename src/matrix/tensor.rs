@@ -0,0 +1,423 @@
+use super::linalg::product;
+use super::Matrix;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Range;
+use num_traits::Num;
+
+// An n-dimensional array. Entry (i0, i1, ...) lives at the dot product of
+// the index with `strides`, so `permute_axes` only reorders the shape and
+// strides. Operations that need row-major order (`reshape`, `into_matrix`)
+// copy the data into it first when the strides are not already row-major.
+#[derive(Debug, Clone)]
+pub struct Tensor<T> {
+    shape: Vec<usize>,
+    strides: Vec<usize>,
+    data: Vec<T>,
+}
+
+fn row_major_strides(shape: &[usize]) -> Vec<usize> {
+    let mut strides = vec![1; shape.len()];
+    for k in (0..shape.len().saturating_sub(1)).rev() {
+        strides[k] = strides[k + 1] * shape[k + 1];
+    }
+
+    return strides;
+}
+
+impl<T> Tensor<T>
+where
+    T: Default + Copy,
+{
+    pub fn new(shape: &[usize]) -> Self {
+        let len = shape.iter().product();
+
+        return Tensor {
+            shape: shape.to_vec(),
+            strides: row_major_strides(shape),
+            data: vec![T::default(); len],
+        };
+    }
+
+    // Takes ownership of row-major data.
+    pub fn from_vec(shape: &[usize], data: Vec<T>) -> Result<Self, &'static str> {
+        if data.len() != shape.iter().product::<usize>() {
+            return Err("Data length does not match dimensions");
+        }
+
+        return Ok(Tensor {
+            shape: shape.to_vec(),
+            strides: row_major_strides(shape),
+            data,
+        });
+    }
+
+    pub fn shape(&self) -> &[usize] {
+        return &self.shape;
+    }
+
+    pub fn strides(&self) -> &[usize] {
+        return &self.strides;
+    }
+
+    pub fn ndim(&self) -> usize {
+        return self.shape.len();
+    }
+
+    pub fn len(&self) -> usize {
+        return self.data.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.data.is_empty();
+    }
+
+    pub fn at(&self, index: &[usize]) -> Option<&T> {
+        return self.offset(index).map(|k| &self.data[k]);
+    }
+
+    pub fn set(&mut self, index: &[usize], value: T) -> Result<&mut Self, &str> {
+        return match self.offset(index) {
+            Some(k) => {
+                self.data[k] = value;
+                Ok(self)
+            }
+            None => Err("Index out of bounds"),
+        };
+    }
+
+    // Every entry in row-major order of the current shape.
+    pub fn to_vec(&self) -> Vec<T> {
+        if self.strides == row_major_strides(&self.shape) {
+            return self.data.clone();
+        }
+
+        let mut index = vec![0; self.ndim()];
+        let mut result = Vec::with_capacity(self.len());
+        for _ in 0..self.len() {
+            let k: usize = index.iter().zip(&self.strides).map(|(i, s)| i * s).sum();
+            result.push(self.data[k]);
+
+            for axis in (0..self.ndim()).rev() {
+                index[axis] += 1;
+                if index[axis] < self.shape[axis] {
+                    break;
+                }
+                index[axis] = 0;
+            }
+        }
+
+        return result;
+    }
+
+    // Same entries in row-major order under a new shape. Returns None when
+    // the element count differs.
+    pub fn reshape(self, shape: &[usize]) -> Option<Self> {
+        if shape.iter().product::<usize>() != self.len() {
+            return None;
+        }
+
+        return Tensor::from_vec(shape, self.to_vec()).ok();
+    }
+
+    // Axis k of the result is axis `axes[k]` of self, so `[1, 0]`
+    // transposes a 2-D tensor. No data is moved. Returns None unless `axes`
+    // lists every axis exactly once.
+    pub fn permute_axes(self, axes: &[usize]) -> Option<Self> {
+        let mut seen = vec![false; self.ndim()];
+        for axis in axes {
+            match seen.get_mut(*axis) {
+                Some(s) if !*s => *s = true,
+                _ => return None,
+            }
+        }
+        if axes.len() != self.ndim() {
+            return None;
+        }
+
+        return Some(Tensor {
+            shape: axes.iter().map(|a| self.shape[*a]).collect(),
+            strides: axes.iter().map(|a| self.strides[*a]).collect(),
+            data: self.data,
+        });
+    }
+
+    // Copies the entries whose index along `axis` lies in `range`. Returns
+    // None if the axis or range is out of bounds.
+    pub fn slice(&self, axis: usize, range: Range<usize>) -> Option<Self> {
+        if axis >= self.ndim() || range.start > range.end || range.end > self.shape[axis] {
+            return None;
+        }
+
+        let (outer, inner): (usize, usize) = (
+            self.shape[..axis].iter().product(),
+            self.shape[axis + 1..].iter().product(),
+        );
+        let data = self.to_vec();
+        let width = range.len() * inner;
+        let mut sliced = Vec::with_capacity(outer * width);
+        for o in 0..outer {
+            let start = (o * self.shape[axis] + range.start) * inner;
+            sliced.extend_from_slice(&data[start..start + width]);
+        }
+
+        let mut shape = self.shape.clone();
+        shape[axis] = range.len();
+
+        return Tensor::from_vec(&shape, sliced).ok();
+    }
+
+    // The sub-tensor at position `i` along `axis`, with that axis removed,
+    // e.g. one image out of a batch.
+    pub fn index_axis(&self, axis: usize, i: usize) -> Option<Self> {
+        let mut shape = self.shape.clone();
+        let sliced = self.slice(axis, i..i.checked_add(1)?)?;
+        shape.remove(axis);
+
+        return sliced.reshape(&shape);
+    }
+
+    // Returns None unless the tensor has exactly two axes.
+    pub fn into_matrix(self) -> Option<Matrix<T>> {
+        if self.ndim() != 2 {
+            return None;
+        }
+
+        return Some(Matrix {
+            rows: self.shape[0],
+            cols: self.shape[1],
+            matrix: self.to_vec(),
+        });
+    }
+
+    fn offset(&self, index: &[usize]) -> Option<usize> {
+        if index.len() != self.ndim() || index.iter().zip(&self.shape).any(|(i, n)| i >= n) {
+            return None;
+        }
+
+        return Some(index.iter().zip(&self.strides).map(|(i, s)| i * s).sum());
+    }
+}
+
+impl<T> Tensor<T>
+where
+    T: Default + Copy + Num,
+{
+    // Sums over each pair of axes in `axes`, (axis of self, axis of
+    // other), like numpy's tensordot. The result has the free axes of self
+    // followed by the free axes of other, so contracting the last axis of
+    // one 2-D tensor with the first of another is a matrix product.
+    // Returns None if an axis repeats or paired axes differ in length.
+    pub fn tensordot(&self, other: &Tensor<T>, axes: &[(usize, usize)]) -> Option<Tensor<T>> {
+        let (mine, theirs): (Vec<usize>, Vec<usize>) = axes.iter().copied().unzip();
+        let distinct = |axes: &[usize], ndim: usize| {
+            axes.iter()
+                .enumerate()
+                .all(|(k, a)| *a < ndim && !axes[..k].contains(a))
+        };
+        if !distinct(&mine, self.ndim())
+            || !distinct(&theirs, other.ndim())
+            || axes.iter().any(|(a, b)| self.shape[*a] != other.shape[*b])
+        {
+            return None;
+        }
+
+        let free_a: Vec<usize> = (0..self.ndim()).filter(|a| !mine.contains(a)).collect();
+        let free_b: Vec<usize> = (0..other.ndim()).filter(|b| !theirs.contains(b)).collect();
+        let order_a: Vec<usize> = free_a.iter().chain(&mine).copied().collect();
+        let order_b: Vec<usize> = theirs.iter().chain(&free_b).copied().collect();
+
+        let rows = free_a.iter().map(|a| self.shape[*a]).product();
+        let inner = mine.iter().map(|a| self.shape[*a]).product();
+        let cols = free_b.iter().map(|b| other.shape[*b]).product();
+
+        let a = self
+            .clone()
+            .permute_axes(&order_a)?
+            .reshape(&[rows, inner])?;
+        let b = other
+            .clone()
+            .permute_axes(&order_b)?
+            .reshape(&[inner, cols])?;
+        let result = product(&a.into_matrix()?, &b.into_matrix()?);
+
+        let shape: Vec<usize> = free_a
+            .iter()
+            .map(|a| self.shape[*a])
+            .chain(free_b.iter().map(|b| other.shape[*b]))
+            .collect();
+
+        return Tensor::from_vec(&shape, result.matrix).ok();
+    }
+}
+
+// Tensors are equal when they have the same shape and the same entries,
+// however those entries are laid out.
+impl<T> PartialEq for Tensor<T>
+where
+    T: Default + Copy + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        return self.shape == other.shape && self.to_vec() == other.to_vec();
+    }
+}
+
+impl<T> From<Matrix<T>> for Tensor<T>
+where
+    T: Default + Copy,
+{
+    fn from(m: Matrix<T>) -> Self {
+        return Tensor::from_vec(&[m.rows, m.cols], m.matrix).unwrap();
+    }
+}
+
+// Evaluates an Einstein summation such as "ij,jk->ik" (matrix product),
+// "ij->ji" (transpose), "ii->" (trace), "i,j->ij" (outer product) or
+// "bij,bjk->bik" (batched product). Each operand's axes are named by
+// letters; labels missing from the output are summed over. Without "->"
+// the output is every label used exactly once, in alphabetical order.
+//
+// Plain contractions of two operands go through `tensordot` and its matrix
+// product; anything with repeated or batch labels falls back to a direct
+// loop over every label combination.
+pub fn einsum<T>(spec: &str, operands: &[&Tensor<T>]) -> Result<Tensor<T>, &'static str>
+where
+    T: Default + Copy + Num,
+{
+    let spec: Vec<char> = spec.chars().filter(|c| !c.is_whitespace()).collect();
+    let arrow = spec.windows(2).position(|w| w == ['-', '>']);
+    let lhs = &spec[..arrow.unwrap_or(spec.len())];
+    let inputs: Vec<&[char]> = lhs.split(|c| *c == ',').collect();
+
+    if inputs.len() != operands.len() {
+        return Err("Number of operands does not match the specification");
+    }
+    if lhs.iter().any(|c| *c != ',' && !c.is_ascii_alphabetic()) {
+        return Err("Labels must be letters");
+    }
+
+    let output: Vec<char> = match arrow {
+        Some(k) => spec[k + 2..].to_vec(),
+        None => {
+            let mut once: Vec<char> = lhs
+                .iter()
+                .copied()
+                .filter(|c| *c != ',' && lhs.iter().filter(|d| *d == c).count() == 1)
+                .collect();
+            once.sort_unstable();
+            once
+        }
+    };
+
+    // Every label with its length: output labels first, then summed ones.
+    let mut labels: Vec<(char, usize)> = Vec::new();
+    for (input, operand) in inputs.iter().zip(operands) {
+        if input.len() != operand.ndim() {
+            return Err("Number of labels does not match the operand's axes");
+        }
+        for (c, n) in input.iter().zip(&operand.shape) {
+            match labels.iter().find(|(d, _)| d == c) {
+                Some((_, m)) if m != n => return Err("Dimension mismatch"),
+                Some(_) => {}
+                None => labels.push((*c, *n)),
+            }
+        }
+    }
+    for (k, c) in output.iter().enumerate() {
+        if !labels.iter().any(|(d, _)| d == c) || output[..k].contains(c) {
+            return Err("Output labels must be distinct and appear in an input");
+        }
+    }
+    labels.sort_by_key(|(c, _)| output.iter().position(|d| d == c).unwrap_or(output.len()));
+
+    if let Some(result) = contract_pair(&inputs, operands, &output) {
+        return Ok(result);
+    }
+
+    // Stride of each operand along each label; a label repeated within one
+    // operand (a diagonal) adds up the strides of its axes.
+    let strides: Vec<Vec<usize>> = inputs
+        .iter()
+        .zip(operands)
+        .map(|(input, operand)| {
+            labels
+                .iter()
+                .map(|(c, _)| {
+                    let axes = input.iter().zip(&operand.strides);
+                    axes.filter(|(d, _)| *d == c).map(|(_, s)| s).sum()
+                })
+                .collect()
+        })
+        .collect();
+
+    let shape: Vec<usize> = labels[..output.len()].iter().map(|(_, n)| *n).collect();
+    let summed: usize = labels[output.len()..].iter().map(|(_, n)| n).product();
+    let total = shape.iter().product::<usize>() * summed;
+    let mut result = vec![T::zero(); shape.iter().product()];
+    let mut index = vec![0; labels.len()];
+
+    for step in 0..total {
+        let term = operands
+            .iter()
+            .zip(&strides)
+            .fold(T::one(), |acc, (operand, s)| {
+                let k: usize = index.iter().zip(s).map(|(i, s)| i * s).sum();
+                acc * operand.data[k]
+            });
+        result[step / summed] = result[step / summed] + term;
+
+        for axis in (0..labels.len()).rev() {
+            index[axis] += 1;
+            if index[axis] < labels[axis].1 {
+                break;
+            }
+            index[axis] = 0;
+        }
+    }
+
+    return Tensor::from_vec(&shape, result);
+}
+
+// Two operands with distinct labels and no batch label: a tensordot over
+// the shared labels, permuted into the output order.
+fn contract_pair<T>(
+    inputs: &[&[char]],
+    operands: &[&Tensor<T>],
+    output: &[char],
+) -> Option<Tensor<T>>
+where
+    T: Default + Copy + Num,
+{
+    let ([a, b], [x, y]) = (inputs, operands) else {
+        return None;
+    };
+    let distinct = |labels: &[char]| (0..labels.len()).all(|k| !labels[..k].contains(&labels[k]));
+    let shared: Vec<char> = a.iter().copied().filter(|c| b.contains(c)).collect();
+
+    if !distinct(a) || !distinct(b) || shared.iter().any(|c| output.contains(c)) {
+        return None;
+    }
+    let free: Vec<char> = a
+        .iter()
+        .chain(b.iter())
+        .copied()
+        .filter(|c| !shared.contains(c))
+        .collect();
+    if free.len() != output.len() {
+        return None;
+    }
+
+    let axes: Vec<(usize, usize)> = shared
+        .iter()
+        .map(|c| {
+            let i = a.iter().position(|d| d == c).unwrap();
+            (i, b.iter().position(|d| d == c).unwrap())
+        })
+        .collect();
+    let order: Vec<usize> = output
+        .iter()
+        .map(|c| free.iter().position(|d| d == c).unwrap())
+        .collect();
+
+    return x.tensordot(y, &axes)?.permute_axes(&order);
+}
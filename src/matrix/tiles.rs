@@ -0,0 +1,137 @@
+use super::Matrix;
+use alloc::vec::Vec;
+
+// Disjoint mutable views for handing separate parts of one matrix to
+// different threads. They borrow the matrix mutably, so the borrow checker
+// guarantees no two views overlap, and they are `Send` whenever `T` is.
+
+// A run of whole rows, starting at row `first_row` of the matrix.
+#[derive(Debug)]
+pub struct RowChunkMut<'a, T> {
+    first_row: usize,
+    cols: usize,
+    data: &'a mut [T],
+}
+
+// A rectangular tile with its top-left corner at `origin` in the matrix,
+// held as one mutable slice per tile row.
+#[derive(Debug)]
+pub struct TileMut<'a, T> {
+    origin: (usize, usize),
+    cols: usize,
+    rows: Vec<&'a mut [T]>,
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy,
+{
+    // Chunks of `rows_per_chunk` rows (the last may be shorter). A chunk
+    // size of zero is treated as one.
+    pub fn row_chunks_mut(&mut self, rows_per_chunk: usize) -> Vec<RowChunkMut<'_, T>> {
+        let cols = self.cols;
+        let step = rows_per_chunk.max(1);
+
+        return self
+            .matrix
+            .chunks_mut((step * cols).max(1))
+            .enumerate()
+            .map(|(k, data)| RowChunkMut {
+                first_row: k * step,
+                cols,
+                data,
+            })
+            .collect();
+    }
+
+    // Tiles of `tile_rows` x `tile_cols` in row-major tile order, with
+    // smaller tiles along the bottom and right edges. Zero sizes are
+    // treated as one.
+    pub fn split_into_tiles_mut(
+        &mut self,
+        tile_rows: usize,
+        tile_cols: usize,
+    ) -> Vec<TileMut<'_, T>> {
+        let (cols, tile_rows, tile_cols) = (self.cols, tile_rows.max(1), tile_cols.max(1));
+        let across = cols.div_ceil(tile_cols);
+        let mut tiles = Vec::new();
+
+        for (band, rows) in self
+            .matrix
+            .chunks_mut((tile_rows * cols).max(1))
+            .enumerate()
+        {
+            let start = tiles.len();
+            for t in 0..across {
+                tiles.push(TileMut {
+                    origin: (band * tile_rows, t * tile_cols),
+                    cols: tile_cols.min(cols - t * tile_cols),
+                    rows: Vec::new(),
+                });
+            }
+
+            for row in rows.chunks_mut(cols) {
+                for (t, segment) in row.chunks_mut(tile_cols).enumerate() {
+                    tiles[start + t].rows.push(segment);
+                }
+            }
+        }
+
+        return tiles;
+    }
+}
+
+impl<T> RowChunkMut<'_, T> {
+    pub fn first_row(&self) -> usize {
+        return self.first_row;
+    }
+
+    pub fn num_rows(&self) -> usize {
+        return match self.cols {
+            0 => 0,
+            cols => self.data.len() / cols,
+        };
+    }
+
+    // Rows of the chunk, numbered from zero within it.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        return self.data.chunks_mut(self.cols.max(1));
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        return self.data;
+    }
+}
+
+impl<'a, T> TileMut<'a, T> {
+    pub fn origin(&self) -> (usize, usize) {
+        return self.origin;
+    }
+
+    pub fn shape(&self) -> (usize, usize) {
+        return (self.rows.len(), self.cols);
+    }
+
+    // Entry (i, j) relative to the tile's corner.
+    pub fn at_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        return self.rows.get_mut(i)?.get_mut(j);
+    }
+
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> + use<'_, 'a, T> {
+        return self.rows.iter_mut().map(|row| &mut **row);
+    }
+
+    // Calls `f(row, col, entry)` with the entry's position in the whole
+    // matrix.
+    pub fn for_each_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(usize, usize, &mut T),
+    {
+        let (r, c) = self.origin;
+        for (i, row) in self.rows.iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                f(r + i, c + j, x);
+            }
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use super::Matrix;
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::Num;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Triangle {
+    Upper,
+    Lower,
+}
+
+// Only the entries on and inside the triangle are stored, packed row by
+// row. Everything outside it is a structural zero and is never visited by
+// the kernels below.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriangularMatrix<T> {
+    size: usize,
+    triangle: Triangle,
+    data: Vec<T>,
+}
+
+impl<T> TriangularMatrix<T> {
+    // Takes `data` already packed row by row for `triangle`.
+    pub(crate) fn from_packed(size: usize, triangle: Triangle, data: Vec<T>) -> Self {
+        debug_assert_eq!(data.len(), size * (size + 1) / 2);
+        return TriangularMatrix {
+            size,
+            triangle,
+            data,
+        };
+    }
+
+    // Columns stored for `row`.
+    fn span(&self, row: usize) -> core::ops::Range<usize> {
+        return match self.triangle {
+            Triangle::Lower => 0..row + 1,
+            Triangle::Upper => row..self.size,
+        };
+    }
+
+    // Packed offset of the first stored entry in `row`.
+    fn row_start(&self, row: usize) -> usize {
+        return match self.triangle {
+            Triangle::Lower => row * (row + 1) / 2,
+            Triangle::Upper => row * self.size - row * row.saturating_sub(1) / 2,
+        };
+    }
+
+    fn packed_index(&self, row: usize, col: usize) -> Option<usize> {
+        if row >= self.size || !self.span(row).contains(&col) {
+            return None;
+        }
+
+        return Some(self.row_start(row) + col - self.span(row).start);
+    }
+}
+
+impl<T> TriangularMatrix<T>
+where
+    T: Default + Copy,
+{
+    // Copies one triangle of a square matrix; the rest is ignored.
+    pub fn from_dense(m: &Matrix<T>, triangle: Triangle) -> Option<Self> {
+        if m.rows != m.cols {
+            return None;
+        }
+
+        let mut result = TriangularMatrix {
+            size: m.rows,
+            triangle,
+            data: Vec::with_capacity(m.rows * (m.rows + 1) / 2),
+        };
+        for i in 0..result.size {
+            for j in result.span(i) {
+                result.data.push(m.matrix[i * m.cols + j]);
+            }
+        }
+
+        return Some(result);
+    }
+
+    pub fn size(&self) -> usize {
+        return self.size;
+    }
+
+    pub fn triangle(&self) -> Triangle {
+        return self.triangle;
+    }
+
+    // Entries outside the triangle read as None, like out of bounds ones.
+    pub fn at(&self, row: usize, col: usize) -> Option<&T> {
+        return self.data.get(self.packed_index(row, col)?);
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: T) -> Result<&mut Self, &str> {
+        return match self.packed_index(row, col) {
+            Some(index) => {
+                self.data[index] = value;
+                Ok(self)
+            }
+            None => Err("Index outside the stored triangle"),
+        };
+    }
+
+    pub fn to_dense(&self) -> Matrix<T> {
+        let n = self.size;
+        let mut result = Matrix::new_empty(n, n);
+
+        for i in 0..n {
+            let start = self.row_start(i);
+            for (k, j) in self.span(i).enumerate() {
+                result.matrix[i * n + j] = self.data[start + k];
+            }
+        }
+
+        return result;
+    }
+}
+
+impl<T> TriangularMatrix<T>
+where
+    T: Default + Copy + Num,
+{
+    pub fn multiply_vector(&self, v: &[T]) -> Option<Vec<T>> {
+        if v.len() != self.size {
+            return None;
+        }
+
+        let result = (0..self.size)
+            .map(|i| {
+                let start = self.row_start(i);
+                self.span(i)
+                    .enumerate()
+                    .fold(T::zero(), |acc, (k, j)| acc + self.data[start + k] * v[j])
+            })
+            .collect();
+
+        return Some(result);
+    }
+
+    pub fn multiply(&self, m: &Matrix<T>) -> Option<Matrix<T>> {
+        if m.rows != self.size {
+            return None;
+        }
+
+        let cols = m.cols;
+        let mut result = vec![T::zero(); self.size * cols];
+
+        for i in 0..self.size {
+            let start = self.row_start(i);
+            for (k, j) in self.span(i).enumerate() {
+                let a = self.data[start + k];
+                for c in 0..cols {
+                    result[i * cols + c] = result[i * cols + c] + a * m.matrix[j * cols + c];
+                }
+            }
+        }
+
+        return Some(Matrix {
+            rows: self.size,
+            cols,
+            matrix: result,
+        });
+    }
+
+    // Forward substitution for lower and back substitution for upper
+    // matrices. Returns None on a size mismatch or a zero on the diagonal.
+    pub fn solve(&self, b: &[T]) -> Option<Vec<T>> {
+        if b.len() != self.size {
+            return None;
+        }
+
+        let n = self.size;
+        let mut x = vec![T::zero(); n];
+        let order: Vec<usize> = match self.triangle {
+            Triangle::Lower => (0..n).collect(),
+            Triangle::Upper => (0..n).rev().collect(),
+        };
+
+        for i in order {
+            let start = self.row_start(i);
+            let mut sum = b[i];
+            let mut diagonal = T::zero();
+
+            for (k, j) in self.span(i).enumerate() {
+                match j == i {
+                    true => diagonal = self.data[start + k],
+                    false => sum = sum - self.data[start + k] * x[j],
+                }
+            }
+
+            if diagonal == T::zero() {
+                return None;
+            }
+            x[i] = sum / diagonal;
+        }
+
+        return Some(x);
+    }
+
+    // Solves for each column of `b` in turn.
+    pub fn solve_matrix(&self, b: &Matrix<T>) -> Option<Matrix<T>> {
+        if b.rows != self.size {
+            return None;
+        }
+
+        let mut result = Matrix::new_empty(b.rows, b.cols);
+        for c in 0..b.cols {
+            let column: Vec<T> = (0..b.rows).map(|i| b.matrix[i * b.cols + c]).collect();
+            for (i, x) in self.solve(&column)?.into_iter().enumerate() {
+                result.matrix[i * b.cols + c] = x;
+            }
+        }
+
+        return Some(result);
+    }
+}
+
+impl<T> From<TriangularMatrix<T>> for Matrix<T>
+where
+    T: Default + Copy,
+{
+    fn from(t: TriangularMatrix<T>) -> Self {
+        return t.to_dense();
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Default + Copy,
+{
+    // Copy with everything below the main diagonal set to the default
+    // value. Works for non-square matrices too.
+    pub fn triu(&self) -> Matrix<T> {
+        let matrix = (0..self.matrix.len())
+            .map(|k| match k / self.cols > k % self.cols {
+                true => T::default(),
+                false => self.matrix[k],
+            })
+            .collect();
+
+        return Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            matrix,
+        };
+    }
+
+    pub fn tril(&self) -> Matrix<T> {
+        let matrix = (0..self.matrix.len())
+            .map(|k| match k / self.cols < k % self.cols {
+                true => T::default(),
+                false => self.matrix[k],
+            })
+            .collect();
+
+        return Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            matrix,
+        };
+    }
+}
@@ -0,0 +1,234 @@
+use crate::matrix::Matrix;
+use pyo3::buffer::PyBuffer;
+use pyo3::exceptions::{PyBufferError, PyIndexError, PyValueError};
+use pyo3::ffi;
+use pyo3::prelude::*;
+use std::ffi::{c_char, c_int, c_void};
+use std::ptr;
+
+#[pyclass(name = "Matrix", module = "matrix")]
+pub struct PyMatrix {
+    inner: Matrix<f64>,
+    shape: [isize; 2],
+    strides: [isize; 2],
+}
+
+impl PyMatrix {
+    fn check_index(&self, row: usize, col: usize) -> PyResult<()> {
+        if row >= self.inner.num_rows() || col >= self.inner.num_cols() {
+            return Err(PyIndexError::new_err("Index out of bounds"));
+        }
+
+        return Ok(());
+    }
+}
+
+#[pymethods]
+impl PyMatrix {
+    #[new]
+    fn new(rows: usize, cols: usize) -> Self {
+        return Matrix::new_empty(rows, cols).into();
+    }
+
+    // Copies any object exposing a 2-D float64 buffer (e.g. a NumPy array)
+    // into a new matrix. Non-contiguous and Fortran-ordered inputs are
+    // gathered into row-major order on the way in. The data is always
+    // copied: the matrix owns its storage, so later writes to `obj` are not
+    // seen by the matrix, nor the other way round. Use the buffer protocol
+    // on the result (`numpy.asarray(m)`) for a zero-copy view.
+    #[staticmethod]
+    fn from_buffer(py: Python<'_>, obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let buffer = PyBuffer::<f64>::get(obj)?;
+
+        if buffer.dimensions() != 2 {
+            return Err(PyValueError::new_err("Expected a 2-dimensional buffer"));
+        }
+
+        let (rows, cols) = (buffer.shape()[0], buffer.shape()[1]);
+        let mut result = Matrix::new_empty(rows, cols);
+        buffer.copy_to_slice(py, result.as_mut_slice())?;
+
+        return Ok(result.into());
+    }
+
+    #[staticmethod]
+    fn from_list(v: Vec<Vec<f64>>) -> PyResult<Self> {
+        let cols = v.first().map_or(0, |row| row.len());
+
+        if cols == 0 || v.iter().any(|row| row.len() != cols) {
+            return Err(PyValueError::new_err(
+                "Rows must be non-empty and of equal length",
+            ));
+        }
+
+        return Ok(Matrix::from_vec(v).into());
+    }
+
+    #[getter]
+    fn shape(&self) -> (usize, usize) {
+        return (self.inner.num_rows(), self.inner.num_cols());
+    }
+
+    fn __getitem__(&self, index: (usize, usize)) -> PyResult<f64> {
+        let (row, col) = index;
+        self.check_index(row, col)?;
+
+        return Ok(self.inner.at_or_default(row, col));
+    }
+
+    fn __setitem__(&mut self, index: (usize, usize), value: f64) -> PyResult<()> {
+        let (row, col) = index;
+        self.check_index(row, col)?;
+
+        return self
+            .inner
+            .set(row, col, value)
+            .map(|_| ())
+            .map_err(|e| PyIndexError::new_err(e.to_string()));
+    }
+
+    fn add(&self, value: f64) -> Self {
+        return self.inner.add(value).into();
+    }
+
+    fn subtract(&self, value: f64) -> Self {
+        return self.inner.subtract(value).into();
+    }
+
+    fn multiply(&self, value: f64) -> Self {
+        return self.inner.multiply(value).into();
+    }
+
+    fn matrix_add(&self, m: PyRef<'_, PyMatrix>) -> PyResult<Self> {
+        return self
+            .inner
+            .matrix_add(&m.inner)
+            .map(PyMatrix::from)
+            .ok_or_else(|| PyValueError::new_err("Matrix dimensions do not match"));
+    }
+
+    fn matrix_multiply(&self, m: PyRef<'_, PyMatrix>) -> PyResult<Self> {
+        return self
+            .inner
+            .matrix_multiply(&m.inner)
+            .map(PyMatrix::from)
+            .ok_or_else(|| PyValueError::new_err("Matrix dimensions do not match"));
+    }
+
+    // Solves self @ x = b; raises ValueError if self is not square, the
+    // shapes disagree, or self is singular.
+    fn solve(&self, b: PyRef<'_, PyMatrix>) -> PyResult<Self> {
+        return self
+            .inner
+            .solve(&b.inner)
+            .map(PyMatrix::from)
+            .ok_or_else(|| PyValueError::new_err("Matrix is singular or dimensions do not match"));
+    }
+
+    fn __matmul__(&self, m: PyRef<'_, PyMatrix>) -> PyResult<Self> {
+        return self.matrix_multiply(m);
+    }
+
+    fn to_list(&self) -> Vec<Vec<f64>> {
+        return self.inner.rows().map(|row| row.to_vec()).collect();
+    }
+
+    fn __str__(&self) -> String {
+        return self.inner.to_string();
+    }
+
+    fn __repr__(&self) -> String {
+        return format!(
+            "Matrix(rows={}, cols={})",
+            self.inner.num_rows(),
+            self.inner.num_cols()
+        );
+    }
+
+    // Exposes the backing buffer without copying, so `numpy.asarray(m)`
+    // aliases the matrix. The buffer is never resized after construction,
+    // which keeps outstanding views valid for the lifetime of the object.
+    // Shape, strides and format are only filled in when the consumer asks
+    // for them; a PyBUF_SIMPLE request sees a flat 1-D byte buffer.
+    unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        if view.is_null() {
+            return Err(PyBufferError::new_err("View is null"));
+        }
+
+        let (rows, cols) = slf.borrow().shape();
+        if (flags & ffi::PyBUF_F_CONTIGUOUS) == ffi::PyBUF_F_CONTIGUOUS && rows > 1 && cols > 1 {
+            return Err(PyBufferError::new_err("Matrix is not Fortran contiguous"));
+        }
+
+        let mut matrix = slf.borrow_mut();
+        let data = matrix.inner.as_mut_slice();
+        let buf = data.as_mut_ptr() as *mut c_void;
+        let len = std::mem::size_of_val(data) as isize;
+
+        unsafe {
+            (*view).buf = buf;
+            (*view).len = len;
+            (*view).readonly = 0;
+            (*view).itemsize = std::mem::size_of::<f64>() as isize;
+            (*view).format = if (flags & ffi::PyBUF_FORMAT) == ffi::PyBUF_FORMAT {
+                c"d".as_ptr() as *mut c_char
+            } else {
+                ptr::null_mut()
+            };
+            (*view).ndim = if (flags & ffi::PyBUF_ND) == ffi::PyBUF_ND {
+                2
+            } else {
+                1
+            };
+            (*view).shape = if (flags & ffi::PyBUF_ND) == ffi::PyBUF_ND {
+                matrix.shape.as_mut_ptr()
+            } else {
+                ptr::null_mut()
+            };
+            (*view).strides = if (flags & ffi::PyBUF_STRIDES) == ffi::PyBUF_STRIDES {
+                matrix.strides.as_mut_ptr()
+            } else {
+                ptr::null_mut()
+            };
+            (*view).suboffsets = ptr::null_mut();
+            (*view).internal = ptr::null_mut();
+        }
+
+        drop(matrix);
+        unsafe {
+            (*view).obj = slf.into_any().into_ptr();
+        }
+
+        return Ok(());
+    }
+}
+
+impl From<Matrix<f64>> for PyMatrix {
+    fn from(inner: Matrix<f64>) -> Self {
+        let (rows, cols) = (inner.num_rows() as isize, inner.num_cols() as isize);
+        let itemsize = std::mem::size_of::<f64>() as isize;
+
+        return PyMatrix {
+            inner,
+            shape: [rows, cols],
+            strides: [cols * itemsize, itemsize],
+        };
+    }
+}
+
+impl From<PyMatrix> for Matrix<f64> {
+    fn from(m: PyMatrix) -> Self {
+        return m.inner;
+    }
+}
+
+#[pymodule(name = "matrix")]
+fn matrix_module(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyMatrix>()?;
+
+    return Ok(());
+}
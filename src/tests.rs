@@ -1 +1,70 @@
+#[cfg(feature = "arrow")]
+mod arrow_test;
+mod blas_test;
+mod broadcast_test;
+mod builder_test;
+mod bytes_test;
+mod conv_test;
+#[cfg(feature = "std")]
+mod csv_test;
+mod dataset_test;
+mod decomposition_test;
+mod diagonal_test;
+mod distance_test;
+mod elementwise_test;
+mod factorization_test;
+#[cfg(feature = "ffi")]
+mod ffi_test;
+mod fft_test;
+mod format_test;
+mod graph_test;
+#[cfg(feature = "hdf5")]
+mod hdf5_test;
+mod heatmap_test;
+#[cfg(feature = "image")]
+mod image_test;
+mod kernel_test;
+mod labels_test;
+mod linalg_test;
+mod macros_test;
+mod markov_test;
+mod mask_test;
+#[cfg(feature = "std")]
+mod mat_test;
 mod matrix_test;
+#[cfg(feature = "mmap")]
+mod mmap_test;
+#[cfg(feature = "std")]
+mod mtx_test;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_test;
+#[cfg(feature = "ndarray")]
+mod ndarray_test;
+mod normalize_test;
+#[cfg(feature = "std")]
+mod npy_test;
+mod pca_test;
+mod permutation_test;
+#[cfg(feature = "plot")]
+mod plot_test;
+mod predicates_test;
+mod query_test;
+#[cfg(feature = "rand")]
+mod random_test;
+mod range_test;
+mod regression_test;
+mod rowops_test;
+#[cfg(feature = "serde")]
+mod serde_test;
+mod shared_test;
+mod sparsity_test;
+mod special_test;
+mod stats_test;
+#[cfg(feature = "std")]
+mod stream_test;
+mod symmetric_test;
+mod tensor_test;
+mod tiles_test;
+mod triangular_test;
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+mod wasm_test;
@@ -0,0 +1,33 @@
+use crate::matrix::Matrix;
+
+#[test]
+fn record_batch_round_trip() {
+    let mat_a = Matrix::from_vec(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    let batch = mat_a.to_record_batch(Some(&["x", "y", "z"])).unwrap();
+
+    assert_eq!(batch.num_rows(), 2);
+    assert_eq!(batch.schema().field(1).name(), "y");
+    assert_eq!(Matrix::from_record_batch(&batch, None).unwrap(), mat_a);
+
+    let mat_b = Matrix::from_record_batch(&batch, Some(&["z", "x"])).unwrap();
+    assert_eq!(
+        mat_b,
+        Matrix::from_vec(vec![vec![3.0, 1.0], vec![6.0, 4.0]])
+    );
+    assert!(Matrix::from_record_batch(&batch, Some(&["w"])).is_err());
+}
+
+#[cfg(feature = "parquet")]
+#[test]
+fn parquet_round_trip() {
+    let mat_a = Matrix::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]);
+    let path = std::env::temp_dir().join("matrix_parquet_round_trip.parquet");
+
+    mat_a
+        .write_parquet(std::fs::File::create(&path).unwrap(), None)
+        .unwrap();
+    let mat_b = Matrix::from_parquet(std::fs::File::open(&path).unwrap(), None).unwrap();
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(mat_a, mat_b);
+}
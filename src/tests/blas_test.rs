@@ -0,0 +1,108 @@
+use crate::matrix;
+use crate::matrix::blas::{axpy, batched_matmul, gemm, gemv, ger, MatrixBatch, Transpose};
+use crate::matrix::Matrix;
+
+#[test]
+fn gemm_with_transposes() {
+    let a: Matrix<f64> = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    let b: Matrix<f64> = matrix![1.0, 0.0; 0.0, 1.0; 1.0, 1.0];
+    let expected = a.matrix_multiply(&b).unwrap();
+
+    let mut c: Matrix<f64> = Matrix::new(2, 2);
+    gemm(&mut c, 1.0, &a, Transpose::No, &b, Transpose::No, 0.0).unwrap();
+    assert_eq!(c, expected);
+
+    let mut c = matrix![f64::NAN, 1.0; 1.0, 1.0];
+    let (at, bt) = (a.transpose(), b.transpose());
+    gemm(&mut c, 2.0, &at, Transpose::Yes, &bt, Transpose::Yes, 0.0).unwrap();
+    assert_eq!(c, expected.multiply(2.0));
+
+    gemm(&mut c, 1.0, &a, Transpose::No, &bt, Transpose::Yes, -1.0).unwrap();
+    assert_eq!(c, expected.multiply(-1.0));
+}
+
+#[test]
+fn gemm_checks_shapes() {
+    let a = matrix![1, 2; 3, 4];
+    let mut c: Matrix<i32> = Matrix::new(2, 3);
+
+    assert_eq!(
+        gemm(&mut c, 1, &a, Transpose::No, &a, Transpose::No, 0),
+        None
+    );
+    assert_eq!(
+        gemm(
+            &mut c,
+            1,
+            &a,
+            Transpose::Yes,
+            &matrix![1, 1, 1; 2, 2, 2],
+            Transpose::No,
+            0
+        ),
+        Some(())
+    );
+    assert_eq!(c, matrix![7, 7, 7; 10, 10, 10]);
+}
+
+#[test]
+fn level_one_and_two() {
+    let mut y = vec![1.0, 2.0];
+    axpy(2.0, &[3.0, -1.0], &mut y).unwrap();
+    assert_eq!(y, vec![7.0, 0.0]);
+    assert_eq!(axpy(1.0, &[1.0], &mut y), None);
+
+    let a: Matrix<f64> = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    let mut y = vec![1.0, 1.0];
+    gemv(1.0, &a, Transpose::No, &[1.0, 0.0, -1.0], 10.0, &mut y).unwrap();
+    assert_eq!(y, vec![8.0, 8.0]);
+
+    let mut z = vec![f64::NAN; 3];
+    gemv(2.0, &a, Transpose::Yes, &[1.0, 1.0], 0.0, &mut z).unwrap();
+    assert_eq!(z, vec![10.0, 14.0, 18.0]);
+
+    let mut b: Matrix<f64> = Matrix::identity(2);
+    ger(3.0, &[1.0, 2.0], &[1.0, -1.0], &mut b).unwrap();
+    assert_eq!(b, matrix![4.0, -3.0; 6.0, -5.0]);
+    assert_eq!(ger(1.0, &[1.0], &[1.0, 1.0], &mut b), None);
+}
+
+#[test]
+fn batched_products() {
+    let rotations: Vec<Matrix<i32>> = vec![matrix![0, -1; 1, 0], matrix![1, 0; 0, 1]];
+    let points: Vec<Matrix<i32>> = vec![matrix![1; 2], matrix![3; 4]];
+
+    let moved = batched_matmul(&rotations, &points).unwrap();
+
+    assert_eq!(moved, vec![matrix![-2; 1], matrix![3; 4]]);
+    assert_eq!(batched_matmul(&rotations, &points[..1]), None);
+    assert_eq!(batched_matmul(&points, &rotations), None);
+}
+
+#[test]
+fn matrix_batch_products() {
+    let a: Vec<Matrix<i64>> = (0..5)
+        .map(|i| Matrix::from_vec(vec![vec![i, 1, 0], vec![2, i, -1]]))
+        .collect();
+    let b: Vec<Matrix<i64>> = (0..5)
+        .map(|i| Matrix::from_vec(vec![vec![1, i], vec![0, 1], vec![i, 2]]))
+        .collect();
+    let (batch_a, batch_b) = (
+        MatrixBatch::from_matrices(&a).unwrap(),
+        MatrixBatch::from_matrices(&b).unwrap(),
+    );
+
+    let product = batch_a.matmul(&batch_b).unwrap();
+    assert_eq!((product.len(), product.shape()), (5, (2, 2)));
+    assert_eq!(product.to_matrices(), batched_matmul(&a, &b).unwrap());
+    assert_eq!(product.get(1), Some(&[1, 2, 1, 1][..]));
+    assert_eq!(product.get(5), None);
+    assert_eq!(batch_a.matmul(&batch_a), None);
+    assert_eq!(
+        MatrixBatch::from_matrices(&[a[0].transpose(), b[0].transpose()]),
+        None
+    );
+
+    #[cfg(feature = "rayon")]
+    assert_eq!(batch_a.par_matmul(&batch_b), Some(product));
+}
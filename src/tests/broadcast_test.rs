@@ -0,0 +1,29 @@
+use crate::matrix;
+use crate::matrix::{Axis, Matrix};
+
+#[test]
+fn center_and_scale_columns() {
+    let m: Matrix<f64> = matrix![1.0, 10.0; 3.0, 30.0];
+
+    let centered = m.sub_row_vector(&m.mean_axis(Axis::Cols)).unwrap();
+    assert_eq!(centered, matrix![-1.0, -10.0; 1.0, 10.0]);
+
+    let scaled = centered.div_row_vector(&[1.0, 10.0]).unwrap();
+    assert_eq!(scaled, matrix![-1.0, -1.0; 1.0, 1.0]);
+    assert!(m.add_row_vector(&[1.0]).is_none());
+}
+
+#[test]
+fn column_vectors_and_general_op() {
+    let m = matrix![1, 2, 3; 4, 5, 6];
+
+    assert_eq!(
+        m.mul_col_vector(&[10, -1]),
+        Some(matrix![10, 20, 30; -4, -5, -6])
+    );
+    assert_eq!(m.add_col_vector(&[1, 2, 3]), None);
+    assert_eq!(
+        m.broadcast_op(Axis::Rows, &[2, 4, 6], i32::max),
+        Some(matrix![2, 4, 6; 4, 5, 6])
+    );
+}
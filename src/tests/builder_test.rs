@@ -0,0 +1,79 @@
+use crate::matrix::builder::{BuildError, MatrixBuilder};
+use crate::matrix::Matrix;
+
+#[test]
+fn builder_push_rows() {
+    let mut builder = MatrixBuilder::new();
+    builder.push_row(&[1, 2, 3]).unwrap();
+    builder.push_row(&[4, 5, 6]).unwrap();
+
+    assert_eq!(
+        builder.push_row(&[7, 8]).err(),
+        Some(BuildError::RowLength {
+            row: 2,
+            expected: 3,
+            found: 2
+        })
+    );
+    assert_eq!(builder.num_rows(), 2);
+    assert_eq!(
+        builder.build(),
+        Ok(Matrix::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]))
+    );
+}
+
+#[test]
+fn builder_with_fixed_width() {
+    let mut builder = MatrixBuilder::<f64>::with_cols(2);
+    assert!(builder.push_row(&[1.0, 2.0, 3.0]).is_err());
+
+    builder
+        .reserve_rows(2)
+        .push_row(&[1.0, 2.0])
+        .and_then(|b| b.push_row(&[3.0, 4.0]))
+        .unwrap();
+
+    let mat_a = builder.build().unwrap();
+    assert_eq!((mat_a.num_rows(), mat_a.num_cols()), (2, 2));
+
+    let empty = MatrixBuilder::<f64>::with_cols(3).build().unwrap();
+    assert_eq!((empty.num_rows(), empty.num_cols()), (0, 3));
+
+    let mut builder = MatrixBuilder::new().rows(3);
+    builder.push_row(&[1]).unwrap();
+    assert_eq!(
+        builder.build(),
+        Err(BuildError::RowCount {
+            expected: 3,
+            found: 1
+        })
+    );
+}
+
+#[test]
+fn builder_fill_and_diagonal() {
+    let mat_a = Matrix::builder()
+        .rows(3)
+        .cols(3)
+        .fill(0.0)
+        .diagonal(1.0)
+        .build()
+        .unwrap();
+    assert_eq!(
+        mat_a,
+        Matrix::from_vec(vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1.0]
+        ])
+    );
+
+    let mat_b = Matrix::builder().rows(2).cols(3).fill(7).build().unwrap();
+    assert_eq!(mat_b.as_slice(), &[7; 6]);
+
+    assert_eq!(
+        Matrix::<i32>::builder().rows(2).build(),
+        Err(BuildError::MissingShape)
+    );
+    assert_eq!(Matrix::<f64>::new(2, 3).as_slice(), &[0.0; 6]);
+}
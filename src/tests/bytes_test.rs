@@ -0,0 +1,63 @@
+use crate::matrix::bytes::{write_header, BytesError, HEADER_LEN, MAGIC, VERSION};
+use crate::matrix::Matrix;
+
+#[test]
+fn bytes_round_trip() {
+    let mat_a = Matrix::from_vec(vec![vec![1.0, -2.5], vec![3.0, 4.0], vec![5.0, 6.0]]);
+    let bytes = mat_a.to_bytes();
+
+    assert_eq!(&bytes[..4], b"MTRX");
+    assert_eq!(bytes.len(), HEADER_LEN + 6 * 8);
+    assert_eq!(Matrix::<f64>::from_bytes(&bytes).unwrap(), mat_a);
+}
+
+#[test]
+fn bytes_rejects_bad_input() {
+    let bytes = Matrix::from_vec(vec![vec![1u16, 2, 3]]).to_bytes();
+
+    assert!(matches!(
+        Matrix::<u32>::from_bytes(&bytes),
+        Err(BytesError::DtypeMismatch { .. })
+    ));
+    assert!(matches!(
+        Matrix::<u16>::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(BytesError::LengthMismatch { .. })
+    ));
+    assert_eq!(
+        Matrix::<u16>::from_bytes(&bytes[1..]),
+        Err(BytesError::BadMagic)
+    );
+}
+
+#[test]
+fn bytes_rejects_overflowing_dimensions() {
+    let mut bytes = Vec::new();
+    write_header(&mut bytes, 2, usize::MAX / 2, 4);
+
+    assert_eq!(
+        Matrix::<f64>::from_bytes(&bytes),
+        Err(BytesError::DimensionsOverflow {
+            rows: usize::MAX / 2,
+            cols: 4
+        })
+    );
+    // 2^32 rows fit in a 64-bit usize but not a 32-bit one, where the
+    // header must be rejected rather than truncated.
+    let mut bytes = MAGIC.to_vec();
+    bytes.extend_from_slice(&[VERSION, 2, 0, 0]);
+    bytes.extend_from_slice(&(1u64 << 32).to_le_bytes());
+    bytes.extend_from_slice(&0u64.to_le_bytes());
+    match usize::BITS {
+        64 => assert_eq!(
+            Matrix::<f64>::from_bytes(&bytes).unwrap().num_rows() as u64,
+            1 << 32
+        ),
+        _ => assert_eq!(
+            Matrix::<f64>::from_bytes(&bytes),
+            Err(BytesError::DimensionsTooLarge {
+                rows: 1 << 32,
+                cols: 0
+            })
+        ),
+    }
+}
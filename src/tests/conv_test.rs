@@ -0,0 +1,107 @@
+use crate::matrix;
+use crate::matrix::conv::{ConvMode, PaddingMode};
+use crate::matrix::Matrix;
+
+#[test]
+fn im2col_patches() {
+    let image = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    let patches = image.im2col(2, 2, 1, 0).unwrap();
+
+    assert_eq!(
+        patches,
+        matrix![1, 2, 4, 5; 2, 3, 5, 6; 4, 5, 7, 8; 5, 6, 8, 9]
+    );
+
+    // Convolution as GEMM: flattened kernel times the patch matrix.
+    let kernel = matrix![1, 0, 0, -1];
+    let output = kernel.matrix_multiply(&patches).unwrap();
+    assert_eq!(output, matrix![-4, -4, -4, -4]);
+
+    let padded = image.im2col(3, 3, 2, 1).unwrap();
+    assert_eq!((padded.num_rows(), padded.num_cols()), (9, 4));
+    assert_eq!(padded.get_row(0), Some(&[0, 0, 0, 5][..]));
+    assert!(image.im2col(4, 4, 1, 0).is_none());
+    assert!(image.im2col(2, 2, 0, 0).is_none());
+}
+
+#[test]
+fn col2im_sums_overlaps() {
+    let ones: Matrix<i32> = matrix![1, 1, 1, 1; 1, 1, 1, 1; 1, 1, 1, 1; 1, 1, 1, 1];
+    let counts = ones.col2im(3, 3, 2, 2, 1, 0).unwrap();
+
+    assert_eq!(counts, matrix![1, 2, 1; 2, 4, 2; 1, 2, 1]);
+    assert!(ones.col2im(4, 4, 2, 2, 1, 0).is_none());
+}
+
+#[test]
+fn convolution_modes() {
+    let image = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    let kernel = matrix![1, 0; 0, -1];
+
+    assert_eq!(
+        image.convolve2d(&kernel, ConvMode::Valid, PaddingMode::Zero),
+        Some(matrix![4, 4; 4, 4])
+    );
+    assert_eq!(
+        image.convolve2d(&kernel, ConvMode::Full, PaddingMode::Zero),
+        Some(matrix![1, 2, 3, 0; 4, 4, 4, -3; 7, 4, 4, -6; 0, -7, -8, -9])
+    );
+    assert_eq!(
+        image.convolve2d(&kernel, ConvMode::Same, PaddingMode::Zero),
+        Some(matrix![1, 2, 3; 4, 4, 4; 7, 4, 4])
+    );
+    assert_eq!(
+        image.correlate2d(&kernel, ConvMode::Valid, PaddingMode::Zero),
+        Some(matrix![-4, -4; -4, -4])
+    );
+    assert!(kernel
+        .convolve2d(&image, ConvMode::Valid, PaddingMode::Zero)
+        .is_none());
+}
+
+#[test]
+fn padding_modes() {
+    let row: Matrix<f64> = matrix![1.0, 2.0, 3.0];
+    let shift = matrix![0.0, 0.0, 1.0];
+
+    // Correlating with [0, 0, 1] reads the value one to the right.
+    let shifted = |padding| row.correlate2d(&shift, ConvMode::Same, padding).unwrap();
+    assert_eq!(shifted(PaddingMode::Zero), matrix![2.0, 3.0, 0.0]);
+    assert_eq!(shifted(PaddingMode::Replicate), matrix![2.0, 3.0, 3.0]);
+    assert_eq!(shifted(PaddingMode::Symmetric), matrix![2.0, 3.0, 3.0]);
+    assert_eq!(shifted(PaddingMode::Wrap), matrix![2.0, 3.0, 1.0]);
+
+    let box_blur = matrix![1.0, 1.0, 1.0].map(|x| x / 3.0);
+    let blurred = row
+        .convolve2d(&box_blur, ConvMode::Same, PaddingMode::Replicate)
+        .unwrap();
+    assert!((blurred.at(0, 0).unwrap() - 4.0 / 3.0).abs() < 1e-12);
+}
+
+#[test]
+fn max_and_average_pooling() {
+    let m: Matrix<f64> = matrix![
+        1.0, 2.0, 5.0, 6.0;
+        3.0, 4.0, 8.0, 7.0;
+        9.0, 0.0, 1.0, 1.0;
+        0.0, 0.0, 1.0, 3.0
+    ];
+
+    assert_eq!(
+        m.max_pool((2, 2), (2, 2)),
+        Some(matrix![4.0, 8.0; 9.0, 3.0])
+    );
+    assert_eq!(
+        m.avg_pool((2, 2), (2, 2)),
+        Some(matrix![2.5, 6.5; 2.25, 1.5])
+    );
+
+    let (pooled, indices) = m.max_pool_with_indices((3, 3), (1, 1)).unwrap();
+    assert_eq!(pooled, matrix![9.0, 8.0; 9.0, 8.0]);
+    assert_eq!(indices, matrix![(2, 0), (1, 2); (2, 0), (1, 2)]);
+
+    // A partial window at the edge is dropped.
+    assert_eq!(m.max_pool((3, 3), (3, 3)), Some(matrix![9.0]));
+    assert!(m.max_pool((5, 1), (1, 1)).is_none());
+    assert!(m.avg_pool((2, 2), (0, 1)).is_none());
+}
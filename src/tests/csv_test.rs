@@ -0,0 +1,38 @@
+use crate::matrix::csv::{CsvError, CsvHeader, CsvOptions};
+use crate::matrix::Matrix;
+
+#[test]
+fn csv_read_with_header() {
+    let input = "a;b;c\n1;2;3\n4;5;6\n";
+    let options = CsvOptions::new().delimiter(';').header(CsvHeader::Capture);
+    let (mat_a, headers) = Matrix::<f64>::from_csv_reader(input.as_bytes(), options).unwrap();
+
+    assert_eq!(headers, Some(vec!["a".into(), "b".into(), "c".into()]));
+    assert_eq!(
+        mat_a,
+        Matrix::from_vec(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]])
+    );
+}
+
+#[test]
+fn csv_reports_parse_location() {
+    let input = "1,2\n3,x\n";
+    let result = Matrix::<i32>::from_csv_reader(input.as_bytes(), CsvOptions::new());
+
+    match result {
+        Err(CsvError::Parse { line, column, .. }) => assert_eq!((line, column), (2, 2)),
+        _ => panic!("expected a parse error"),
+    }
+}
+
+#[test]
+fn csv_round_trip() {
+    let mat_a = Matrix::from_vec(vec![vec![1, 2], vec![3, 4]]);
+    let mut out = Vec::new();
+    mat_a.write_csv(&mut out).unwrap();
+
+    assert_eq!(String::from_utf8(out.clone()).unwrap(), "1,2\n3,4\n");
+
+    let (mat_b, _) = Matrix::<i32>::from_csv_reader(out.as_slice(), CsvOptions::new()).unwrap();
+    assert_eq!(mat_a, mat_b);
+}
@@ -0,0 +1,71 @@
+use crate::matrix;
+use crate::matrix::Matrix;
+
+#[test]
+fn retain_rows_drops_nan() {
+    let mut m: Matrix<f64> = matrix![1.0, 2.0; f64::NAN, 3.0; 4.0, 5.0; 6.0, f64::NAN];
+
+    m.retain_rows(|row| row.iter().all(|x| !x.is_nan()));
+
+    assert_eq!(m, matrix![1.0, 2.0; 4.0, 5.0]);
+}
+
+#[test]
+fn select_subsets() {
+    let m = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+
+    assert_eq!(
+        m.select_rows(&[2, 0, 2]),
+        Some(matrix![7, 8, 9; 1, 2, 3; 7, 8, 9])
+    );
+    assert_eq!(m.select_cols(&[2, 1]), Some(matrix![3, 2; 6, 5; 9, 8]));
+    assert_eq!(m.select_rows(&[]).map(|s| s.num_rows()), Some(0));
+    assert_eq!(m.select_cols(&[3]), None);
+}
+
+#[test]
+fn sort_rows_is_stable() {
+    let mut m = matrix![3, 0; 1, 1; 3, 2; 2, 3; 1, 4];
+    let labels = vec!['a', 'b', 'c', 'd', 'e'];
+
+    let p = m.sort_rows_by_col(0).unwrap();
+
+    assert_eq!(m, matrix![1, 1; 1, 4; 2, 3; 3, 0; 3, 2]);
+    assert_eq!(p.apply_vector(&labels), Some(vec!['b', 'e', 'd', 'a', 'c']));
+    assert_eq!(m.sort_rows_by_col(2), None);
+
+    m.sort_rows_by(|a, b| b[1].cmp(&a[1]));
+    assert_eq!(m.select_cols(&[1]), Some(matrix![4; 3; 2; 1; 0]));
+}
+
+#[test]
+fn sort_rows_places_nan_last() {
+    let mut m: Matrix<f64> = matrix![f64::NAN; 2.0; f64::NAN; 1.0];
+
+    let p = m.sort_rows_by_col(0).unwrap();
+
+    assert_eq!(p.indices(), &[3, 1, 0, 2]);
+    assert!(m.as_slice()[..2] == [1.0, 2.0] && m.as_slice()[2..].iter().all(|x| x.is_nan()));
+}
+
+#[test]
+fn unique_rows_with_mapping() {
+    let m = matrix![1, 0; 0, 1; 1, 0; 1, 1; 0, 1];
+
+    let (unique, inverse) = m.unique_rows_with_inverse();
+
+    assert_eq!(unique, matrix![1, 0; 0, 1; 1, 1]);
+    assert_eq!(inverse, vec![0, 1, 0, 2, 1]);
+    assert_eq!(unique.select_rows(&inverse), Some(m));
+}
+
+#[test]
+fn split_rows_into_fold_and_rest() {
+    let m = matrix![0; 1; 2; 3; 4];
+
+    let (fold, rest) = m.split_rows_at(&[3, 1]).unwrap();
+
+    assert_eq!(fold, matrix![3; 1]);
+    assert_eq!(rest, matrix![0; 2; 4]);
+    assert!(m.split_rows_at(&[5]).is_none());
+}
@@ -0,0 +1,260 @@
+use crate::matrix;
+use crate::matrix::decomposition::{
+    apply_givens, apply_householder, givens, householder_vector, Side,
+};
+use crate::matrix::fft::Complex;
+use crate::matrix::Matrix;
+
+fn assert_close(got: &[f64], want: &[f64]) {
+    assert_within(got, want, 1e-12);
+}
+
+fn assert_within(got: &[f64], want: &[f64], tolerance: f64) {
+    assert_eq!(got.len(), want.len());
+    for (g, w) in got.iter().zip(want) {
+        assert!((g - w).abs() < tolerance, "{:?} != {:?}", got, want);
+    }
+}
+
+#[test]
+fn householder_zeroes_below_first_entry() {
+    let mut m: Matrix<f64> = matrix![9.0, 3.0; 3.0, 4.0; 4.0, 1.0];
+    let v = householder_vector(&[3.0, 4.0]);
+
+    // Reflect rows 1 and 2 so that column 0 has a zero in row 2.
+    apply_householder(&mut m, &v, 1, Side::Left).unwrap();
+    assert_close(&m.as_slice()[..2], &[9.0, 3.0]);
+    assert_close(&[m.as_slice()[2].abs(), m.as_slice()[4]], &[5.0, 0.0]);
+
+    let mut row: Matrix<f64> = matrix![0.0, 3.0, 4.0];
+    apply_householder(&mut row, &v, 1, Side::Right).unwrap();
+    assert_close(&[row.as_slice()[1].abs(), row.as_slice()[2]], &[5.0, 0.0]);
+
+    assert!(apply_householder(&mut row, &v, 2, Side::Right).is_none());
+    assert_eq!(householder_vector(&[0.0, 0.0]), vec![0.0, 0.0]);
+}
+
+#[test]
+fn givens_rotation() {
+    let (c, s, r) = givens(3.0, 4.0);
+    assert_close(&[c, s, r], &[0.6, 0.8, 5.0]);
+    assert_eq!(givens(2.0, 0.0), (1.0, 0.0, 2.0));
+
+    let mut m: Matrix<f64> = matrix![3.0, 1.0; 4.0, 2.0];
+    apply_givens(&mut m, 0, 1, c, s, Side::Left).unwrap();
+    assert_close(m.as_slice(), &[5.0, 2.2, 0.0, 0.4]);
+
+    let mut n: Matrix<f64> = matrix![3.0, 4.0; 1.0, 2.0];
+    apply_givens(&mut n, 0, 1, c, s, Side::Right).unwrap();
+    assert_close(n.as_slice(), &[5.0, 0.0, 2.2, 0.4]);
+    assert!(apply_givens(&mut n, 0, 2, c, s, Side::Left).is_none());
+}
+
+#[test]
+fn hessenberg_similarity() {
+    let a: Matrix<f64> = matrix![
+        4.0, 1.0, 2.0, 3.0;
+        2.0, 5.0, 1.0, 0.0;
+        1.0, 2.0, 6.0, 1.0;
+        3.0, 0.0, 1.0, 7.0
+    ];
+    let (h, q) = a.hessenberg().unwrap();
+
+    for i in 2..4_usize {
+        for j in 0..i - 1 {
+            assert_eq!(*h.at(i, j).unwrap(), 0.0);
+        }
+    }
+
+    let qhqt = q
+        .matrix_multiply(&h)
+        .unwrap()
+        .matrix_multiply(&q.transpose())
+        .unwrap();
+    // Two extra products on top of the reduction, so allow more roundoff.
+    assert_within(qhqt.as_slice(), a.as_slice(), 1e-10);
+
+    let qtq = q.transpose().matrix_multiply(&q).unwrap();
+    assert_within(qtq.as_slice(), Matrix::<f64>::identity(4).as_slice(), 1e-10);
+    assert!(matrix![1.0, 2.0].hessenberg().is_none());
+}
+
+#[test]
+fn bidiagonalize_tall_and_wide() {
+    let tall: Matrix<f64> = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0; 7.0, 8.0, 10.0; 1.0, 0.0, 1.0];
+
+    for a in [tall.transpose(), tall] {
+        let (u, b, v) = a.bidiagonalize().unwrap();
+        let (m, n) = (a.num_rows(), a.num_cols());
+
+        for i in 0..m {
+            for j in 0..n {
+                if j != i && j != i + 1 {
+                    assert_eq!(*b.at(i, j).unwrap(), 0.0);
+                }
+            }
+        }
+
+        let usv = u
+            .matrix_multiply(&b)
+            .unwrap()
+            .matrix_multiply(&v.transpose())
+            .unwrap();
+        assert_close(usv.as_slice(), a.as_slice());
+
+        let utu = u.transpose().matrix_multiply(&u).unwrap();
+        assert_close(utu.as_slice(), Matrix::<f64>::identity(m).as_slice());
+        let vtv = v.transpose().matrix_multiply(&v).unwrap();
+        assert_close(vtv.as_slice(), Matrix::<f64>::identity(n).as_slice());
+    }
+}
+
+#[test]
+fn svd_reconstructs_tall_wide_and_rank_deficient() {
+    let tall: Matrix<f64> = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0; 7.0, 8.0, 10.0; 1.0, 0.0, 1.0];
+    let rank_one: Matrix<f64> = matrix![1.0, 2.0, 3.0; 2.0, 4.0, 6.0; -1.0, -2.0, -3.0];
+
+    for a in [tall.transpose(), tall, rank_one] {
+        let (u, s, v) = a.svd().unwrap();
+        let (m, n) = (a.num_rows(), a.num_cols());
+
+        assert_eq!(s.len(), m.min(n));
+        assert!(s.windows(2).all(|w| w[0] >= w[1]) && s.iter().all(|x| *x >= 0.0));
+
+        let mut sigma = Matrix::<f64>::new(m, n);
+        for (i, x) in s.iter().enumerate() {
+            sigma.set(i, i, *x).unwrap();
+        }
+        let usv = u
+            .matrix_multiply(&sigma)
+            .unwrap()
+            .matrix_multiply(&v.transpose())
+            .unwrap();
+        assert_close(usv.as_slice(), a.as_slice());
+
+        let utu = u.transpose().matrix_multiply(&u).unwrap();
+        assert_close(utu.as_slice(), Matrix::<f64>::identity(m).as_slice());
+        let vtv = v.transpose().matrix_multiply(&v).unwrap();
+        assert_close(vtv.as_slice(), Matrix::<f64>::identity(n).as_slice());
+    }
+
+    // The rank-one matrix has a single nonzero singular value, ‖x‖‖y‖.
+    let (_, s, _) = matrix![1.0, 2.0, 3.0; 2.0, 4.0, 6.0; -1.0, -2.0, -3.0]
+        .svd()
+        .unwrap();
+    assert_close(&s, &[84.0_f64.sqrt(), 0.0, 0.0]);
+    let (_, s, _) = matrix![3.0, 0.0; 0.0, -4.0].svd().unwrap();
+    assert_close(&s, &[4.0, 3.0]);
+}
+
+#[test]
+fn real_schur_form() {
+    // Eigenvalues 1, 2 and the complex pair 3 ± 4i.
+    let a: Matrix<f64> = matrix![
+        2.0, 1.0, 0.0, 3.0;
+        0.0, 3.0, -4.0, 1.0;
+        0.0, 4.0, 3.0, 2.0;
+        0.0, 0.0, 0.0, 1.0
+    ];
+    let p: Matrix<f64> = matrix![
+        1.0, 2.0, 0.0, 1.0;
+        0.0, 1.0, 1.0, 0.0;
+        1.0, 0.0, 1.0, 2.0;
+        0.0, 1.0, 0.0, 1.0
+    ];
+    let pinv = p.solve(&Matrix::identity(4)).unwrap();
+    let a = p
+        .matrix_multiply(&a)
+        .unwrap()
+        .matrix_multiply(&pinv)
+        .unwrap();
+
+    let (q, t) = a.schur().unwrap();
+    let qtqt = q
+        .matrix_multiply(&t)
+        .unwrap()
+        .matrix_multiply(&q.transpose())
+        .unwrap();
+    assert_close(qtqt.as_slice(), a.as_slice());
+    for i in 2..4_usize {
+        for j in 0..i - 1 {
+            assert_eq!(*t.at(i, j).unwrap(), 0.0);
+        }
+    }
+
+    let mut values = a.eigenvalues().unwrap();
+    values.sort_by(|x, y| (x.re, x.im).partial_cmp(&(y.re, y.im)).unwrap());
+    let expected = [(1.0, 0.0), (2.0, 0.0), (3.0, -4.0), (3.0, 4.0)];
+    for (got, (re, im)) in values.iter().zip(expected) {
+        assert!((got.re - re).abs() < 1e-9 && (got.im - im).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn schur_of_symmetric_is_diagonal() {
+    let a: Matrix<f64> = matrix![2.0, 1.0, 0.0; 1.0, 2.0, 1.0; 0.0, 1.0, 2.0];
+    let (_, t) = a.schur().unwrap();
+
+    let mut diagonal: Vec<f64> = (0..3).map(|i| *t.at(i, i).unwrap()).collect();
+    diagonal.sort_by(|x, y| x.partial_cmp(y).unwrap());
+    let r = 2.0_f64.sqrt();
+    assert_close(&diagonal, &[2.0 - r, 2.0, 2.0 + r]);
+    for i in 1..3 {
+        assert_eq!(*t.at(i, i - 1).unwrap(), 0.0);
+    }
+    assert!(matrix![1.0, 2.0].schur().is_none());
+}
+
+#[test]
+fn polar_extracts_rotation() {
+    // A rotation by 30 degrees followed by an anisotropic stretch.
+    let (c, s) = (3.0_f64.sqrt() / 2.0, 0.5);
+    let rotation: Matrix<f64> = matrix![c, -s; s, c];
+    let stretch: Matrix<f64> = matrix![2.0, 0.5; 0.5, 1.0];
+    let a = rotation.matrix_multiply(&stretch).unwrap();
+
+    let (u, p) = a.polar().unwrap();
+    assert_close(u.as_slice(), rotation.as_slice());
+    assert_close(p.as_slice(), stretch.as_slice());
+    assert_close(u.matrix_multiply(&p).unwrap().as_slice(), a.as_slice());
+
+    assert!(matrix![1.0, 2.0; 2.0, 4.0].polar().is_none());
+    assert!(matrix![1.0, 2.0].polar().is_none());
+}
+
+#[test]
+fn balancing_evens_out_norms() {
+    let a: Matrix<f64> = matrix![1.0, 1e6, 0.0; 1e-6, 2.0, 1e4; 0.0, 1e-4, 3.0];
+    let (b, d) = a.balance().unwrap();
+
+    // B = D⁻¹ A D, and every scale factor is a power of two.
+    let back = d
+        .multiply_dense(&b)
+        .unwrap()
+        .multiply_diagonal(&d.inverse().unwrap())
+        .unwrap();
+    assert_close(back.as_slice(), a.as_slice());
+    assert!(d.values().iter().all(|x| x.log2().fract() == 0.0));
+
+    let largest = b.as_slice().iter().fold(0.0_f64, |m, x| m.max(x.abs()));
+    assert!(largest < 10.0);
+    assert_eq!(
+        (0..3).map(|i| *b.at(i, i).unwrap()).collect::<Vec<_>>(),
+        vec![1.0, 2.0, 3.0]
+    );
+    assert!(matrix![1.0, 2.0].balance().is_none());
+
+    let (b, d) = matrix![1.0, f64::INFINITY; 1.0, 1.0].balance().unwrap();
+    assert_eq!(d.values(), &vec![1.0, 1.0]);
+    assert_eq!(b.as_slice(), &[1.0, f64::INFINITY, 1.0, 1.0]);
+
+    let sorted = |values: Vec<Complex<f64>>| {
+        let mut re: Vec<f64> = values.iter().map(|v| v.re).collect();
+        re.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        re
+    };
+    assert_close(
+        &sorted(a.eigenvalues_balanced().unwrap()),
+        &sorted(a.eigenvalues().unwrap()),
+    );
+}
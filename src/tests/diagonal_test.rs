@@ -0,0 +1,32 @@
+use crate::matrix;
+use crate::matrix::diagonal::DiagonalMatrix;
+
+#[test]
+fn diagonal_arithmetic() {
+    let d = DiagonalMatrix::new(vec![2.0, 4.0, 5.0]);
+    let e =
+        DiagonalMatrix::from_dense(&matrix![1.0, 9.0, 9.0; 9.0, 2.0, 9.0; 9.0, 9.0, 3.0]).unwrap();
+
+    assert_eq!(d.multiply(&e).unwrap().values(), &[2.0, 8.0, 15.0]);
+    assert_eq!(d.inverse().unwrap().values(), &[0.5, 0.25, 0.2]);
+    assert_eq!(d.pow(3).values(), &[8.0, 64.0, 125.0]);
+    assert_eq!(d.solve(&[2.0, 2.0, 10.0]), Some(vec![1.0, 0.5, 2.0]));
+    assert_eq!(
+        d.to_dense(),
+        matrix![2.0, 0.0, 0.0; 0.0, 4.0, 0.0; 0.0, 0.0, 5.0]
+    );
+
+    let singular = DiagonalMatrix::new(vec![1.0, 0.0]);
+    assert!(singular.inverse().is_none());
+    assert!(singular.solve(&[1.0, 1.0]).is_none());
+}
+
+#[test]
+fn diagonal_scales_dense() {
+    let d = DiagonalMatrix::new(vec![2, 3]);
+    let a = matrix![1, 2; 3, 4];
+
+    assert_eq!(d.multiply_dense(&a), d.to_dense().matrix_multiply(&a));
+    assert_eq!(a.multiply_diagonal(&d), a.matrix_multiply(&d.to_dense()));
+    assert!(d.multiply_dense(&matrix![1, 2, 3]).is_none());
+}
@@ -0,0 +1,45 @@
+use crate::matrix;
+use crate::matrix::distance::{cdist, pdist, Metric};
+use crate::matrix::Matrix;
+
+#[test]
+fn cdist_metrics() {
+    let a: Matrix<f64> = matrix![0.0, 0.0; 3.0, 4.0];
+    let b: Matrix<f64> = matrix![0.0, 0.0; 1.0, 1.0; 6.0, 8.0];
+
+    assert_eq!(
+        cdist(&a, &b, Metric::Euclidean),
+        Some(matrix![0.0, 2.0_f64.sqrt(), 10.0; 5.0, 13.0_f64.sqrt(), 5.0])
+    );
+    assert_eq!(
+        cdist(&a, &b, Metric::Manhattan),
+        Some(matrix![0.0, 2.0, 14.0; 7.0, 5.0, 7.0])
+    );
+    assert_eq!(
+        cdist(&a, &b, Metric::Chebyshev).unwrap().get_row(1),
+        Some(&[4.0, 3.0, 4.0][..])
+    );
+
+    let cosine = cdist(&a, &b, Metric::Cosine).unwrap();
+    assert!(cosine.at(1, 2).unwrap().abs() < 1e-12);
+    assert!(cosine.at(0, 0).unwrap().is_nan());
+    assert!(cdist(&a, &matrix![1.0], Metric::Euclidean).is_none());
+}
+
+#[test]
+fn pdist_condensed_and_blocked() {
+    let points: Matrix<f64> = matrix![0.0; 1.0; 3.0; 6.0];
+
+    assert_eq!(
+        pdist(&points, Metric::Manhattan),
+        vec![1.0, 3.0, 6.0, 2.0, 5.0, 3.0]
+    );
+    assert!(pdist(&matrix![1.0, 2.0], Metric::Euclidean).is_empty());
+
+    // Inputs larger than one block give the same result as the direct loop.
+    let many = Matrix::from_iter(150, 2, (0..300).map(|k| (k % 17) as f64)).unwrap();
+    let full = cdist(&many, &many, Metric::SquaredEuclidean).unwrap();
+    let condensed = pdist(&many, Metric::SquaredEuclidean);
+    assert_eq!(full.at(0, 149), condensed.get(148));
+    assert_eq!(full.at(149, 0), full.at(0, 149));
+}
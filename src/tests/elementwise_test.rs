@@ -0,0 +1,28 @@
+use crate::matrix;
+use crate::matrix::Matrix;
+
+#[test]
+fn float_functions() {
+    let m: Matrix<f64> = matrix![1.0, 4.0; 9.0, 16.0];
+
+    assert_eq!(m.sqrt(), matrix![1.0, 2.0; 3.0, 4.0]);
+    assert_eq!(m.powf(0.5), m.sqrt());
+    assert_eq!(m.ln().exp().map(|x| x.round()), m);
+    assert_eq!(matrix![-1.5, 2.0].abs(), matrix![1.5, 2.0]);
+    assert_eq!(
+        matrix![-1.5, 0.5, 7.0].clamp(0.0, 1.0),
+        matrix![0.0, 0.5, 1.0]
+    );
+    assert!(matrix![f64::NAN].clamp(0.0, 1.0).at(0, 0).unwrap().is_nan());
+}
+
+#[test]
+fn activations() {
+    let m: Matrix<f64> = matrix![-1000.0, 0.0, 1000.0; -2.0, 0.5, 3.0];
+
+    assert_eq!(m.sigmoid().get_row(0), Some(&[0.0, 0.5, 1.0][..]));
+    assert_eq!(m.relu(), matrix![0.0, 0.0, 1000.0; 0.0, 0.5, 3.0]);
+
+    let s = m.sigmoid();
+    assert!((s.at(1, 0).unwrap() - 1.0 / (1.0 + 2.0_f64.exp())).abs() < 1e-15);
+}
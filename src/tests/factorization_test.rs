@@ -0,0 +1,51 @@
+use crate::matrix;
+use crate::matrix::symmetric::SymmetricMatrix;
+use crate::matrix::Matrix;
+
+fn assert_close(a: &Matrix<f64>, b: &Matrix<f64>) {
+    assert_eq!((a.num_rows(), a.num_cols()), (b.num_rows(), b.num_cols()));
+    assert!(a
+        .as_slice()
+        .iter()
+        .zip(b.as_slice())
+        .all(|(x, y)| (x - y).abs() < 1e-10));
+}
+
+#[test]
+fn lu_factor_solves_many_right_hand_sides() {
+    let a: Matrix<f64> = matrix![0.0, 2.0, 1.0; 1.0, 1.0, 0.0; 3.0, 0.0, 4.0];
+    let lu = a.lu_factor().unwrap();
+
+    let b: Matrix<f64> = matrix![1.0, 0.0; 2.0, 1.0; 3.0, -1.0];
+    let x = lu.solve(&b).unwrap();
+    assert_close(&a.matrix_multiply(&x).unwrap(), &b);
+    assert_close(&x, &a.solve(&b).unwrap());
+    assert!((lu.determinant() - a.determinant().unwrap()).abs() < 1e-12);
+
+    let batch = vec![matrix![1.0; 0.0; 0.0], matrix![0.0; 0.0; 1.0]];
+    let solutions = a.solve_batch(&batch).unwrap();
+    for (x, b) in solutions.iter().zip(&batch) {
+        assert_close(&a.matrix_multiply(x).unwrap(), b);
+    }
+
+    let singular: Matrix<f64> = matrix![1.0, 2.0; 2.0, 4.0];
+    assert!(singular.lu_factor().is_none());
+    assert!(lu.solve(&matrix![1.0; 2.0]).is_none());
+}
+
+#[test]
+fn cholesky_factor_solves() {
+    let a: Matrix<f64> = matrix![4.0, 2.0, 0.0; 2.0, 5.0, 1.0; 0.0, 1.0, 3.0];
+    let chol = SymmetricMatrix::from_dense(&a)
+        .unwrap()
+        .cholesky_factor()
+        .unwrap();
+
+    let b: Matrix<f64> = matrix![1.0, 2.0; 0.0, 1.0; -1.0, 0.0];
+    let x = chol.solve(&b).unwrap();
+    assert_close(&a.matrix_multiply(&x).unwrap(), &b);
+    assert_eq!(chol.solve_batch(&[b]).map(|xs| xs.len()), Some(1));
+
+    let indefinite = SymmetricMatrix::from_dense(&matrix![1.0, 2.0; 2.0, 1.0]).unwrap();
+    assert!(indefinite.cholesky_factor().is_none());
+}
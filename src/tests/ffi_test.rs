@@ -0,0 +1,40 @@
+use crate::ffi::{
+    matrix_cols, matrix_create, matrix_free, matrix_from_data, matrix_get, matrix_solve, MATRIX_OK,
+};
+use std::ptr;
+
+#[test]
+fn ffi_solve() {
+    let a = [2.0, 1.0, 1.0, 3.0];
+    let b = [3.0, 5.0];
+    unsafe {
+        let (a, b) = (
+            matrix_from_data(2, 2, a.as_ptr()),
+            matrix_from_data(2, 1, b.as_ptr()),
+        );
+        let x = matrix_solve(a, b);
+        let mut out = 0.0;
+
+        assert_eq!(matrix_cols(x), 1);
+        assert_eq!(matrix_get(x, 0, 0, &mut out), MATRIX_OK);
+        assert!((out - 0.8).abs() < 1e-12);
+        assert_eq!(matrix_get(x, 1, 0, &mut out), MATRIX_OK);
+        assert!((out - 1.4).abs() < 1e-12);
+        assert!(matrix_solve(b, a).is_null());
+        assert!(matrix_solve(a, ptr::null()).is_null());
+
+        let singular = matrix_create(2, 2);
+        assert!(matrix_solve(singular, b).is_null());
+        for m in [a, b, x, singular] {
+            matrix_free(m);
+        }
+    }
+}
+
+#[test]
+fn ffi_rejects_overflowing_dimensions() {
+    let data = [0.0];
+
+    assert!(matrix_create(usize::MAX, 2).is_null());
+    assert!(unsafe { matrix_from_data(usize::MAX, 2, data.as_ptr()) }.is_null());
+}
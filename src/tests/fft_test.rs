@@ -0,0 +1,63 @@
+use crate::matrix;
+use crate::matrix::fft::Complex;
+use crate::matrix::Matrix;
+
+fn assert_close(got: &Matrix<Complex<f64>>, want: &[Complex<f64>]) {
+    assert_eq!(got.as_slice().len(), want.len());
+    for (g, w) in got.as_slice().iter().zip(want) {
+        assert!((g - w).norm() < 1e-9, "{} != {}", g, w);
+    }
+}
+
+#[test]
+fn fft2_of_small_real_matrix() {
+    let m: Matrix<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+    let c = |re| Complex::new(re, 0.0);
+
+    assert_close(&m.fft2_real(), &[c(10.0), c(-2.0), c(-4.0), c(0.0)]);
+
+    // A single impulse has a flat spectrum.
+    let mut impulse: Matrix<f64> = Matrix::new(3, 5);
+    impulse.set(0, 0, 1.0).unwrap();
+    assert_close(&impulse.fft2_real(), &[c(1.0); 15]);
+}
+
+#[test]
+fn fft2_round_trip_any_size() {
+    for (rows, cols) in [(4, 8), (3, 5), (6, 7), (1, 12)] {
+        let m = Matrix::from_iter(
+            rows,
+            cols,
+            (0..rows * cols).map(|k| Complex::new((k * k % 7) as f64, k as f64 * 0.5)),
+        )
+        .unwrap();
+        let back = m.fft2().ifft2();
+
+        assert_close(&back, m.as_slice());
+    }
+}
+
+#[test]
+fn fft2_matches_direct_dft() {
+    let (rows, cols) = (3, 6);
+    let m: Matrix<f64> = Matrix::from_iter(rows, cols, (0..18).map(|k| k as f64 % 5.0)).unwrap();
+    let tau = std::f64::consts::TAU;
+
+    let mut expected = Vec::new();
+    for u in 0..rows {
+        for v in 0..cols {
+            let mut sum = Complex::new(0.0, 0.0);
+            for i in 0..rows {
+                for j in 0..cols {
+                    let angle =
+                        -tau * ((u * i) as f64 / rows as f64 + (v * j) as f64 / cols as f64);
+                    sum += Complex::from_polar(*m.at(i, j).unwrap(), angle);
+                }
+            }
+            expected.push(sum);
+        }
+    }
+
+    assert_close(&m.fft2_real(), &expected);
+    assert_eq!(m.fft2_real().ifft2().re().map(|x| x.round()), m);
+}
@@ -0,0 +1,220 @@
+use crate::matrix::format::{BracketStyle, FormatOptions, LatexStyle, Notation};
+use crate::matrix::Matrix;
+use std::fmt::Alignment;
+
+#[test]
+fn display_default() {
+    let mat_a = Matrix::from_vec(vec![vec![1, 20], vec![300, 4]]);
+
+    assert_eq!(mat_a.to_string(), "\n[   1  20 ]\n[ 300   4 ]\n");
+}
+
+#[test]
+fn display_formatter_flags() {
+    let mat_a = Matrix::from_vec(vec![vec![1.0, 2.5], vec![-3.0, 0.125]]);
+
+    assert_eq!(
+        format!("{:.2}", mat_a),
+        "\n[  1.00  2.50 ]\n[ -3.00  0.12 ]\n"
+    );
+    assert_eq!(
+        format!("{:<6.1}", mat_a),
+        "\n[ 1.0    2.5    ]\n[ -3.0   0.1    ]\n"
+    );
+}
+
+#[test]
+fn format_with_options() {
+    let mat_a = Matrix::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    let options = FormatOptions::new()
+        .precision(1)
+        .separator(", ")
+        .brackets(BracketStyle::Round)
+        .align(Alignment::Left)
+        .width(4);
+
+    assert_eq!(
+        mat_a.format_with(&options),
+        "( 1.0 , 2.0  )\n( 3.0 , 4.0  )\n"
+    );
+    assert_eq!(
+        mat_a.format_with(&FormatOptions::new().brackets(BracketStyle::None)),
+        "1 2\n3 4\n"
+    );
+}
+
+#[test]
+fn format_float_notation() {
+    let mat_a = Matrix::from_vec(vec![vec![1500.0, 0.002], vec![-2.0, 0.0]]);
+
+    let sci = FormatOptions::new()
+        .notation(Notation::Scientific)
+        .precision(1)
+        .brackets(BracketStyle::None);
+    assert_eq!(
+        mat_a.format_float_with(&sci),
+        " 1.5e3 2.0e-3\n-2.0e0  0.0e0\n"
+    );
+
+    let eng = sci.clone().notation(Notation::Engineering);
+    assert_eq!(
+        mat_a.format_float_with(&eng),
+        " 1.5e3 2.0e-3\n-2.0e0    0.0\n"
+    );
+
+    let auto = sci.clone().notation(Notation::Auto);
+    assert_eq!(
+        mat_a.format_float_with(&auto),
+        mat_a.format_float_with(&sci)
+    );
+}
+
+#[test]
+fn format_float_shared_exponent() {
+    let mat_a = Matrix::from_vec(vec![vec![1200.0, 35.0], vec![-400.0, 9000.0]]);
+    let options = FormatOptions::new()
+        .notation(Notation::Scientific)
+        .shared_exponent(true)
+        .precision(2);
+
+    assert_eq!(
+        mat_a.format_float_with(&options),
+        "1e3 *\n[  1.20  0.04 ]\n[ -0.40  9.00 ]\n"
+    );
+    assert_eq!(
+        format!("{:.1e}", mat_a),
+        "\n[  1.2e3  3.5e1 ]\n[ -4.0e2  9.0e3 ]\n"
+    );
+}
+
+#[test]
+fn latex_output() {
+    let mat_a = Matrix::from_vec(vec![vec![1.0, 2.5], vec![3.0, -4.0]]);
+
+    assert_eq!(
+        mat_a.to_latex(LatexStyle::Bmatrix, Some(1)),
+        "\\begin{bmatrix}\n1.0 & 2.5 \\\\\n3.0 & -4.0\n\\end{bmatrix}"
+    );
+    assert!(mat_a
+        .to_latex(LatexStyle::Pmatrix, None)
+        .starts_with("\\begin{pmatrix}\n1 & 2.5"));
+}
+
+#[test]
+fn markdown_table() {
+    let mat_a = Matrix::from_vec(vec![vec![1, 2], vec![3, 4]]);
+
+    assert_eq!(
+        mat_a.to_markdown_table(None, None),
+        "| 0 | 1 |\n| ---: | ---: |\n| 1 | 2 |\n| 3 | 4 |\n"
+    );
+    assert_eq!(
+        mat_a.to_markdown_table(Some(&["x", "y"]), Some(&["a|b", "c"])),
+        "|  | a\\|b | c |\n| --- | ---: | ---: |\n| x | 1 | 2 |\n| y | 3 | 4 |\n"
+    );
+}
+
+#[test]
+fn html_table() {
+    let mat_a = Matrix::from_vec(vec![vec![1, 2]]);
+
+    assert_eq!(
+        mat_a.to_html_table(Some(&["<r>"]), Some(&["a", "b"])),
+        "<table>\n  <thead>\n    <tr><th></th><th>a</th><th>b</th></tr>\n  </thead>\n  \
+         <tbody>\n    <tr><th>&lt;r&gt;</th><td>1</td><td>2</td></tr>\n  </tbody>\n</table>\n"
+    );
+    assert!(!mat_a.to_html_table(None, None).contains("<thead>"));
+}
+
+#[test]
+fn format_row_numbers_and_labels() {
+    let mat_a = Matrix::from_vec((0..11).map(|i| vec![i, 100 * i]).collect());
+    let options = FormatOptions::new()
+        .row_numbers(true)
+        .col_labels(&["x", "height"]);
+    let out = mat_a.format_with(&options);
+    let lines: Vec<&str> = out.lines().collect();
+
+    assert_eq!(lines[0], "          x height");
+    assert_eq!(lines[1], " 0 [      0      0 ]");
+    assert_eq!(lines[11], "10 [     10   1000 ]");
+}
+
+#[test]
+fn display_truncates_large_matrices() {
+    let mat_a = Matrix::from_vec(
+        (0..40)
+            .map(|i| (0..40).map(|j| i * 40 + j).collect())
+            .collect(),
+    );
+    let out = mat_a.to_string();
+    let lines: Vec<&str> = out.lines().collect();
+
+    assert_eq!(lines.len(), 8);
+    assert_eq!(lines[1], "[    0    1    2    …   37   38   39 ]");
+    assert_eq!(lines[4], "[    ⋮    ⋮    ⋮    ⋱    ⋮    ⋮    ⋮ ]");
+    assert_eq!(lines[7], "[ 1560 1561 1562    … 1597 1598 1599 ]");
+}
+
+#[test]
+fn summary_shows_shape_and_corners() {
+    let mat_a = Matrix::from_vec((0..10).map(|i| vec![i; 2]).collect());
+    let options = FormatOptions::new()
+        .threshold(4)
+        .edge_items(1)
+        .row_numbers(true);
+
+    assert_eq!(mat_a.summary().lines().next(), Some("Matrix 10x2"));
+    assert_eq!(
+        mat_a.format_with(&options),
+        "0 [ 0 0 ]\n  [ ⋮ ⋮ ]\n9 [ 9 9 ]\n"
+    );
+}
+
+#[test]
+fn display_empty_and_degenerate() {
+    let empty: Matrix<f64> = Matrix::from_vec(vec![]);
+    let no_cols: Matrix<f64> = Matrix::from_vec(vec![vec![], vec![]]);
+
+    assert_eq!(empty.to_string(), "\n[] (0x0)\n");
+    assert_eq!(no_cols.to_string(), "\n[] (2x0)\n");
+    assert_eq!(
+        format!("{:e}", Matrix::<f64>::new_empty(0, 4)),
+        "\n[] (0x4)\n"
+    );
+
+    assert_eq!(no_cols.format_with(&FormatOptions::new()), "[]\n");
+    assert_eq!(no_cols.rows().count(), 0);
+    assert_eq!(no_cols.summary(), "Matrix 2x0\n[]\n");
+}
+
+#[test]
+fn tables_empty_and_degenerate() {
+    let no_rows: Matrix<i32> = Matrix::new_empty(0, 2);
+    let no_cols: Matrix<i32> = Matrix::new_empty(2, 0);
+
+    assert_eq!(
+        no_rows.to_markdown_table(None, None),
+        "| 0 | 1 |\n| ---: | ---: |\n"
+    );
+    assert_eq!(no_cols.to_markdown_table(None, None), "");
+    assert_eq!(
+        no_cols.to_latex(LatexStyle::Bmatrix, None),
+        "\\begin{bmatrix}\n\\end{bmatrix}"
+    );
+    assert_eq!(
+        no_rows.to_html_table(None, None),
+        "<table>\n  <tbody>\n  </tbody>\n</table>\n"
+    );
+    assert_eq!(no_cols.render_heatmap(&Default::default()), "");
+}
+
+#[test]
+fn tables_keep_rows_without_columns() {
+    let no_cols: Matrix<i32> = Matrix::new_empty(2, 0);
+
+    assert_eq!(
+        no_cols.to_markdown_table(Some(&["a", "b"]), None),
+        "|  |\n| --- |\n| a |\n| b |\n"
+    );
+}
@@ -0,0 +1,117 @@
+use crate::matrix;
+use crate::matrix::Matrix;
+
+#[test]
+fn path_graph_laplacians() {
+    let a: Matrix<f64> = matrix![0.0, 1.0, 0.0; 1.0, 0.0, 1.0; 0.0, 1.0, 0.0];
+
+    assert_eq!(a.degrees(), Some(vec![1.0, 2.0, 1.0]));
+    assert_eq!(
+        a.adjacency_to_laplacian(),
+        Some(matrix![1.0, -1.0, 0.0; -1.0, 2.0, -1.0; 0.0, -1.0, 1.0])
+    );
+    assert_eq!(
+        a.random_walk_laplacian(),
+        Some(matrix![1.0, -1.0, 0.0; -0.5, 1.0, -0.5; 0.0, -1.0, 1.0])
+    );
+
+    let normalized = a.normalized_laplacian().unwrap();
+    let r = -1.0 / 2.0_f64.sqrt();
+    let expected = [1.0, r, 0.0, r, 1.0, r, 0.0, r, 1.0];
+    for (got, want) in normalized.as_slice().iter().zip(expected) {
+        assert!((got - want).abs() < 1e-12);
+    }
+
+    assert!(matrix![0.0, 1.0].adjacency_to_laplacian().is_none());
+}
+
+#[test]
+fn symmetry_and_connectivity() {
+    let directed: Matrix<f64> = matrix![0.0, 1.0, 0.0; 0.0, 0.0, 1.0; 0.0, 0.0, 0.0];
+    let split: Matrix<f64> = matrix![0.0, 1.0, 0.0; 1.0, 0.0, 0.0; 0.0, 0.0, 0.0];
+
+    assert!(!directed.is_undirected(0.0));
+    assert!(directed.is_connected());
+    assert!(split.is_undirected(0.0));
+    assert!(!split.is_connected());
+
+    // The isolated vertex keeps a zero row in the normalized Laplacian.
+    let normalized = split.normalized_laplacian().unwrap();
+    assert_eq!(normalized.get_row(2), Some(&[0.0, 0.0, 0.0][..]));
+}
+
+#[test]
+fn reachability() {
+    // 0 -> 1 -> 2 -> 3, plus a self-contained loop 4 <-> 5.
+    let mut edges = Matrix::new_empty(6, 6);
+    for (i, j) in [(0, 1), (1, 2), (2, 3), (4, 5), (5, 4)] {
+        edges.set(i, j, true).unwrap();
+    }
+    let closure = edges.transitive_closure().unwrap();
+
+    assert_eq!(
+        closure.get_row(0),
+        Some(&[false, true, true, true, false, false][..])
+    );
+    assert_eq!(closure.get_row(3), Some(&[false; 6][..]));
+    assert_eq!(
+        closure.get_row(4),
+        Some(&[false, false, false, false, true, true][..])
+    );
+
+    let path = edges.boolean_product(&edges).unwrap();
+    assert_eq!(path.at(0, 2), Some(&true));
+    assert_eq!(path.at(0, 1), Some(&false));
+    assert!(matrix![true, false].transitive_closure().is_none());
+}
+
+#[test]
+fn min_plus_shortest_paths() {
+    let inf = f64::INFINITY;
+    let w: Matrix<f64> = matrix![
+        0.0, 4.0, 1.0, inf;
+        inf, 0.0, inf, 1.0;
+        inf, 2.0, 0.0, 6.0;
+        inf, inf, inf, 0.0
+    ];
+
+    assert_eq!(
+        w.shortest_paths(),
+        Some(matrix![
+            0.0, 3.0, 1.0, 4.0;
+            inf, 0.0, inf, 1.0;
+            inf, 2.0, 0.0, 3.0;
+            inf, inf, inf, 0.0
+        ])
+    );
+    assert_eq!(
+        w.min_plus_product(&w).unwrap().get_row(0),
+        Some(&[0.0, 3.0, 1.0, 5.0][..])
+    );
+
+    let negative_cycle: Matrix<f64> = matrix![0.0, 1.0; -2.0, 0.0];
+    assert!(negative_cycle.shortest_paths().is_none());
+}
+
+#[test]
+fn pagerank_ranks_hub_first() {
+    // Everyone links to vertex 0, which links back to 1; vertex 3 has no
+    // outgoing edges.
+    let a: Matrix<f64> = matrix![
+        0.0, 1.0, 0.0, 0.0;
+        1.0, 0.0, 0.0, 0.0;
+        1.0, 0.0, 0.0, 0.0;
+        1.0, 0.0, 1.0, 0.0
+    ];
+    let result = a.pagerank(0.85, 1e-10).unwrap();
+
+    assert!(result.converged);
+    assert!(result.iterations > 1);
+    assert!((result.ranks.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    assert!(result.ranks[0] > result.ranks[1]);
+    assert!(result.ranks[1] > result.ranks[2]);
+
+    // With no damping every vertex gets the same score immediately.
+    let flat = a.pagerank(0.0, 1e-12).unwrap();
+    assert_eq!(flat.ranks, vec![0.25; 4]);
+}
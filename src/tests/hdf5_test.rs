@@ -0,0 +1,44 @@
+use crate::matrix;
+use crate::matrix::hdf5::Hdf5RowChunks;
+use crate::matrix::Matrix;
+
+#[test]
+fn hdf5_round_trip() {
+    let path = std::env::temp_dir().join(format!("matrix_hdf5_{}.h5", std::process::id()));
+    let a: Matrix<f64> = matrix![1.0, -2.5, 3.0; 4.0, 5.0, 6.5];
+    let b: Matrix<i32> = matrix![1, 2; 3, 4; 5, 6];
+
+    a.write_hdf5(&path, "a").unwrap();
+    b.write_hdf5(&path, "b").unwrap();
+
+    assert_eq!(Matrix::<f64>::from_hdf5(&path, "a").unwrap(), a);
+    assert_eq!(Matrix::<i32>::from_hdf5(&path, "b").unwrap(), b);
+    assert!(a.write_hdf5(&path, "a").is_err());
+    assert!(Matrix::<f64>::from_hdf5(&path, "missing").is_err());
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn hdf5_row_chunks() {
+    let path = std::env::temp_dir().join(format!("matrix_hdf5_chunks_{}.h5", std::process::id()));
+    let m = Matrix::from_vec(
+        (0..5)
+            .map(|i| (0..3).map(|j| i * 3 + j).collect())
+            .collect(),
+    );
+    m.write_hdf5(&path, "m").unwrap();
+
+    let chunks = Hdf5RowChunks::<i64>::open(&path, "m", 2).unwrap();
+    assert_eq!((chunks.num_rows(), chunks.num_cols()), (5, 3));
+
+    let chunks: Vec<Matrix<i64>> = chunks.map(|c| c.unwrap()).collect();
+    assert_eq!(
+        chunks.iter().map(|c| c.num_rows()).collect::<Vec<_>>(),
+        vec![2, 2, 1]
+    );
+    assert_eq!(chunks[2], matrix![12, 13, 14]);
+    assert!(Hdf5RowChunks::<i64>::open(&path, "m", 0).is_err());
+
+    std::fs::remove_file(&path).unwrap();
+}
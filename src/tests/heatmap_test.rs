@@ -0,0 +1,26 @@
+use crate::matrix::heatmap::{ColorScale, HeatmapOptions, HeatmapStyle};
+use crate::matrix::Matrix;
+
+#[test]
+fn heatmap_shades() {
+    let mat_a = Matrix::from_vec(vec![vec![0.0, 1.0], vec![2.0, 4.0]]);
+
+    assert_eq!(mat_a.render_heatmap(&HeatmapOptions::new()), "  ░░\n▒▒██\n");
+    assert_eq!(
+        mat_a.render_heatmap(&HeatmapOptions::new().range(0.0, 1.0)),
+        "  ██\n████\n"
+    );
+}
+
+#[test]
+fn heatmap_ansi() {
+    let mat_a = Matrix::from_vec(vec![vec![-1.0, 0.0, 1.0]]);
+    let options = HeatmapOptions::new()
+        .style(HeatmapStyle::Ansi256)
+        .scale(ColorScale::Diverging);
+
+    assert_eq!(
+        mat_a.render_heatmap(&options),
+        "\x1b[48;5;19m  \x1b[48;5;231m  \x1b[48;5;124m  \x1b[0m\n"
+    );
+}
@@ -0,0 +1,24 @@
+use crate::matrix::Matrix;
+use image::GrayImage;
+
+#[test]
+fn gray_image_round_trip() {
+    let image = GrayImage::from_raw(3, 2, vec![0, 51, 102, 153, 204, 255]).unwrap();
+    let mat_a = Matrix::from_gray_image(&image);
+
+    assert_eq!((mat_a.num_rows(), mat_a.num_cols()), (2, 3));
+    assert_eq!(mat_a.at_or_default(1, 0), 153);
+    assert_eq!(mat_a.to_gray_image().unwrap(), image);
+}
+
+#[test]
+fn normalized_image_round_trip() {
+    let image = GrayImage::from_raw(2, 1, vec![0, 255]).unwrap();
+    let mat_a = Matrix::from_gray_image_normalized(&image);
+
+    assert_eq!(mat_a, Matrix::from_vec(vec![vec![0.0, 1.0]]));
+
+    let mat_b = Matrix::from_vec(vec![vec![-0.5, 0.2, 1.5]]);
+    let image = mat_b.to_gray_image_normalized().unwrap();
+    assert_eq!(image.as_raw(), &vec![0, 51, 255]);
+}
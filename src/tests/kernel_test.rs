@@ -0,0 +1,29 @@
+use crate::matrix;
+use crate::matrix::{Axis, Matrix};
+
+#[test]
+fn gram_matrices() {
+    let x = matrix![1, 2, 3; 4, 5, 6];
+
+    assert_eq!(x.gram(Axis::Rows).to_dense(), matrix![14, 32; 32, 77]);
+    assert_eq!(
+        x.gram(Axis::Cols).to_dense(),
+        matrix![17, 22, 27; 22, 29, 36; 27, 36, 45]
+    );
+    assert_eq!(x.gram(Axis::Cols).packed().len(), 6);
+}
+
+#[test]
+fn rbf_kernel_matrix() {
+    let points: Matrix<f64> = matrix![0.0, 0.0; 1.0, 0.0; 0.0, 2.0];
+    let rbf = |a: &[f64], b: &[f64]| {
+        let d2: f64 = a.iter().zip(b).map(|(x, y)| (x - y) * (x - y)).sum();
+        (-d2 / 2.0).exp()
+    };
+    let k = points.kernel_matrix(rbf);
+
+    assert_eq!(k.at(1, 1), Some(&1.0));
+    assert_eq!(k.at(0, 1), Some(&(-0.5_f64).exp()));
+    assert_eq!(k.at(2, 1), k.at(1, 2));
+    assert_eq!(k.at(1, 2), Some(&(-2.5_f64).exp()));
+}
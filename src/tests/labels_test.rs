@@ -0,0 +1,24 @@
+use crate::matrix;
+use crate::matrix::Matrix;
+
+#[test]
+fn one_hot_round_trip() {
+    let labels = [2, 0, 1, 2];
+    let encoded: Matrix<f32> = Matrix::one_hot(&labels, 3).unwrap();
+
+    assert_eq!(
+        encoded,
+        matrix![0.0, 0.0, 1.0; 1.0, 0.0, 0.0; 0.0, 1.0, 0.0; 0.0, 0.0, 1.0]
+    );
+    assert_eq!(encoded.argmax_rows(), Some(labels.to_vec()));
+    assert!(Matrix::<u8>::one_hot(&[0, 3], 3).is_err());
+}
+
+#[test]
+fn decode_scores() {
+    let scores = matrix![0.1, 0.7, 0.2; 0.6, 0.3, 0.1];
+
+    assert_eq!(scores.argmax_rows(), Some(vec![1, 0]));
+    assert_eq!(Matrix::<u8>::one_hot(&[], 4).unwrap().num_rows(), 0);
+    assert_eq!(Matrix::<f64>::new(2, 0).argmax_rows(), None);
+}
@@ -0,0 +1,117 @@
+use crate::matrix;
+use crate::matrix::Matrix;
+
+#[test]
+fn identity_and_pow() {
+    let m = matrix![1, 1; 1, 0];
+
+    assert_eq!(Matrix::<i32>::identity(2), matrix![1, 0; 0, 1]);
+    assert_eq!(m.pow(0), Some(matrix![1, 0; 0, 1]));
+    assert_eq!(m.pow(10), Some(matrix![89, 55; 55, 34]));
+    assert!(matrix![1, 2, 3].pow(2).is_none());
+}
+
+#[test]
+fn solve_with_pivoting() {
+    let a: Matrix<f64> = matrix![0.0, 2.0, 1.0; 1.0, 1.0, 0.0; 2.0, 0.0, 3.0];
+    let b = matrix![5.0, 1.0; 3.0, 2.0; 11.0, 5.0];
+    let x = a.solve(&b).unwrap();
+    let check = a.matrix_multiply(&x).unwrap();
+
+    for (got, want) in check.as_slice().iter().zip(b.as_slice()) {
+        assert!((got - want).abs() < 1e-12);
+    }
+
+    assert!(matrix![1.0, 2.0; 2.0, 4.0].solve(&b).is_none());
+    assert!(a.solve(&matrix![1.0; 2.0]).is_none());
+    assert!(matrix![f64::NAN, 1.0; 1.0, 1.0]
+        .solve(&matrix![1.0; 2.0])
+        .is_none());
+}
+
+#[test]
+fn transpose_and_qr() {
+    let a: Matrix<f64> = matrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0];
+    assert_eq!(a.transpose(), matrix![1.0, 3.0, 5.0; 2.0, 4.0, 6.0]);
+
+    let (q, r) = a.qr().unwrap();
+    let qtq = q.transpose().matrix_multiply(&q).unwrap();
+    let qr = q.matrix_multiply(&r).unwrap();
+
+    for (got, want) in qtq.as_slice().iter().zip([1.0, 0.0, 0.0, 1.0]) {
+        assert!((got - want).abs() < 1e-12);
+    }
+    for (got, want) in qr.as_slice().iter().zip(a.as_slice()) {
+        assert!((got - want).abs() < 1e-12);
+    }
+    assert_eq!(*r.at(1, 0).unwrap(), 0.0);
+    assert!(a.transpose().qr().is_none());
+}
+
+#[test]
+fn least_squares_fit() {
+    // y = 1 + 2x exactly, so least squares recovers it.
+    let a: Matrix<f64> = matrix![1.0, 0.0; 1.0, 1.0; 1.0, 2.0; 1.0, 3.0];
+    let b: Matrix<f64> = matrix![1.0; 3.0; 5.0; 7.0];
+    let x = a.lstsq(&b).unwrap();
+
+    assert!((x.at(0, 0).unwrap() - 1.0).abs() < 1e-12);
+    assert!((x.at(1, 0).unwrap() - 2.0).abs() < 1e-12);
+
+    let collinear: Matrix<f64> = matrix![1.0, 2.0; 2.0, 4.0; 3.0, 6.0];
+    assert!(collinear.lstsq(&matrix![1.0; 2.0; 3.0]).is_none());
+}
+
+#[test]
+fn symmetric_eigen_decomposition() {
+    let a: Matrix<f64> = matrix![4.0, 1.0, 2.0; 1.0, 3.0, 0.0; 2.0, 0.0, 5.0];
+    let (values, vectors) = a.symmetric_eigen().unwrap();
+
+    assert!(values.windows(2).all(|w| w[0] <= w[1]));
+    assert!((values.iter().sum::<f64>() - 12.0).abs() < 1e-12);
+
+    // A v = λ v for every column.
+    let av = a.matrix_multiply(&vectors).unwrap();
+    for (j, value) in values.iter().enumerate() {
+        for i in 0..3 {
+            let want = value * vectors.at(i, j).unwrap();
+            assert!((av.at(i, j).unwrap() - want).abs() < 1e-10);
+        }
+    }
+    assert!(matrix![1.0, 2.0].symmetric_eigen().is_none());
+}
+
+#[test]
+fn lu_and_determinants() {
+    let a: Matrix<f64> = matrix![0.0, 2.0, 1.0; 1.0, 1.0, 0.0; 2.0, 0.0, 3.0];
+    let (p, l, u) = a.lu().unwrap();
+
+    let pa = p.apply_rows(&a).unwrap();
+    let lu = l.matrix_multiply(&u).unwrap();
+    for (got, want) in lu.as_slice().iter().zip(pa.as_slice()) {
+        assert!((got - want).abs() < 1e-12);
+    }
+    assert_eq!(*l.at(0, 0).unwrap(), 1.0);
+    assert_eq!(*u.at(2, 0).unwrap(), 0.0);
+
+    assert!((a.determinant().unwrap() + 8.0).abs() < 1e-12);
+    let (sign, ln_abs) = a.ln_det().unwrap();
+    assert_eq!(sign, -1.0);
+    assert!((ln_abs - 8.0_f64.ln()).abs() < 1e-12);
+
+    assert_eq!(
+        matrix![1.0, 2.0; 2.0, 4.0].ln_det(),
+        Some((0.0, f64::NEG_INFINITY))
+    );
+    assert!(matrix![1.0, 2.0].determinant().is_none());
+}
+
+#[test]
+fn ln_det_does_not_overflow() {
+    let big: Matrix<f64> = Matrix::<f64>::identity(200).multiply(1e10);
+
+    assert_eq!(big.determinant(), Some(f64::INFINITY));
+    let (sign, ln_abs) = big.ln_det().unwrap();
+    assert_eq!(sign, 1.0);
+    assert!((ln_abs - 200.0 * 1e10_f64.ln()).abs() < 1e-9);
+}
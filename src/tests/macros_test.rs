@@ -0,0 +1,27 @@
+use crate::matrix::Matrix;
+use crate::{matrix, vector};
+
+#[test]
+fn matrix_macro() {
+    let mat_a = matrix![1, 2, 3; 4, 5, 6];
+    assert_eq!(mat_a, Matrix::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]));
+
+    let mat_b: Matrix<f64> = matrix![
+        1.0, 2.0;
+        3.0, 4.0;
+    ];
+    assert_eq!((mat_b.num_rows(), mat_b.num_cols()), (2, 2));
+    assert_eq!(matrix![7], Matrix::from_vec(vec![vec![7]]));
+
+    let empty: Matrix<i32> = matrix![];
+    assert_eq!((empty.num_rows(), empty.num_cols()), (0, 0));
+}
+
+#[test]
+fn vector_macro() {
+    let v = vector![1.5, 2.5, 3.5];
+
+    assert_eq!((v.num_rows(), v.num_cols()), (3, 1));
+    assert_eq!(v.as_slice(), &[1.5, 2.5, 3.5]);
+    assert_eq!(Matrix::from([[1, 2], [3, 4]]), matrix![1, 2; 3, 4]);
+}
@@ -0,0 +1,50 @@
+use crate::matrix;
+use crate::matrix::Matrix;
+
+#[test]
+fn stochastic_and_stationary() {
+    let p: Matrix<f64> = matrix![0.9, 0.1; 0.5, 0.5];
+
+    assert!(p.is_row_stochastic(1e-12));
+    assert!(!matrix![0.9, 0.2; 0.5, 0.5].is_row_stochastic(1e-12));
+    assert!(!matrix![1.5, -0.5; 0.5, 0.5].is_row_stochastic(1e-12));
+
+    let pi = p.stationary_distribution(1e-12, 10_000).unwrap();
+    assert!((pi[0] - 5.0 / 6.0).abs() < 1e-9);
+    assert!((pi[1] - 1.0 / 6.0).abs() < 1e-9);
+
+    // Periodic chains still converge thanks to the lazy iteration.
+    let flip = matrix![0.0, 1.0; 1.0, 0.0];
+    assert_eq!(
+        flip.stationary_distribution(1e-12, 100),
+        Some(vec![0.5, 0.5])
+    );
+    assert_eq!(flip.n_step_transition(3), Some(flip));
+}
+
+#[test]
+fn gamblers_ruin_absorption() {
+    // States 0 and 3 absorb; from 1 and 2 move left or right with equal odds.
+    let p: Matrix<f64> = matrix![
+        1.0, 0.0, 0.0, 0.0;
+        0.5, 0.0, 0.5, 0.0;
+        0.0, 0.5, 0.0, 0.5;
+        0.0, 0.0, 0.0, 1.0
+    ];
+    let result = p.absorption_probabilities().unwrap();
+
+    assert_eq!(result.transient, vec![1, 2]);
+    assert_eq!(result.absorbing, vec![0, 3]);
+
+    let expected = [2.0 / 3.0, 1.0 / 3.0, 1.0 / 3.0, 2.0 / 3.0];
+    for (got, want) in result.probabilities.as_slice().iter().zip(expected) {
+        assert!((got - want).abs() < 1e-12);
+    }
+    for got in result.expected_steps {
+        assert!((got - 2.0).abs() < 1e-12);
+    }
+
+    // A closed loop of transient states is never absorbed.
+    let trapped = matrix![1.0, 0.0, 0.0; 0.0, 0.0, 1.0; 0.0, 1.0, 0.0];
+    assert!(trapped.absorption_probabilities().is_none());
+}
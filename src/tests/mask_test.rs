@@ -0,0 +1,48 @@
+use crate::matrix;
+use crate::matrix::Matrix;
+
+#[test]
+fn scalar_comparisons() {
+    let m: Matrix<f64> = matrix![1.0, 2.0; 3.0, f64::NAN];
+
+    assert_eq!(m.gt(2.0), matrix![false, false; true, false]);
+    assert_eq!(m.le(2.0), matrix![true, true; false, false]);
+    assert_eq!(m.eq_elementwise(3.0), matrix![false, false; true, false]);
+}
+
+#[test]
+fn matrix_comparisons() {
+    let a = matrix![1, 5; 3, 4];
+    let b = matrix![2, 5; 1, 4];
+
+    assert_eq!(a.matrix_lt(&b), Some(matrix![true, false; false, false]));
+    assert_eq!(a.matrix_ge(&b), Some(matrix![false, true; true, true]));
+    assert_eq!(a.matrix_gt(&matrix![1, 2]), None);
+}
+
+#[test]
+fn select_by_mask() {
+    let a = matrix![1, -2; -3, 4];
+    let b = matrix![10, 20; 30, 40];
+    let positive = a.gt(0);
+
+    assert_eq!(a.select(&positive, &b), Some(matrix![1, 20; 30, 4]));
+    assert_eq!(a.where_(&positive, 0), Some(matrix![1, 0; 0, 4]));
+    assert_eq!(a.filter(&positive), Some(vec![1, 4]));
+    assert_eq!(a.filter(&matrix![true, false]), None);
+}
+
+#[test]
+fn masked_assignment() {
+    let mut m: Matrix<f64> = matrix![0.5, -3.0; 7.0, 1e-9];
+
+    let outliers = m.map(|x| x.abs() > 2.0);
+    let tiny = m.map(|x| x.abs() < 1e-6);
+    m.apply_where(&outliers, |x| x.clamp(-2.0, 2.0))
+        .unwrap()
+        .set_where(&tiny, 0.0)
+        .unwrap();
+
+    assert_eq!(m, matrix![0.5, -2.0; 2.0, 0.0]);
+    assert!(m.set_where(&matrix![true], 1.0).is_err());
+}
@@ -0,0 +1,80 @@
+use crate::matrix::mat::{read_mat, write_mat, MatError};
+use crate::matrix::Matrix;
+
+#[test]
+fn mat_round_trip() {
+    let mat_a = Matrix::from_vec(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    let mat_b = Matrix::from_vec(vec![vec![7.0], vec![8.0]]);
+    let mut out = Vec::new();
+    write_mat(&mut out, &[("a", &mat_a), ("long_name", &mat_b)]).unwrap();
+
+    assert_eq!(&out[..10], b"MATLAB 5.0");
+    assert_eq!(out.len() % 8, 0);
+
+    let variables = read_mat::<f64, _>(out.as_slice()).unwrap();
+    assert_eq!(
+        variables,
+        vec![
+            (String::from("a"), mat_a),
+            (String::from("long_name"), mat_b)
+        ]
+    );
+}
+
+#[test]
+fn mat_converts_stored_type() {
+    let mat_a = Matrix::from_vec(vec![vec![1u8, 2], vec![3, 4]]);
+    let mut out = Vec::new();
+    mat_a.write_mat(&mut out, "x").unwrap();
+
+    let mat_b = Matrix::<f64>::from_mat(out.as_slice(), "x").unwrap();
+    assert_eq!(
+        mat_b,
+        Matrix::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]])
+    );
+    assert!(Matrix::<f64>::from_mat(out.as_slice(), "y").is_err());
+}
+
+#[test]
+fn mat_keeps_64_bit_integers_exact() {
+    let mat_a = Matrix::from_vec(vec![vec![(1i64 << 60) + 1, i64::MIN]]);
+    let mat_b = Matrix::from_vec(vec![vec![u64::MAX], vec![(1 << 53) + 1]]);
+    let mut out = Vec::new();
+    mat_a.write_mat(&mut out, "a").unwrap();
+    assert_eq!(Matrix::<i64>::from_mat(out.as_slice(), "a").unwrap(), mat_a);
+
+    out.clear();
+    mat_b.write_mat(&mut out, "b").unwrap();
+    assert_eq!(Matrix::<u64>::from_mat(out.as_slice(), "b").unwrap(), mat_b);
+
+    // A negative row count in the dimensions element.
+    out[160..164].copy_from_slice(&(-1i32).to_le_bytes());
+    assert!(matches!(
+        Matrix::<u64>::from_mat(out.as_slice(), "b"),
+        Err(MatError::Header(_))
+    ));
+}
+
+#[cfg(feature = "zlib")]
+#[test]
+fn mat_reads_compressed_variables() {
+    use std::io::Write;
+
+    let mat_a = Matrix::from_vec(vec![vec![1.5, -2.0], vec![0.25, 4.0]]);
+    let mut plain = Vec::new();
+    mat_a.write_mat(&mut plain, "c").unwrap();
+
+    let mut encoder = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(&plain[128..]).unwrap();
+    let compressed = encoder.finish().unwrap();
+
+    let mut file = plain[..128].to_vec();
+    file.extend_from_slice(&15u32.to_le_bytes());
+    file.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+    file.extend_from_slice(&compressed);
+
+    assert_eq!(
+        Matrix::<f64>::from_mat(file.as_slice(), "c").unwrap(),
+        mat_a
+    );
+}
@@ -0,0 +1,92 @@
+use crate::matrix;
+use crate::matrix::mmap::MmapError;
+use crate::matrix::{Axis, Matrix};
+
+#[test]
+fn mapped_file_reads_like_a_matrix() {
+    let path = std::env::temp_dir().join(format!("matrix_mmap_{}.bin", std::process::id()));
+    let m: Matrix<f64> = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0; 7.0, 8.0, 9.0];
+    std::fs::write(&path, m.to_bytes()).unwrap();
+
+    let mapped = Matrix::<f64>::open_mmap(&path).unwrap();
+
+    assert_eq!((mapped.num_rows(), mapped.num_cols()), (3, 3));
+    assert_eq!(mapped.at(1, 2), Some(6.0));
+    assert_eq!(mapped.at(3, 0), None);
+    assert_eq!(mapped.get_row(2), Some(vec![7.0, 8.0, 9.0]));
+    assert_eq!(
+        mapped.rows().map(|r| r[0]).collect::<Vec<_>>(),
+        vec![1.0, 4.0, 7.0]
+    );
+    assert_eq!(
+        mapped.slice_rows(1..3),
+        Some(matrix![4.0, 5.0, 6.0; 7.0, 8.0, 9.0])
+    );
+    assert_eq!(mapped.to_matrix(), m);
+
+    let wrong = Matrix::<i32>::open_mmap(&path);
+    assert!(matches!(wrong, Err(MmapError::Format(_))));
+    drop(mapped);
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(matches!(
+        Matrix::<f64>::open_mmap(&path),
+        Err(MmapError::Io(_))
+    ));
+}
+
+#[test]
+fn out_of_core_reductions_and_product() {
+    let dir = std::env::temp_dir();
+    let id = std::process::id();
+    let (path_a, path_b, path_c) = (
+        dir.join(format!("matrix_ooc_a_{}.bin", id)),
+        dir.join(format!("matrix_ooc_b_{}.bin", id)),
+        dir.join(format!("matrix_ooc_c_{}.bin", id)),
+    );
+
+    let a = Matrix::from_vec(
+        (0..7)
+            .map(|i| (0..5).map(|j| i * 5 + j - 10).collect())
+            .collect(),
+    );
+    let b = Matrix::from_vec(
+        (0..5)
+            .map(|i| (0..6).map(|j| (i + 2 * j) % 7).collect())
+            .collect(),
+    );
+    std::fs::write(&path_a, a.to_bytes()).unwrap();
+    std::fs::write(&path_b, b.to_bytes()).unwrap();
+    let (ma, mb) = (
+        Matrix::<i64>::open_mmap(&path_a).unwrap(),
+        Matrix::<i64>::open_mmap(&path_b).unwrap(),
+    );
+
+    // A 96-byte budget holds two rows of five i64s per block.
+    assert_eq!(
+        ma.row_blocks(96)
+            .map(|blk| blk.num_rows())
+            .collect::<Vec<_>>(),
+        vec![2, 2, 2, 1]
+    );
+    assert_eq!(ma.sum_axis(Axis::Cols, 96), a.sum_axis(Axis::Cols));
+    assert_eq!(ma.sum_axis(Axis::Rows, 1), a.sum_axis(Axis::Rows));
+
+    // 2x2 tiles, so every dimension has a ragged edge.
+    let product = ma.matmul_to_file(&mb, &path_c, 3 * 4 * 8).unwrap();
+    assert_eq!(product.to_matrix(), a.matrix_multiply(&b).unwrap());
+    assert!(matches!(
+        mb.matmul_to_file(&ma, &path_c, 1024),
+        Err(MmapError::DimensionMismatch)
+    ));
+    assert!(matches!(
+        ma.matmul_to_file(&mb, &path_b, 1024),
+        Err(MmapError::OutputAliasesInput)
+    ));
+    assert_eq!(mb.to_matrix(), b);
+
+    drop((ma, mb, product));
+    for path in [path_a, path_b, path_c] {
+        std::fs::remove_file(path).unwrap();
+    }
+}
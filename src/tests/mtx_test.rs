@@ -0,0 +1,62 @@
+use crate::matrix::mtx::{MtxError, MtxFormat};
+use crate::matrix::Matrix;
+
+#[test]
+fn mtx_read_symmetric_coordinate() {
+    let input = "%%MatrixMarket matrix coordinate real symmetric\n\
+                 % comment\n\
+                 3 3 4\n\
+                 1 1 2.0\n\
+                 2 1 -1.0\n\
+                 2 2 2.0\n\
+                 3 3 5.0\n";
+    let mat_a = Matrix::<f64>::from_matrix_market(input.as_bytes()).unwrap();
+
+    assert_eq!(
+        mat_a,
+        Matrix::from_vec(vec![
+            vec![2.0, -1.0, 0.0],
+            vec![-1.0, 2.0, 0.0],
+            vec![0.0, 0.0, 5.0],
+        ])
+    );
+}
+
+#[test]
+fn mtx_round_trip() {
+    let mat_a = Matrix::from_vec(vec![vec![1, 0, 3], vec![0, 5, 0]]);
+
+    for format in [MtxFormat::Array, MtxFormat::Coordinate] {
+        let mut out = Vec::new();
+        mat_a.write_matrix_market(&mut out, format).unwrap();
+
+        let mat_b = Matrix::<i32>::from_matrix_market(out.as_slice()).unwrap();
+        assert_eq!(mat_a, mat_b);
+    }
+}
+
+#[test]
+fn mtx_rejects_out_of_bounds_entry() {
+    let input = "%%MatrixMarket matrix coordinate integer general\n2 2 1\n3 1 7\n";
+
+    assert!(Matrix::<i32>::from_matrix_market(input.as_bytes()).is_err());
+}
+
+#[test]
+fn mtx_rejects_overflowing_size_line() {
+    let input = "%%MatrixMarket matrix array integer general\n4294967296 4294967296 1\n";
+
+    assert!(matches!(
+        Matrix::<i8>::from_matrix_market(input.as_bytes()),
+        Err(MtxError::Header(_))
+    ));
+
+    let short = "%%MatrixMarket matrix array integer symmetric\n3 3\n1\n2\n";
+    assert!(matches!(
+        Matrix::<i8>::from_matrix_market(short.as_bytes()),
+        Err(MtxError::EntryCount {
+            expected: 6,
+            found: 2
+        })
+    ));
+}
@@ -0,0 +1,31 @@
+use crate::matrix::Matrix;
+use nalgebra::{DMatrix, Matrix2x3, SMatrix};
+
+#[test]
+fn nalgebra_dmatrix_round_trip() {
+    let mat_a = Matrix::from_vec(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+
+    let d = DMatrix::from(&mat_a);
+    assert_eq!(
+        d,
+        DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0])
+    );
+    assert_eq!(d[(1, 0)], 4.0);
+
+    let mat_b: Matrix<f64> = d.into();
+    assert_eq!(mat_b, mat_a);
+    assert_eq!(DMatrix::from(mat_b), DMatrix::from(&mat_a));
+}
+
+#[test]
+fn nalgebra_fixed_size() {
+    let s = Matrix2x3::new(1, 2, 3, 4, 5, 6);
+    let mat_a: Matrix<i32> = s.into();
+    assert_eq!(mat_a, Matrix::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]));
+
+    let back: Matrix2x3<i32> = mat_a.try_into().unwrap();
+    assert_eq!(back, s);
+
+    let mat_b = Matrix::from_vec(vec![vec![1, 2], vec![3, 4]]);
+    assert!(SMatrix::<i32, 2, 3>::try_from(mat_b).is_err());
+}
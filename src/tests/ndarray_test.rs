@@ -0,0 +1,36 @@
+use crate::matrix::Matrix;
+use ndarray::{array, Array2, ArrayD, IxDyn};
+
+#[test]
+fn ndarray_round_trip() {
+    let mat_a = Matrix::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    let ptr = mat_a.as_slice().as_ptr();
+
+    let a: Array2<i32> = mat_a.into();
+    assert_eq!(a, array![[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(a.as_ptr(), ptr);
+
+    let mat_b: Matrix<i32> = a.into();
+    assert_eq!(mat_b.as_slice().as_ptr(), ptr);
+}
+
+#[test]
+fn ndarray_copies_non_standard_layout() {
+    let a = array![[1, 2, 3], [4, 5, 6]].reversed_axes();
+    let mat_a: Matrix<i32> = a.into();
+
+    assert_eq!(
+        mat_a,
+        Matrix::from_vec(vec![vec![1, 4], vec![2, 5], vec![3, 6]])
+    );
+    assert_eq!(mat_a.as_array_view(), array![[1, 4], [2, 5], [3, 6]]);
+}
+
+#[test]
+fn ndarray_dynamic_dimensions() {
+    let a = ArrayD::<f64>::zeros(IxDyn(&[2, 2]));
+    assert!(Matrix::try_from(a).is_ok());
+
+    let a = ArrayD::<f64>::zeros(IxDyn(&[2, 2, 2]));
+    assert!(Matrix::try_from(a).is_err());
+}
@@ -0,0 +1,34 @@
+use crate::matrix;
+use crate::matrix::normalize::{Norm, Scaling};
+use crate::matrix::{Axis, Matrix};
+
+#[test]
+fn normalize_rows_by_norm() {
+    let m: Matrix<f64> = matrix![3.0, -4.0; 0.0, 0.0; 1.0, 3.0];
+
+    assert_eq!(
+        m.normalize_rows(Norm::L2),
+        matrix![0.6, -0.8; 0.0, 0.0; 1.0 / 10.0_f64.sqrt(), 3.0 / 10.0_f64.sqrt()]
+    );
+    assert_eq!(
+        m.normalize_rows(Norm::L1),
+        matrix![3.0 / 7.0, -4.0 / 7.0; 0.0, 0.0; 0.25, 0.75]
+    );
+}
+
+#[test]
+fn min_max_columns_with_scalings() {
+    let m: Matrix<f64> = matrix![1.0, 10.0, 5.0; 3.0, 30.0, 5.0; 2.0, 20.0, 5.0];
+    let (scaled, scalings) = m.normalize_axis(Axis::Cols, Norm::MinMax);
+
+    assert_eq!(scaled, matrix![0.0, 0.0, 0.0; 1.0, 1.0, 0.0; 0.5, 0.5, 0.0]);
+    assert_eq!(
+        scalings[1],
+        Scaling {
+            offset: 10.0,
+            scale: 20.0
+        }
+    );
+    assert_eq!(scalings[2].scale, 0.0);
+    assert_eq!(m.normalize_cols(Norm::MinMax), scaled);
+}
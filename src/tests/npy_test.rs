@@ -0,0 +1,83 @@
+use crate::matrix::npy::NpyError;
+use crate::matrix::Matrix;
+
+#[test]
+fn npy_round_trip() {
+    let mat_a = Matrix::from_vec(vec![vec![1.5f32, -2.0, 3.25], vec![4.0, 5.0, 6.0]]);
+    let mut out = Vec::new();
+    mat_a.to_npy(&mut out).unwrap();
+
+    assert_eq!((out.len() - 2 * 3 * 4) % 64, 0);
+    assert_eq!(Matrix::<f32>::from_npy(out.as_slice()).unwrap(), mat_a);
+}
+
+#[test]
+fn npy_reads_fortran_order_big_endian() {
+    let mut header = String::from("{'descr': '>i2', 'fortran_order': True, 'shape': (2, 3), }");
+    header.push_str(&" ".repeat(64 - (10 + header.len() + 1) % 64));
+    header.push('\n');
+
+    let mut input = b"\x93NUMPY\x01\x00".to_vec();
+    input.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    input.extend_from_slice(header.as_bytes());
+    for x in [1i16, 4, 2, 5, 3, 6] {
+        input.extend_from_slice(&x.to_be_bytes());
+    }
+
+    let mat_a = Matrix::<i16>::from_npy(input.as_slice()).unwrap();
+    assert_eq!(mat_a, Matrix::from_vec(vec![vec![1, 2, 3], vec![4, 5, 6]]));
+}
+
+#[test]
+fn npy_rejects_dtype_mismatch() {
+    let mat_a = Matrix::from_vec(vec![vec![1.0f64, 2.0]]);
+    let mut out = Vec::new();
+    mat_a.to_npy(&mut out).unwrap();
+
+    assert!(Matrix::<f32>::from_npy(out.as_slice()).is_err());
+}
+
+#[test]
+fn npy_rejects_hostile_shapes() {
+    let npy = |shape: &str| {
+        let header = format!(
+            "{{'descr': '<f8', 'fortran_order': False, 'shape': {}, }}\n",
+            shape
+        );
+        let mut input = b"\x93NUMPY\x01\x00".to_vec();
+        input.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        input.extend_from_slice(header.as_bytes());
+        return input;
+    };
+
+    let overflow = npy(&format!("({}, 4)", usize::MAX / 2));
+    assert!(matches!(
+        Matrix::<f64>::from_npy(overflow.as_slice()),
+        Err(NpyError::Shape(_))
+    ));
+
+    let truncated = npy("(100000, 100000)");
+    assert!(matches!(
+        Matrix::<f64>::from_npy(truncated.as_slice()),
+        Err(NpyError::Io(_))
+    ));
+}
+
+#[cfg(feature = "npz")]
+#[test]
+fn npz_round_trip() {
+    use crate::matrix::npy::{read_npz, write_npz};
+    use std::io::Cursor;
+
+    let mat_a = Matrix::from_vec(vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    let mat_b = Matrix::from_vec(vec![vec![5.0, 6.0, 7.0]]);
+    let mut out = Cursor::new(Vec::new());
+    write_npz(&mut out, &[("a", &mat_a), ("b", &mat_b)]).unwrap();
+
+    out.set_position(0);
+    let arrays = read_npz::<f64, _>(out).unwrap();
+    assert_eq!(
+        arrays,
+        vec![(String::from("a"), mat_a), (String::from("b"), mat_b)]
+    );
+}
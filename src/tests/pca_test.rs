@@ -0,0 +1,70 @@
+use crate::matrix;
+use crate::matrix::pca::WhiteningMethod;
+use crate::matrix::{Axis, Matrix};
+
+#[test]
+fn pca_finds_dominant_direction() {
+    // Points spread along (1, 1) with a little noise across it.
+    let data: Matrix<f64> = matrix![
+        -2.0, -2.1;
+        -1.0, -0.9;
+        0.0, 0.1;
+        1.0, 0.9;
+        2.0, 2.0
+    ];
+    let pca = data.pca(1).unwrap();
+    let axis = pca.components.as_slice();
+    let r = 0.5_f64.sqrt();
+
+    assert!((axis[0] - r).abs() < 0.01 && (axis[1] - r).abs() < 0.01);
+    assert!(pca.explained_variance_ratio[0] > 0.99);
+    assert_eq!(pca.mean, vec![0.0, 0.0]);
+
+    let projected = pca.project(&data).unwrap();
+    assert_eq!((projected.num_rows(), projected.num_cols()), (5, 1));
+    assert!(projected.at(0, 0).unwrap() < &-2.8);
+    assert!(data.pca(3).is_none());
+}
+
+#[test]
+fn full_pca_round_trips() {
+    let data: Matrix<f64> = matrix![1.0, 5.0, 2.0; 3.0, 1.0, 0.0; 4.0, 4.0, 4.0; 0.0, 2.0, 7.0];
+    let pca = data.pca(3).unwrap();
+
+    let ratios: f64 = pca.explained_variance_ratio.iter().sum();
+    assert!((ratios - 1.0).abs() < 1e-12);
+    assert!(pca.explained_variance.windows(2).all(|w| w[0] >= w[1]));
+
+    let back = pca.inverse_transform(&pca.project(&data).unwrap()).unwrap();
+    for (got, want) in back.as_slice().iter().zip(data.as_slice()) {
+        assert!((got - want).abs() < 1e-10);
+    }
+}
+
+#[test]
+fn whitening_gives_identity_covariance() {
+    let data: Matrix<f64> = matrix![
+        2.0, 1.0, 0.5;
+        4.0, 3.5, -1.0;
+        1.0, 1.5, 2.0;
+        5.0, 3.0, 0.0;
+        3.0, 4.0, 1.0;
+        0.0, -1.0, 3.0
+    ];
+
+    for method in [WhiteningMethod::Pca, WhiteningMethod::Zca] {
+        let (white, whitening) = data.whiten(method, 0.0).unwrap();
+        let cov = white.covariance(Axis::Rows, 1).unwrap();
+        let identity: Matrix<f64> = Matrix::identity(3);
+
+        for (got, want) in cov.as_slice().iter().zip(identity.as_slice()) {
+            assert!((got - want).abs() < 1e-9);
+        }
+        assert_eq!(whitening.apply(&data), Some(white));
+    }
+
+    // ZCA is the symmetric inverse square root of the covariance.
+    let (_, zca) = data.whiten(WhiteningMethod::Zca, 0.0).unwrap();
+    let t = &zca.transform;
+    assert!((t.at(0, 1).unwrap() - t.at(1, 0).unwrap()).abs() < 1e-12);
+}
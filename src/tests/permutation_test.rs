@@ -0,0 +1,37 @@
+use crate::matrix;
+use crate::matrix::permutation::PermutationMatrix;
+use crate::matrix::Matrix;
+
+#[test]
+fn permutation_validation_and_sign() {
+    assert!(PermutationMatrix::from_indices(vec![0, 0, 1]).is_none());
+    assert!(PermutationMatrix::from_indices(vec![0, 3]).is_none());
+
+    let p = PermutationMatrix::from_indices(vec![2, 0, 1]).unwrap();
+    assert_eq!(p.sign(), 1);
+    assert_eq!(p.inverse().indices(), &[1, 2, 0]);
+    assert_eq!(
+        p.compose(&p.inverse()),
+        Some(PermutationMatrix::identity(3))
+    );
+
+    let mut q = PermutationMatrix::identity(3);
+    q.swap(0, 2);
+    assert_eq!(q.sign(), -1);
+}
+
+#[test]
+fn permutation_matches_dense_product() {
+    let p = PermutationMatrix::from_indices(vec![2, 0, 1]).unwrap();
+    let q = PermutationMatrix::from_indices(vec![1, 0, 2]).unwrap();
+    let a = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    let dense: Matrix<i32> = p.to_dense();
+
+    assert_eq!(p.apply_rows(&a), dense.matrix_multiply(&a));
+    assert_eq!(p.apply_cols(&a), a.matrix_multiply(&dense));
+    assert_eq!(p.apply_vector(&[10, 20, 30]), Some(vec![30, 10, 20]));
+    assert_eq!(
+        p.compose(&q).unwrap().to_dense::<i32>(),
+        dense.matrix_multiply(&q.to_dense()).unwrap()
+    );
+}
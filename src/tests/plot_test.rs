@@ -0,0 +1,36 @@
+use crate::matrix::heatmap::ColorScale;
+use crate::matrix::plot::{PlotError, PlotOptions};
+use crate::matrix::Matrix;
+use std::env;
+use std::fs;
+
+#[test]
+fn plot_heatmap_png() {
+    let path = env::temp_dir().join("matrix_plot_heatmap.png");
+    let mat_a = Matrix::from_vec(vec![vec![0.0, 1.0], vec![2.0, f64::NAN]]);
+    let options = PlotOptions::new().size(40, 20).scale(ColorScale::Grayscale);
+
+    mat_a.plot_heatmap(&path, &options).unwrap();
+
+    let bytes = fs::read(&path).unwrap();
+    assert_eq!(&bytes[1..4], b"PNG");
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn spy_svg() {
+    let path = env::temp_dir().join("matrix_plot_spy.svg");
+    let mat_a = Matrix::from_vec(vec![vec![1, 0, 0], vec![0, 0, 2]]);
+
+    mat_a.spy(&path, &PlotOptions::new().size(30, 20)).unwrap();
+
+    let svg = fs::read_to_string(&path).unwrap();
+    assert_eq!(svg.matches("<rect").count(), 3);
+    fs::remove_file(&path).unwrap();
+
+    let empty: Matrix<i32> = Matrix::new_empty(0, 3);
+    assert!(matches!(
+        empty.spy(&path, &PlotOptions::new()),
+        Err(PlotError::Empty)
+    ));
+}
@@ -0,0 +1,47 @@
+use crate::matrix;
+use crate::matrix::Matrix;
+
+#[test]
+fn structure_checks() {
+    let upper: Matrix<f64> = matrix![1.0, 2.0; 0.0, 3.0];
+    let symmetric: Matrix<f64> = matrix![1.0, 2.0; 2.0 + 1e-12, 3.0];
+    let identity: Matrix<f64> = Matrix::identity(3);
+
+    assert!(upper.is_upper_triangular(0.0));
+    assert!(!upper.is_lower_triangular(0.0));
+    assert!(!upper.is_symmetric(0.0));
+    assert!(symmetric.is_symmetric(1e-9));
+    assert!(!symmetric.is_symmetric(0.0));
+    assert!(identity.is_diagonal(0.0) && identity.is_identity(0.0));
+    assert!(!upper.is_diagonal(0.0));
+
+    let wide: Matrix<f64> = matrix![1.0, 0.0, 0.0];
+    assert!(!wide.is_square() && !wide.is_upper_triangular(0.0));
+}
+
+#[test]
+fn orthogonality() {
+    let (c, s) = (0.6, 0.8);
+    let rotation: Matrix<f64> = matrix![c, -s; s, c];
+
+    assert!(rotation.is_orthogonal(1e-12));
+    assert!(!rotation.multiply(2.0).is_orthogonal(1e-12));
+    assert!(matrix![0.0, 1.0; 1.0, 0.0].is_orthogonal(0.0));
+}
+
+#[test]
+fn definiteness() {
+    let spd: Matrix<f64> = matrix![4.0, 1.0; 1.0, 3.0];
+    let singular: Matrix<f64> = matrix![1.0, 1.0; 1.0, 1.0];
+    let indefinite: Matrix<f64> = matrix![1.0, 2.0; 2.0, 1.0];
+
+    assert!(spd.is_positive_definite());
+    assert!(spd.is_positive_semidefinite(0.0));
+    assert!(!singular.is_positive_definite());
+    assert!(singular.is_positive_semidefinite(1e-12));
+    assert!(!indefinite.is_positive_definite());
+    assert!(!indefinite.is_positive_semidefinite(1e-12));
+
+    // Not symmetric, even though the quadratic form is positive.
+    assert!(!matrix![2.0, 1.0; 0.0, 2.0].is_positive_definite());
+}
@@ -0,0 +1,39 @@
+use crate::matrix;
+use crate::matrix::{Axis, Matrix};
+
+#[test]
+fn element_queries() {
+    let m: Matrix<f64> = matrix![0.1, 0.5; 0.9, f64::NAN];
+
+    assert!(m.any(|x| x.is_nan()));
+    assert!(!m.all(|x| (0.0..=1.0).contains(x)));
+    assert_eq!(m.count(|x| *x > 0.3), 2);
+
+    let empty: Matrix<f64> = Matrix::new(0, 0);
+    assert!(empty.all(|x| x.is_nan()) && !empty.any(|_| true));
+}
+
+#[test]
+fn axis_queries() {
+    let m = matrix![1, -2, 3; 4, 5, -6];
+
+    assert_eq!(m.any_axis(Axis::Rows, |x| *x < 0), vec![true, true]);
+    assert_eq!(m.all_axis(Axis::Cols, |x| *x > 0), vec![true, false, false]);
+    assert_eq!(m.count_axis(Axis::Rows, |x| *x > 0), vec![2, 2]);
+    assert_eq!(m.count_axis(Axis::Cols, |x| *x > 0), vec![2, 1, 1]);
+}
+
+#[test]
+fn folds() {
+    let m = matrix![1, 2, 3; 4, 5, 6];
+
+    assert_eq!(m.fold(0, |acc, x| acc * 10 + x), 123456);
+    assert_eq!(m.fold_axis(Axis::Rows, 1, |acc, x| acc * x), vec![6, 120]);
+    assert_eq!(
+        m.fold_axis(Axis::Cols, Vec::new(), |mut acc, x| {
+            acc.push(*x);
+            acc
+        }),
+        vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+    );
+}
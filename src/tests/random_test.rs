@@ -0,0 +1,128 @@
+use crate::matrix::random::seeded_rng;
+use crate::matrix::Matrix;
+
+#[test]
+fn random_uniform() {
+    let mut rng = rand::thread_rng();
+    let mat_a: Matrix<f64> = Matrix::random(20, 30, &mut rng);
+
+    assert_eq!((mat_a.num_rows(), mat_a.num_cols()), (20, 30));
+    assert_eq!(mat_a.as_slice().len(), 600);
+    assert!(mat_a.as_slice().iter().all(|x| (0.0..1.0).contains(x)));
+}
+
+#[test]
+fn random_normal_moments() {
+    let mut rng = rand::thread_rng();
+    let mat_a: Matrix<f64> = Matrix::random_normal(100, 100, &mut rng);
+
+    let n = mat_a.as_slice().len() as f64;
+    let mean = mat_a.as_slice().iter().sum::<f64>() / n;
+    let var = mat_a
+        .as_slice()
+        .iter()
+        .map(|x| (x - mean).powi(2))
+        .sum::<f64>()
+        / n;
+
+    assert!(mean.abs() < 0.1);
+    assert!((var - 1.0).abs() < 0.1);
+}
+
+#[test]
+fn random_with_distribution() {
+    let mut rng = rand::thread_rng();
+    let dist = rand_distr::Uniform::new(-5, 5);
+    let mat_a: Matrix<i32> = Matrix::random_with(10, 10, &dist, &mut rng);
+
+    assert!(mat_a.as_slice().iter().all(|x| (-5..5).contains(x)));
+}
+
+#[test]
+fn random_sparse_density() {
+    let mut rng = rand::thread_rng();
+    let dist = rand_distr::Uniform::new(1.0, 2.0);
+    let mat_a: Matrix<f64> = Matrix::random_sparse(100, 100, 0.1, &dist, &mut rng).unwrap();
+
+    let nonzero = mat_a.as_slice().iter().filter(|x| **x != 0.0).count();
+    assert!((700..1300).contains(&nonzero));
+    assert!(Matrix::<f64>::random_sparse(2, 2, 1.5, &dist, &mut rng).is_err());
+}
+
+#[test]
+fn random_orthogonal_and_spd() {
+    let mut rng = rand::thread_rng();
+    let q: Matrix<f64> = Matrix::random_orthogonal(6, &mut rng);
+
+    for j in 0..6 {
+        for k in 0..6 {
+            let dot: f64 = (0..6)
+                .map(|i| q.at_or_default(i, j) * q.at_or_default(i, k))
+                .sum();
+            let expected = if j == k { 1.0 } else { 0.0 };
+            assert!((dot - expected).abs() < 1e-10);
+        }
+    }
+
+    let s: Matrix<f64> = Matrix::random_spd(5, 1e-3, &mut rng);
+    for i in 0..5 {
+        assert!(s.at_or_default(i, i) > 0.0);
+        for j in 0..5 {
+            assert_eq!(s.at_or_default(i, j), s.at_or_default(j, i));
+        }
+    }
+}
+
+#[test]
+fn random_seeded_is_reproducible() {
+    let mat_a: Matrix<u32> = Matrix::random_seeded(3, 4, 42);
+    let mat_b: Matrix<u32> = Matrix::random_seeded(3, 4, 42);
+    let mat_c: Matrix<u32> = Matrix::random_seeded(3, 4, 43);
+
+    assert_eq!(mat_a, mat_b);
+    assert_ne!(mat_a, mat_c);
+    // Pinned so a change in the generator stream is caught.
+    assert_eq!(mat_a.as_slice()[0], 962419617);
+
+    let mut rng = seeded_rng(7);
+    let mat_d: Matrix<f64> = Matrix::random_normal(2, 2, &mut rng);
+    assert_eq!(mat_d, Matrix::random_normal(2, 2, &mut seeded_rng(7)));
+}
+
+#[test]
+fn shuffle_rows_keeps_rows_intact() {
+    let original = Matrix::from_vec((0..50).map(|i| vec![i, i * 10]).collect());
+    let labels: Vec<i32> = (0..50).collect();
+
+    let mut shuffled = Matrix::from_flat(50, 2, original.as_slice().to_vec()).unwrap();
+    let p = shuffled.shuffle_rows(&mut seeded_rng(7));
+
+    assert_ne!(shuffled, original);
+    assert!(shuffled.rows().all(|row| row[1] == row[0] * 10));
+    assert_eq!(p.apply_rows(&original), Some(shuffled));
+    assert_eq!(p.apply_vector(&labels).unwrap()[0], p.indices()[0] as i32);
+
+    let mut a = Matrix::from_flat(50, 2, original.as_slice().to_vec()).unwrap();
+    let mut b = Matrix::from_flat(50, 2, original.as_slice().to_vec()).unwrap();
+    assert_eq!(a.shuffle_rows_seeded(3), b.shuffle_rows_seeded(3));
+    assert_eq!(a, b);
+}
+
+#[test]
+fn partition_rows_train_test() {
+    let m = Matrix::from_vec((0..10).map(|i| vec![i]).collect());
+
+    let (train, test) = m.partition_rows(0.75, &mut seeded_rng(1)).unwrap();
+
+    assert_eq!((train.num_rows(), test.num_rows()), (8, 2));
+    let mut all: Vec<i32> = train
+        .as_slice()
+        .iter()
+        .chain(test.as_slice())
+        .copied()
+        .collect();
+    assert!(train.as_slice().windows(2).all(|w| w[0] < w[1]));
+    all.sort();
+    assert_eq!(all, (0..10).collect::<Vec<_>>());
+    assert!(m.partition_rows(1.5, &mut seeded_rng(1)).is_err());
+}
@@ -0,0 +1,41 @@
+use crate::matrix::Matrix;
+
+#[test]
+fn linspace_row_major() {
+    let mat_a: Matrix<f64> = Matrix::linspace(0.0, 1.0, 2, 3);
+
+    assert_eq!(
+        mat_a.as_slice(),
+        &[0.0, 0.2, 0.4, 0.6000000000000001, 0.8, 1.0]
+    );
+    assert_eq!(mat_a.num_rows(), 2);
+    assert_eq!(Matrix::linspace(5.0, 9.0, 1, 1).as_slice(), &[5.0]);
+}
+
+#[test]
+fn arange_and_reshape() {
+    let mat_a = Matrix::arange(1, 2, 2, 2);
+    assert_eq!(mat_a, Matrix::from_vec(vec![vec![1, 3], vec![5, 7]]));
+
+    let mat_b = mat_a.reshape(1, 4).unwrap();
+    assert_eq!(mat_b, Matrix::from_vec(vec![vec![1, 3, 5, 7]]));
+    assert!(mat_b.reshape(3, 1).is_none());
+
+    let mat_c: Matrix<f64> = Matrix::arange(0.0, 0.1, 1, 4);
+    assert_eq!(mat_c.as_slice()[3], 0.30000000000000004);
+}
+
+#[test]
+fn meshgrid_xy_indexing() {
+    let (x, y) = Matrix::meshgrid(&[1, 2, 3], &[10, 20]);
+
+    assert_eq!(x, Matrix::from_vec(vec![vec![1, 2, 3], vec![1, 2, 3]]));
+    assert_eq!(
+        y,
+        Matrix::from_vec(vec![vec![10, 10, 10], vec![20, 20, 20]])
+    );
+
+    let (x, y) = Matrix::<f64>::meshgrid(&[], &[1.0]);
+    assert_eq!((x.num_rows(), x.num_cols()), (1, 0));
+    assert!(y.as_slice().is_empty());
+}
@@ -0,0 +1,62 @@
+use crate::matrix;
+use crate::matrix::regression::{linear_regression, solve_ridge, RegressionOptions, Solver};
+use crate::matrix::Matrix;
+
+#[test]
+fn fit_with_intercept() {
+    // y = 3 + 2 x0 - x1 plus a little noise.
+    let x: Matrix<f64> = matrix![0.0, 1.0; 1.0, 0.0; 2.0, 3.0; 3.0, 1.0; 4.0, 4.0];
+    let y = [2.1, 5.0, 3.9, 8.0, 7.1];
+
+    for solver in [Solver::Qr, Solver::NormalEquations] {
+        let options = RegressionOptions::new().solver(solver);
+        let fit = linear_regression(&x, &y, &options).unwrap();
+
+        assert!((fit.intercept - 3.0).abs() < 0.2);
+        assert!((fit.coefficients[0] - 2.0).abs() < 0.1);
+        assert!((fit.coefficients[1] + 1.0).abs() < 0.1);
+        assert!(fit.r_squared > 0.99 && fit.r_squared <= 1.0);
+        assert_eq!(fit.residuals.len(), 5);
+        assert!(fit.residuals.iter().sum::<f64>().abs() < 1e-9);
+    }
+}
+
+#[test]
+fn fit_through_origin_and_predict() {
+    let x: Matrix<f64> = matrix![1.0; 2.0; 3.0];
+    let options = RegressionOptions::new().intercept(false);
+    let fit = linear_regression(&x, &[2.0, 4.0, 6.0], &options).unwrap();
+
+    assert_eq!(fit.intercept, 0.0);
+    assert!((fit.coefficients[0] - 2.0).abs() < 1e-12);
+    assert!((fit.r_squared - 1.0).abs() < 1e-12);
+    assert_eq!(
+        fit.predict(&matrix![10.0]).map(|p| p[0].round()),
+        Some(20.0)
+    );
+
+    assert!(linear_regression(&x, &[1.0, 2.0], &options).is_none());
+    let collinear: Matrix<f64> = matrix![1.0, 2.0; 2.0, 4.0; 3.0, 6.0];
+    assert!(linear_regression(&collinear, &[1.0, 2.0, 3.0], &options).is_none());
+}
+
+#[test]
+fn ridge_handles_collinear_features() {
+    let x: Matrix<f64> = matrix![1.0, 2.0; 2.0, 4.0; 3.0, 6.0];
+    let y = [1.0, 2.0, 3.0];
+
+    // The penalty picks the solution along (1, 2), the direction the two
+    // columns share, shrunk by λ.
+    let beta = solve_ridge(&x, &y, 1.0).unwrap();
+    let expected = 14.0 / (14.0 * 5.0 + 1.0);
+    assert!((beta[0] - expected).abs() < 1e-12);
+    assert!((beta[1] - 2.0 * expected).abs() < 1e-12);
+
+    // With no penalty it is plain least squares, which fails here.
+    assert!(solve_ridge(&x, &y, 0.0).is_none());
+    assert!(solve_ridge(&x, &y, -1.0).is_none());
+
+    let well_posed: Matrix<f64> = matrix![1.0; 2.0];
+    let plain = solve_ridge(&well_posed, &[2.0, 4.0], 0.0).unwrap();
+    assert!((plain[0] - 2.0).abs() < 1e-12);
+}
@@ -0,0 +1,70 @@
+use crate::matrix;
+use crate::matrix::Matrix;
+
+#[test]
+fn map_rows_and_cols() {
+    let m: Matrix<f64> = matrix![1.0, 3.0; 2.0, 6.0];
+
+    let sums = m.map_rows(|row| vec![row.iter().sum::<f64>()]).unwrap();
+    assert_eq!(sums, matrix![4.0; 8.0]);
+
+    let centered = m
+        .map_cols(|col| {
+            let mean = col.iter().sum::<f64>() / col.len() as f64;
+            col.iter().map(|x| x - mean).collect()
+        })
+        .unwrap();
+    assert_eq!(centered, matrix![-0.5, -1.5; 0.5, 1.5]);
+
+    let mut n = 0;
+    assert!(m
+        .map_rows(|row| {
+            n += 1;
+            row[..n].to_vec()
+        })
+        .is_none());
+}
+
+#[test]
+fn elementary_row_operations() {
+    let mut m = matrix![2, 4; 3, 1];
+
+    m.scale_row(0, 3).unwrap().row_axpy(1, 0, -2).unwrap();
+
+    assert_eq!(m, matrix![6, 12; -9, -23]);
+    assert!(m.scale_row(2, 1).is_err() && m.row_axpy(0, 2, 1).is_err());
+}
+
+#[test]
+fn bulk_copies() {
+    let mut m: Matrix<i32> = Matrix::new(3, 3);
+
+    m.set_row(0, &[1, 2, 3])
+        .unwrap()
+        .set_col(2, &[7, 8, 9])
+        .unwrap()
+        .copy_submatrix_from(&matrix![4, 5; 6, 0], (1, 0))
+        .unwrap();
+
+    assert_eq!(m, matrix![1, 2, 7; 4, 5, 8; 6, 0, 9]);
+    assert!(m.set_row(0, &[1, 2]).is_err() && m.set_col(3, &[0; 3]).is_err());
+    assert!(m.copy_submatrix_from(&matrix![1, 2], (2, 2)).is_err());
+
+    let mut copy: Matrix<i32> = Matrix::new(3, 3);
+    copy.copy_from(&m).unwrap();
+    assert_eq!(copy, m);
+    assert!(copy.copy_from(&matrix![1]).is_err());
+}
+
+#[test]
+fn fill_in_place() {
+    let mut m: Matrix<usize> = Matrix::new(2, 3);
+    let buffer = m.as_slice().as_ptr();
+
+    m.fill_with(|i, j| i * 10 + j);
+    assert_eq!(m, matrix![0, 1, 2; 10, 11, 12]);
+
+    m.fill(7);
+    assert_eq!(m, matrix![7, 7, 7; 7, 7, 7]);
+    assert_eq!(m.as_slice().as_ptr(), buffer);
+}
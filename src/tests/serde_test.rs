@@ -0,0 +1,22 @@
+use crate::matrix::Matrix;
+
+#[test]
+fn serde_round_trip() {
+    let mat_a = Matrix::from_vec(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+
+    let json = serde_json::to_string(&mat_a).unwrap();
+    assert_eq!(
+        json,
+        r#"{"rows":2,"cols":3,"data":[1.0,2.0,3.0,4.0,5.0,6.0]}"#
+    );
+
+    let mat_b: Matrix<f64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(mat_a, mat_b);
+}
+
+#[test]
+fn serde_rejects_wrong_element_count() {
+    let json = r#"{"rows":2,"cols":2,"data":[1.0,2.0,3.0]}"#;
+
+    assert!(serde_json::from_str::<Matrix<f64>>(json).is_err());
+}
@@ -0,0 +1,41 @@
+use crate::matrix;
+use crate::matrix::Matrix;
+
+#[test]
+fn clones_share_until_mutated() {
+    let shared = matrix![1, 2; 3, 4].into_shared();
+    let mut copy = shared.clone();
+
+    assert!(copy.ptr_eq(&shared) && shared.is_shared());
+    assert_eq!(copy.as_slice().as_ptr(), shared.as_slice().as_ptr());
+    assert_eq!(copy.sum(), 10);
+
+    copy.make_mut().set(0, 0, 9).unwrap();
+    assert!(!copy.ptr_eq(&shared) && !shared.is_shared());
+    assert_eq!(shared.at(0, 0), Some(&1));
+    assert_eq!(copy.into_matrix(), matrix![9, 2; 3, 4]);
+
+    // Unique handles mutate and unwrap in place.
+    let mut unique = shared;
+    let buffer = unique.as_slice().as_ptr();
+    unique.make_mut().fill(0);
+    assert_eq!(unique.into_matrix().as_slice().as_ptr(), buffer);
+}
+
+#[test]
+fn fan_out_across_threads() {
+    let data: Matrix<f64> = Matrix::linspace(0.0, 99.0, 10, 10);
+    let shared = data.into_shared();
+
+    let sums: Vec<f64> = std::thread::scope(|s| {
+        let workers: Vec<_> = (0..4)
+            .map(|w| {
+                let view = shared.clone();
+                s.spawn(move || view.get_row(w).unwrap().iter().sum::<f64>())
+            })
+            .collect();
+        workers.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+
+    assert_eq!(sums, vec![45.0, 145.0, 245.0, 345.0]);
+}
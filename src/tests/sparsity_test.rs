@@ -0,0 +1,22 @@
+use crate::matrix;
+use crate::matrix::Matrix;
+
+#[test]
+fn counts_nonzero_entries() {
+    let m: Matrix<f64> = matrix![1.0, 0.0, 1e-12; 0.0, -2.0, 0.0];
+
+    assert_eq!(m.count_nonzero(0.0), 3);
+    assert_eq!(m.count_nonzero(1e-9), 2);
+    assert_eq!(m.density(1e-9), 2.0 / 6.0);
+    assert_eq!(m.nonzero_indices(1e-9), vec![(0, 0), (1, 1)]);
+}
+
+#[test]
+fn empty_and_nan() {
+    let empty: Matrix<f64> = Matrix::new(0, 3);
+    assert_eq!(empty.density(0.0), 0.0);
+    assert!(empty.nonzero_indices(0.0).is_empty());
+
+    let m: Matrix<f64> = matrix![f64::NAN, 0.0];
+    assert_eq!(m.nonzero_indices(0.0), vec![(0, 0)]);
+}
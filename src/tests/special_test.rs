@@ -0,0 +1,41 @@
+use crate::matrix::special::{circulant, companion, hilbert, toeplitz, vandermonde};
+use crate::matrix::Matrix;
+
+#[test]
+fn hilbert_and_vandermonde() {
+    let h: Matrix<f64> = hilbert(3);
+    assert_eq!(h.at_or_default(0, 0), 1.0);
+    assert_eq!(h.at_or_default(1, 2), 0.25);
+    assert_eq!(h.at_or_default(2, 2), 0.2);
+
+    let v = vandermonde(&[1, 2, 3], 4);
+    assert_eq!(
+        v,
+        Matrix::from_vec(vec![vec![1, 1, 1, 1], vec![1, 2, 4, 8], vec![1, 3, 9, 27]])
+    );
+}
+
+#[test]
+fn toeplitz_and_circulant() {
+    let t = toeplitz(&[1, 2, 3], &[9, 4, 5, 6]);
+    assert_eq!(
+        t,
+        Matrix::from_vec(vec![vec![1, 4, 5, 6], vec![2, 1, 4, 5], vec![3, 2, 1, 4]])
+    );
+
+    let c = circulant(&[1, 2, 3]);
+    assert_eq!(
+        c,
+        Matrix::from_vec(vec![vec![1, 3, 2], vec![2, 1, 3], vec![3, 2, 1]])
+    );
+}
+
+#[test]
+fn companion_matrix() {
+    // 2x^2 - 6x + 4 = 2(x - 1)(x - 2)
+    let c = companion(&[2.0, -6.0, 4.0]).unwrap();
+    assert_eq!(c, Matrix::from_vec(vec![vec![3.0, -2.0], vec![1.0, 0.0]]));
+
+    assert!(companion(&[1.0]).is_none());
+    assert!(companion(&[0.0, 1.0, 2.0]).is_none());
+}
@@ -0,0 +1,139 @@
+use crate::matrix;
+use crate::matrix::{Axis, Matrix};
+
+fn assert_close(got: &[f64], want: &[f64]) {
+    assert_eq!(got.len(), want.len());
+    for (g, w) in got.iter().zip(want) {
+        assert!((g - w).abs() < 1e-9, "{} != {}", g, w);
+    }
+}
+
+#[test]
+fn covariance_of_observations() {
+    let data: Matrix<f64> = matrix![1.0, 2.0; 2.0, 4.0; 3.0, 6.5; 4.0, 7.5];
+    let cov = data.covariance(Axis::Rows, 1).unwrap();
+
+    assert_close(
+        cov.as_slice(),
+        &[5.0 / 3.0, 9.5 / 3.0, 9.5 / 3.0, 18.5 / 3.0],
+    );
+
+    // The same variables laid out as rows give the same matrix.
+    let by_cols: Matrix<f64> = matrix![1.0, 2.0, 3.0, 4.0; 2.0, 4.0, 6.5, 7.5];
+    assert_close(
+        by_cols.covariance(Axis::Cols, 1).unwrap().as_slice(),
+        cov.as_slice(),
+    );
+    assert!(data.covariance(Axis::Rows, 4).is_none());
+}
+
+#[test]
+fn covariance_is_stable_with_large_offset() {
+    let offset = 1e9;
+    let data: Matrix<f64> = matrix![offset + 4.0; offset + 7.0; offset + 13.0; offset + 16.0];
+
+    assert_close(data.covariance(Axis::Rows, 1).unwrap().as_slice(), &[30.0]);
+}
+
+#[test]
+fn correlation_matrix() {
+    let data: Matrix<f64> = matrix![1.0, 3.0, 5.0; 2.0, 2.0, 5.0; 3.0, 1.0, 5.0];
+    let corr = data.correlation(Axis::Rows).unwrap();
+
+    assert_close(&corr.as_slice()[..2], &[1.0, -1.0]);
+    assert_close(&corr.as_slice()[3..5], &[-1.0, 1.0]);
+    assert!(corr.at(2, 2).unwrap().is_nan());
+}
+
+#[test]
+fn axis_reductions() {
+    let data: Matrix<f64> = matrix![1.0, 5.0, 3.0; 4.0, 2.0, 6.0];
+
+    assert_eq!(data.sum_axis(Axis::Rows), vec![9.0, 12.0]);
+    assert_eq!(data.sum_axis(Axis::Cols), vec![5.0, 7.0, 9.0]);
+    assert_eq!(data.mean_axis(Axis::Cols), vec![2.5, 3.5, 4.5]);
+    assert_eq!(data.var_axis(Axis::Cols, 0), vec![2.25, 2.25, 2.25]);
+    assert_eq!(data.std_axis(Axis::Rows, 1), vec![2.0, 2.0]);
+    assert_eq!(data.min_axis(Axis::Rows), Some(vec![1.0, 2.0]));
+    assert_eq!(data.max_axis(Axis::Cols), Some(vec![4.0, 5.0, 6.0]));
+
+    assert_eq!(data.sum(), 21.0);
+    assert_eq!(data.mean(), 3.5);
+    assert_close(&[data.var(1)], &[3.5]);
+    assert_eq!((data.min(), data.max()), (Some(1.0), Some(6.0)));
+
+    let ints = matrix![1, 2; 3, 4];
+    assert_eq!(ints.sum_axis(Axis::Rows), vec![3, 7]);
+    assert_eq!(ints.max(), Some(4));
+
+    let empty: Matrix<f64> = Matrix::new(2, 0);
+    assert_eq!(empty.min_axis(Axis::Rows), None);
+    assert_eq!(empty.min(), None);
+    assert!(empty.mean().is_nan());
+    assert!(Matrix::<f64>::new(0, 2).mean_axis(Axis::Cols)[1].is_nan());
+}
+
+#[test]
+fn argmin_and_argmax() {
+    let scores = matrix![0.1, 0.7, 0.2; 0.5, 0.1, 0.5; 0.3, 0.3, 0.9];
+
+    assert_eq!(scores.argmax(), Some((2, 2)));
+    assert_eq!(scores.argmin(), Some((0, 0)));
+    assert_eq!(scores.argmax_axis(Axis::Rows), Some(vec![1, 0, 2]));
+    assert_eq!(scores.argmin_axis(Axis::Cols), Some(vec![0, 1, 0]));
+
+    assert_eq!(Matrix::<i32>::new(0, 3).argmax(), None);
+    assert_eq!(Matrix::<i32>::new(3, 0).argmin_axis(Axis::Rows), None);
+}
+
+#[test]
+fn cumulative_sums_and_products() {
+    let m = matrix![1, 2, 3; 4, 5, 6];
+
+    assert_eq!(m.cumsum_axis(Axis::Rows), matrix![1, 3, 6; 4, 9, 15]);
+    assert_eq!(m.cumsum_axis(Axis::Cols), matrix![1, 2, 3; 5, 7, 9]);
+    assert_eq!(m.cumprod_axis(Axis::Rows), matrix![1, 2, 6; 4, 20, 120]);
+
+    let integral = m.cumsum_axis(Axis::Rows).cumsum_axis(Axis::Cols);
+    assert_eq!(integral, matrix![1, 3, 6; 5, 12, 21]);
+}
+
+#[test]
+fn discrete_differences() {
+    let m = matrix![1, 4, 9, 16; 2, 3, 5, 8];
+
+    assert_eq!(m.diff_axis(Axis::Rows, 1), matrix![3, 5, 7; 1, 2, 3]);
+    assert_eq!(m.diff_axis(Axis::Rows, 2), matrix![2, 2; 1, 1]);
+    assert_eq!(m.diff_axis(Axis::Cols, 1), matrix![1, -1, -4, -8]);
+    assert_eq!(m.diff_axis(Axis::Rows, 0), m);
+
+    let flat = m.diff_axis(Axis::Rows, 5);
+    assert_eq!((flat.num_rows(), flat.num_cols()), (2, 0));
+}
+
+#[test]
+fn softmax_is_stable() {
+    let logits: Matrix<f64> = matrix![1.0, 2.0, 3.0; 1000.0, 1000.0, 1000.0];
+    let probs = logits.softmax_rows();
+
+    let e = [1.0_f64.exp(), 2.0_f64.exp(), 3.0_f64.exp()];
+    let total: f64 = e.iter().sum();
+    assert_close(
+        &probs.as_slice()[..3],
+        &[e[0] / total, e[1] / total, e[2] / total],
+    );
+    assert_close(&probs.as_slice()[3..], &[1.0 / 3.0; 3]);
+
+    let lse = logits.logsumexp_rows();
+    assert_close(&lse, &[total.ln(), 1000.0 + 3.0_f64.ln()]);
+    assert_eq!(
+        Matrix::<f64>::new(1, 0).logsumexp_rows(),
+        vec![f64::NEG_INFINITY]
+    );
+
+    let degenerate =
+        matrix![f64::NEG_INFINITY, f64::NEG_INFINITY; 0.0, f64::INFINITY].softmax_rows();
+    assert!(degenerate.as_slice()[..2].iter().all(|x| x.is_nan()));
+    assert_eq!(degenerate.as_slice()[2], 0.0);
+    assert!(degenerate.as_slice()[3].is_nan());
+}
@@ -0,0 +1,37 @@
+use crate::matrix;
+use crate::matrix::bytes::HEADER_LEN;
+use crate::matrix::stream::RowReader;
+use crate::matrix::Matrix;
+use std::io::ErrorKind;
+
+#[test]
+fn stream_round_trip() {
+    let m: Matrix<f32> = matrix![1.0, -2.0, 3.5; 4.0, 5.0, 6.0];
+    let mut out = Vec::new();
+
+    m.write_rows_to(&mut out).unwrap();
+
+    assert_eq!(out, m.to_bytes()[HEADER_LEN..]);
+    assert_eq!(Matrix::<f32>::read_rows_from(out.as_slice(), 3).unwrap(), m);
+
+    let rows: Vec<Vec<f32>> = RowReader::new(out.as_slice(), 3)
+        .map(|r| r.unwrap())
+        .collect();
+    assert_eq!(rows, vec![vec![1.0, -2.0, 3.5], vec![4.0, 5.0, 6.0]]);
+}
+
+#[test]
+fn stream_rejects_partial_rows() {
+    let mut out = Vec::new();
+    matrix![1u16, 2, 3; 4, 5, 6]
+        .write_rows_to(&mut out)
+        .unwrap();
+
+    let error = Matrix::<u16>::read_rows_from(&out[..out.len() - 1], 3).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+    assert!(Matrix::<u16>::read_rows_from(out.as_slice(), 4).is_err());
+    assert!(Matrix::<u16>::read_rows_from(out.as_slice(), 0).is_err());
+
+    let empty = Matrix::<u16>::read_rows_from(&[][..], 3).unwrap();
+    assert_eq!((empty.num_rows(), empty.num_cols()), (0, 3));
+}
@@ -0,0 +1,43 @@
+use crate::matrix;
+use crate::matrix::symmetric::SymmetricMatrix;
+use crate::matrix::triangular::Triangle;
+
+#[test]
+fn symmetric_packed_storage() {
+    let dense = matrix![4.0, 2.0, 1.0; 2.0, 5.0, 3.0; 1.0, 3.0, 6.0];
+    let mut s = SymmetricMatrix::from_dense(&dense).unwrap();
+
+    assert_eq!(s.packed(), &[4.0, 2.0, 5.0, 1.0, 3.0, 6.0]);
+    assert_eq!(s.to_dense(), dense);
+    assert!(SymmetricMatrix::from_dense(&matrix![1.0, 2.0; 3.0, 4.0]).is_none());
+    assert!(SymmetricMatrix::from_dense(&matrix![1.0, 2.0; 2.0, 1.0; 3.0, 3.0]).is_none());
+
+    let _ = s.set(0, 2, 9.0);
+    assert_eq!(s.at(2, 0), Some(&9.0));
+    assert_eq!(s.at(3, 0), None);
+}
+
+#[test]
+fn symmetric_multiply() {
+    let dense = matrix![2, 1, 0; 1, 3, 4; 0, 4, 5];
+    let s = SymmetricMatrix::from_dense(&dense).unwrap();
+    let b = matrix![1, 2; 3, 4; 5, 6];
+
+    assert_eq!(s.multiply(&b), dense.matrix_multiply(&b));
+    assert_eq!(s.multiply_vector(&[1, 1, 1]), Some(vec![3, 8, 9]));
+    assert!(s.multiply_vector(&[1, 1]).is_none());
+}
+
+#[test]
+fn symmetric_cholesky() {
+    let s = SymmetricMatrix::from_dense(&matrix![4.0, 2.0; 2.0, 5.0]).unwrap();
+    let l = s.cholesky().unwrap();
+
+    assert_eq!(l.triangle(), Triangle::Lower);
+    assert_eq!(l.at(1, 0), Some(&1.0));
+    assert_eq!(l.at(0, 1), None);
+    assert_eq!(l.to_dense(), matrix![2.0, 0.0; 1.0, 2.0]);
+
+    let indefinite = SymmetricMatrix::from_dense(&matrix![1.0, 2.0; 2.0, 1.0]).unwrap();
+    assert!(indefinite.cholesky().is_none());
+}
@@ -0,0 +1,92 @@
+use crate::einsum;
+use crate::matrix;
+use crate::matrix::tensor::{einsum, Tensor};
+
+#[test]
+fn reshape_permute_and_slice() {
+    // A batch of two 2x3 "images".
+    let t = Tensor::from_vec(&[2, 2, 3], (0..12).collect()).unwrap();
+    assert_eq!(t.at(&[1, 0, 2]), Some(&8));
+    assert_eq!(t.at(&[2, 0, 0]), None);
+
+    let moved = t.clone().permute_axes(&[2, 0, 1]).unwrap();
+    assert_eq!(moved.shape(), &[3, 2, 2]);
+    assert_eq!(moved.at(&[2, 1, 0]), Some(&8));
+    assert_eq!(moved.to_vec()[..4], [0, 3, 6, 9]);
+    assert!(t.clone().permute_axes(&[0, 0, 1]).is_none());
+
+    let second = t.index_axis(0, 1).unwrap();
+    assert_eq!(second.into_matrix(), Some(matrix![6, 7, 8; 9, 10, 11]));
+
+    let columns = t.slice(2, 1..3).unwrap();
+    assert_eq!(columns.shape(), &[2, 2, 2]);
+    assert_eq!(columns.to_vec(), vec![1, 2, 4, 5, 7, 8, 10, 11]);
+
+    let flat = moved.reshape(&[3, 4]).unwrap();
+    assert_eq!(flat.at(&[0, 1]), Some(&3));
+    assert!(t.reshape(&[5, 2]).is_none());
+}
+
+#[test]
+fn tensordot_contracts_to_matrix() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![5, 6; 7, 8];
+    let product = a.matrix_multiply(&b).unwrap();
+    let (ta, tb) = (Tensor::from(a), Tensor::from(b));
+
+    let contracted = ta.tensordot(&tb, &[(1, 0)]).unwrap();
+    assert_eq!(contracted.into_matrix(), Some(product));
+
+    // Contracting both axes gives a scalar: sum of a ∘ b.
+    let inner = ta.tensordot(&tb, &[(0, 0), (1, 1)]).unwrap();
+    assert_eq!((inner.ndim(), inner.to_vec()), (0, vec![70]));
+
+    // Batch of vectors times a matrix: (2, 3) x (3,) -> (2,).
+    let batch = Tensor::from_vec(&[2, 3], vec![1, 0, 2, 0, 1, 1]).unwrap();
+    let v = Tensor::from_vec(&[3], vec![1, 2, 3]).unwrap();
+    assert_eq!(batch.tensordot(&v, &[(1, 0)]).unwrap().to_vec(), vec![7, 5]);
+    assert!(batch.tensordot(&v, &[(0, 0)]).is_none());
+}
+
+#[test]
+fn einsum_notation() {
+    let a = Tensor::from(matrix![1, 2; 3, 4]);
+    let b = Tensor::from(matrix![5, 6; 7, 8]);
+
+    let product = einsum!("ij,jk->ik", &a, &b).unwrap();
+    assert_eq!(product.into_matrix(), Some(matrix![19, 22; 43, 50]));
+    assert_eq!(einsum!("ij->ji", &a).unwrap().to_vec(), vec![1, 3, 2, 4]);
+    assert_eq!(einsum!("ii", &a).unwrap().to_vec(), vec![5]);
+    assert_eq!(einsum!("ij,ij->", &a, &b).unwrap().to_vec(), vec![70]);
+
+    let (u, v) = (
+        Tensor::from_vec(&[2], vec![1, 2]).unwrap(),
+        Tensor::from_vec(&[3], vec![1, 0, -1]).unwrap(),
+    );
+    assert_eq!(
+        einsum!("i,j", &u, &v).unwrap().to_vec(),
+        vec![1, 0, -1, 2, 0, -2]
+    );
+}
+
+#[test]
+fn einsum_batched_and_errors() {
+    let a = Tensor::from_vec(&[2, 2, 2], vec![1, 2, 3, 4, 1, 0, 0, 1]).unwrap();
+    let b = Tensor::from_vec(&[2, 2, 1], vec![1, 1, 5, 6]).unwrap();
+
+    let batched = einsum("bij,bjk->bik", &[&a, &b]).unwrap();
+    assert_eq!(batched.shape(), &[2, 2, 1]);
+    assert_eq!(batched.to_vec(), vec![3, 7, 5, 6]);
+
+    // The same contraction as tensordot, with the output reordered.
+    let swapped = einsum(
+        "ij,jk->ki",
+        &[&a.index_axis(0, 0).unwrap(), &b.index_axis(0, 1).unwrap()],
+    );
+    assert_eq!(swapped.unwrap().to_vec(), vec![17, 39]);
+
+    assert!(einsum("ij,jk->ik", &[&a, &b]).is_err());
+    assert!(einsum("bij,bjk->bik", &[&a]).is_err());
+    assert!(einsum("bij,bkj->bik", &[&a, &b]).is_err());
+    assert!(einsum("bij,bjk->bix", &[&a, &b]).is_err());
+}
@@ -0,0 +1,59 @@
+use crate::matrix;
+use crate::matrix::Matrix;
+
+#[test]
+fn row_chunks_on_threads() {
+    let mut m: Matrix<usize> = Matrix::new(5, 3);
+
+    std::thread::scope(|s| {
+        for mut chunk in m.row_chunks_mut(2) {
+            s.spawn(move || {
+                let first = chunk.first_row();
+                for (i, row) in chunk.rows_mut().enumerate() {
+                    row.iter_mut().for_each(|x| *x = first + i);
+                }
+            });
+        }
+    });
+
+    assert_eq!(m, matrix![0, 0, 0; 1, 1, 1; 2, 2, 2; 3, 3, 3; 4, 4, 4]);
+    assert_eq!(
+        m.row_chunks_mut(2)
+            .iter()
+            .map(|c| c.num_rows())
+            .collect::<Vec<_>>(),
+        vec![2, 2, 1]
+    );
+}
+
+#[test]
+fn tiles_cover_the_matrix_once() {
+    let mut m: Matrix<usize> = Matrix::new(3, 5);
+
+    let mut tiles = m.split_into_tiles_mut(2, 2);
+    let shapes: Vec<_> = tiles.iter().map(|t| (t.origin(), t.shape())).collect();
+    assert_eq!(
+        shapes,
+        vec![
+            ((0, 0), (2, 2)),
+            ((0, 2), (2, 2)),
+            ((0, 4), (2, 1)),
+            ((2, 0), (1, 2)),
+            ((2, 2), (1, 2)),
+            ((2, 4), (1, 1)),
+        ]
+    );
+    *tiles[5].at_mut(0, 0).unwrap() = 100;
+    assert!(tiles[5].at_mut(0, 1).is_none());
+
+    std::thread::scope(|s| {
+        for mut tile in tiles {
+            s.spawn(move || tile.for_each_mut(|i, j, x| *x += i * 10 + j));
+        }
+    });
+
+    assert_eq!(
+        m,
+        matrix![0, 1, 2, 3, 4; 10, 11, 12, 13, 14; 20, 21, 22, 23, 124]
+    );
+}
@@ -0,0 +1,42 @@
+use crate::matrix;
+use crate::matrix::triangular::{Triangle, TriangularMatrix};
+
+#[test]
+fn triu_and_tril() {
+    let mat_a = matrix![1, 2, 3; 4, 5, 6];
+
+    assert_eq!(mat_a.triu(), matrix![1, 2, 3; 0, 5, 6]);
+    assert_eq!(mat_a.tril(), matrix![1, 0, 0; 4, 5, 0]);
+}
+
+#[test]
+fn triangular_storage_and_multiply() {
+    let dense = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+    let upper = TriangularMatrix::from_dense(&dense, Triangle::Upper).unwrap();
+    let lower = TriangularMatrix::from_dense(&dense, Triangle::Lower).unwrap();
+
+    assert_eq!(upper.to_dense(), dense.triu());
+    assert_eq!(lower.to_dense(), dense.tril());
+    assert_eq!(upper.at(1, 2), Some(&6));
+    assert_eq!(upper.at(2, 1), None);
+
+    let b = matrix![1, 0; 0, 1; 1, 1];
+    assert_eq!(upper.multiply(&b), dense.triu().matrix_multiply(&b));
+    assert_eq!(lower.multiply_vector(&[1, 1, 1]), Some(vec![1, 9, 24]));
+}
+
+#[test]
+fn triangular_solve() {
+    let lower =
+        TriangularMatrix::from_dense(&matrix![2.0, 0.0; 1.0, 4.0], Triangle::Lower).unwrap();
+    assert_eq!(lower.solve(&[4.0, 10.0]), Some(vec![2.0, 2.0]));
+
+    let upper =
+        TriangularMatrix::from_dense(&matrix![2.0, 1.0; 0.0, 4.0], Triangle::Upper).unwrap();
+    let b = matrix![5.0, 3.0; 8.0, 4.0];
+    assert_eq!(upper.solve_matrix(&b), Some(matrix![1.5, 1.0; 2.0, 1.0]));
+
+    let singular =
+        TriangularMatrix::from_dense(&matrix![0.0, 1.0; 0.0, 1.0], Triangle::Upper).unwrap();
+    assert!(singular.solve(&[1.0, 1.0]).is_none());
+}
@@ -0,0 +1,15 @@
+use crate::wasm::JsMatrix;
+use wasm_bindgen_test::wasm_bindgen_test;
+
+#[wasm_bindgen_test]
+fn js_matrix_solve() {
+    let a = JsMatrix::from_array(2, 2, &[2.0, 1.0, 1.0, 3.0]).unwrap();
+    let b = JsMatrix::from_array(2, 1, &[3.0, 5.0]).unwrap();
+    let x = a.solve(&b).unwrap();
+
+    assert_eq!((x.rows(), x.cols()), (2, 1));
+    assert!((x.get(0, 0).unwrap() - 0.8).abs() < 1e-12);
+    assert!((x.get(1, 0).unwrap() - 1.4).abs() < 1e-12);
+    assert!(b.solve(&a).is_err());
+    assert!(JsMatrix::new(2, 2).solve(&b).is_err());
+}
@@ -0,0 +1,124 @@
+use crate::matrix::Matrix;
+use js_sys::Float64Array;
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen(js_name = Matrix)]
+pub struct JsMatrix {
+    inner: Matrix<f64>,
+}
+
+#[wasm_bindgen(js_class = Matrix)]
+impl JsMatrix {
+    #[wasm_bindgen(constructor)]
+    pub fn new(rows: usize, cols: usize) -> JsMatrix {
+        return JsMatrix {
+            inner: Matrix::new_empty(rows, cols),
+        };
+    }
+
+    #[wasm_bindgen(js_name = fromArray)]
+    pub fn from_array(rows: usize, cols: usize, data: &[f64]) -> Result<JsMatrix, JsError> {
+        let inner = Matrix::from_flat(rows, cols, data.to_vec()).map_err(JsError::new)?;
+
+        return Ok(JsMatrix { inner });
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn rows(&self) -> usize {
+        return self.inner.num_rows();
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn cols(&self) -> usize {
+        return self.inner.num_cols();
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<f64> {
+        if row >= self.rows() || col >= self.cols() {
+            return None;
+        }
+
+        return self.inner.at(row, col).copied();
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: f64) -> Result<(), JsError> {
+        if row >= self.rows() || col >= self.cols() {
+            return Err(JsError::new("Index out of bounds"));
+        }
+
+        return self
+            .inner
+            .set(row, col, value)
+            .map(|_| ())
+            .map_err(JsError::new);
+    }
+
+    pub fn add(&self, value: f64) -> JsMatrix {
+        return self.inner.add(value).into();
+    }
+
+    pub fn subtract(&self, value: f64) -> JsMatrix {
+        return self.inner.subtract(value).into();
+    }
+
+    pub fn multiply(&self, value: f64) -> JsMatrix {
+        return self.inner.multiply(value).into();
+    }
+
+    #[wasm_bindgen(js_name = matrixAdd)]
+    pub fn matrix_add(&self, m: &JsMatrix) -> Result<JsMatrix, JsError> {
+        return self
+            .inner
+            .matrix_add(&m.inner)
+            .map(JsMatrix::from)
+            .ok_or_else(|| JsError::new("Matrix dimensions do not match"));
+    }
+
+    #[wasm_bindgen(js_name = matrixMultiply)]
+    pub fn matrix_multiply(&self, m: &JsMatrix) -> Result<JsMatrix, JsError> {
+        return self
+            .inner
+            .matrix_multiply(&m.inner)
+            .map(JsMatrix::from)
+            .ok_or_else(|| JsError::new("Matrix dimensions do not match"));
+    }
+
+    // Solves self * x = b; throws if self is not square, the shapes
+    // disagree, or self is singular.
+    pub fn solve(&self, b: &JsMatrix) -> Result<JsMatrix, JsError> {
+        return self
+            .inner
+            .solve(&b.inner)
+            .map(JsMatrix::from)
+            .ok_or_else(|| JsError::new("Matrix is singular or dimensions do not match"));
+    }
+
+    // The returned array aliases wasm memory directly. It is invalidated by
+    // any allocation that grows the memory, so callers must not hold on to it
+    // across calls back into the module.
+    pub fn view(&self) -> Float64Array {
+        return unsafe { Float64Array::view(self.inner.as_slice()) };
+    }
+
+    #[wasm_bindgen(js_name = toFloat64Array)]
+    pub fn to_float64_array(&self) -> Float64Array {
+        return Float64Array::from(self.inner.as_slice());
+    }
+
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        return self.inner.to_string();
+    }
+}
+
+impl From<Matrix<f64>> for JsMatrix {
+    fn from(inner: Matrix<f64>) -> Self {
+        return JsMatrix { inner };
+    }
+}
+
+impl From<JsMatrix> for Matrix<f64> {
+    fn from(m: JsMatrix) -> Self {
+        return m.inner;
+    }
+}